@@ -0,0 +1,149 @@
+use std::fmt;
+
+use std::f32::consts::PI;
+
+
+
+
+
+
+
+
+//struct
+    //maps progress through an animation (0 to 1) onto progress between its values; elastic and bounce curves overshoot
+    //or undershoot along the way, but every curve starts at 0 and finishes at 1
+    #[derive(Copy, Clone, PartialEq)]
+    pub enum Easing {
+        Linear,
+        QuadIn, QuadOut, QuadInOut,
+        CubicIn, CubicOut, CubicInOut,
+        ElasticIn, ElasticOut, ElasticInOut,
+        BounceIn, BounceOut, BounceInOut,
+        CubicBezier(f32, f32, f32, f32), //the two control points (x1, y1, x2, y2) as in CSS; x values are kept within 0 to 1
+    }
+//new
+    impl Easing {
+        pub fn new_default() -> Easing {
+            Easing::Linear
+        }
+    }
+//apply
+    impl Easing {
+        pub fn apply(&self, t:f32) -> f32 {
+            let t = t.clamp(0.0, 1.0);
+
+            match *self {
+                Easing::Linear => t,
+
+                Easing::QuadIn => t * t,
+                Easing::QuadOut => 1.0 - (1.0 - t) * (1.0 - t),
+                Easing::QuadInOut => if t < 0.5 { 2.0 * t * t } else { 1.0 - (-2.0 * t + 2.0).powi(2) / 2.0 },
+
+                Easing::CubicIn => t * t * t,
+                Easing::CubicOut => 1.0 - (1.0 - t).powi(3),
+                Easing::CubicInOut => if t < 0.5 { 4.0 * t * t * t } else { 1.0 - (-2.0 * t + 2.0).powi(3) / 2.0 },
+
+                Easing::ElasticIn => {
+                    if t == 0.0 || t == 1.0 { return t; }
+                    -(2.0f32).powf(10.0 * t - 10.0) * ((t * 10.0 - 10.75) * (2.0 * PI / 3.0)).sin()
+                },
+                Easing::ElasticOut => {
+                    if t == 0.0 || t == 1.0 { return t; }
+                    (2.0f32).powf(-10.0 * t) * ((t * 10.0 - 0.75) * (2.0 * PI / 3.0)).sin() + 1.0
+                },
+                Easing::ElasticInOut => {
+                    if t == 0.0 || t == 1.0 { return t; }
+                    let wave = ((20.0 * t - 11.125) * (2.0 * PI / 4.5)).sin();
+                    if t < 0.5 {
+                        -((2.0f32).powf(20.0 * t - 10.0) * wave) / 2.0
+                    } else {
+                        (2.0f32).powf(-20.0 * t + 10.0) * wave / 2.0 + 1.0
+                    }
+                },
+
+                Easing::BounceIn => 1.0 - bounce_out(1.0 - t),
+                Easing::BounceOut => bounce_out(t),
+                Easing::BounceInOut => if t < 0.5 { (1.0 - bounce_out(1.0 - 2.0 * t)) / 2.0 } else { (1.0 + bounce_out(2.0 * t - 1.0)) / 2.0 },
+
+                Easing::CubicBezier(x1, y1, x2, y2) => cubic_bezier(x1.clamp(0.0, 1.0), y1, x2.clamp(0.0, 1.0), y2, t),
+            }
+        }
+    }
+//printing
+    impl Easing {
+        fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result {
+            match *self {
+                Easing::Linear => write!(f, "Linear"),
+                Easing::QuadIn => write!(f, "QuadIn"),
+                Easing::QuadOut => write!(f, "QuadOut"),
+                Easing::QuadInOut => write!(f, "QuadInOut"),
+                Easing::CubicIn => write!(f, "CubicIn"),
+                Easing::CubicOut => write!(f, "CubicOut"),
+                Easing::CubicInOut => write!(f, "CubicInOut"),
+                Easing::ElasticIn => write!(f, "ElasticIn"),
+                Easing::ElasticOut => write!(f, "ElasticOut"),
+                Easing::ElasticInOut => write!(f, "ElasticInOut"),
+                Easing::BounceIn => write!(f, "BounceIn"),
+                Easing::BounceOut => write!(f, "BounceOut"),
+                Easing::BounceInOut => write!(f, "BounceInOut"),
+                Easing::CubicBezier(x1, y1, x2, y2) => write!(f, "CubicBezier({},{},{},{})", x1, y1, x2, y2),
+            }
+        }
+    }
+    impl fmt::Display for Easing {
+        fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { self.fmt(f) }
+    }
+    impl fmt::Debug for Easing {
+        fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { self.fmt(f) }
+    }
+
+
+
+
+
+
+
+
+//utilities
+    fn bounce_out(t:f32) -> f32 {
+        let n = 7.5625;
+        let d = 2.75;
+
+        if t < 1.0 / d {
+            n * t * t
+        } else if t < 2.0 / d {
+            let t = t - 1.5 / d;
+            n * t * t + 0.75
+        } else if t < 2.5 / d {
+            let t = t - 2.25 / d;
+            n * t * t + 0.9375
+        } else {
+            let t = t - 2.625 / d;
+            n * t * t + 0.984375
+        }
+    }
+    //the curve runs from (0,0) to (1,1) by way of the two control points; find the curve parameter where x is the input
+    //(Newton's method, falling back to bisection if that stalls) and return the y at that parameter
+    fn cubic_bezier(x1:f32, y1:f32, x2:f32, y2:f32, x:f32) -> f32 {
+        let curve = |a:f32, b:f32, s:f32| 3.0 * (1.0 - s) * (1.0 - s) * s * a + 3.0 * (1.0 - s) * s * s * b + s * s * s;
+        let slope = |a:f32, b:f32, s:f32| 3.0 * (1.0 - s) * (1.0 - s) * a + 6.0 * (1.0 - s) * s * (b - a) + 3.0 * s * s * (1.0 - b);
+
+        let mut s = x;
+        for _ in 0..8 {
+            let error = curve(x1, x2, s) - x;
+            if error.abs() < 1e-6 { return curve(y1, y2, s); }
+            let gradient = slope(x1, x2, s);
+            if gradient.abs() < 1e-6 { break; }
+            s -= error / gradient;
+        }
+
+        let (mut low, mut high) = (0.0f32, 1.0f32);
+        s = x;
+        for _ in 0..32 {
+            let value = curve(x1, x2, s);
+            if (value - x).abs() < 1e-6 { break; }
+            if value < x { low = s; } else { high = s; }
+            s = (low + high) / 2.0;
+        }
+        curve(y1, y2, s)
+    }
\ No newline at end of file
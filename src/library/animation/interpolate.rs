@@ -0,0 +1,118 @@
+use super::super::data_type::{
+    Colour,
+    Offset,
+    Point,
+    Viewbox,
+};
+
+
+
+
+
+
+
+
+//trait
+    //a value that can be blended between two others; t runs from 0 (all a) to 1 (all b), though eased progress can run
+    //a little beyond either end
+    pub trait Interpolate {
+        fn interpolate(a:&Self, b:&Self, t:f32) -> Self;
+    }
+
+    fn lerp(a:f32, b:f32, t:f32) -> f32 {
+        a + (b - a) * t
+    }
+
+
+
+
+
+
+
+
+//implementations
+    impl Interpolate for f32 {
+        fn interpolate(a:&f32, b:&f32, t:f32) -> f32 {
+            lerp(*a, *b, t)
+        }
+    }
+    impl Interpolate for Point {
+        fn interpolate(a:&Point, b:&Point, t:f32) -> Point {
+            Point::new(
+                lerp(a.get_x(), b.get_x(), t),
+                lerp(a.get_y(), b.get_y(), t),
+            )
+        }
+    }
+    impl Interpolate for Offset {
+        //the angle is blended directly, so going from 0 to 4π spins twice
+        fn interpolate(a:&Offset, b:&Offset, t:f32) -> Offset {
+            Offset::new(
+                lerp(a.get_x(), b.get_x(), t),
+                lerp(a.get_y(), b.get_y(), t),
+                lerp(a.get_scale(), b.get_scale(), t),
+                lerp(a.get_angle(), b.get_angle(), t),
+            )
+        }
+    }
+    impl Interpolate for Colour {
+        //blending the premultiplied values means a fully transparent colour contributes nothing of its hue, so fading
+        //from transparent black to red doesn't pass through a murky dark red
+        fn interpolate(a:&Colour, b:&Colour, t:f32) -> Colour {
+            let alpha = lerp(a.a(), b.a(), t);
+            if alpha <= 0.0 {
+                return Colour::new(0.0, 0.0, 0.0, 0.0);
+            }
+
+            Colour::new(
+                lerp(a.premultiplied_r(), b.premultiplied_r(), t) / alpha,
+                lerp(a.premultiplied_g(), b.premultiplied_g(), t) / alpha,
+                lerp(a.premultiplied_b(), b.premultiplied_b(), t) / alpha,
+                alpha,
+            )
+        }
+    }
+    impl Interpolate for Viewbox {
+        //the size, constraints and device pixel ratio aren't things to be animated, so the result takes b's
+        fn interpolate(a:&Viewbox, b:&Viewbox, t:f32) -> Viewbox {
+            let mut output = Viewbox::new(
+                lerp(a.get_x(), b.get_x(), t),
+                lerp(a.get_y(), b.get_y(), t),
+                lerp(a.get_scale(), b.get_scale(), t),
+                lerp(a.get_angle(), b.get_angle(), t),
+                lerp(a.get_anchor_x(), b.get_anchor_x(), t),
+                lerp(a.get_anchor_y(), b.get_anchor_y(), t),
+                b.get_width(),
+                b.get_height(),
+            );
+            output.set_constraints( b.get_constraints().clone() );
+            output.set_device_pixel_ratio( b.get_device_pixel_ratio() );
+            output
+        }
+    }
+
+
+
+
+
+
+
+
+//perceptual colour
+    //a colour that blends in the OKLab space, where equal steps look like equal changes in colour; avoids the grey
+    //dip that blending straight red, green and blue values gives between complementary colours
+    #[derive(Copy, Clone)]
+    pub struct PerceptualColour(pub Colour);
+
+    impl Interpolate for PerceptualColour {
+        fn interpolate(a:&PerceptualColour, b:&PerceptualColour, t:f32) -> PerceptualColour {
+            let lab_a = a.0.to_oklab();
+            let lab_b = b.0.to_oklab();
+            PerceptualColour( Colour::new_from_oklab(
+                lerp(lab_a.0, lab_b.0, t),
+                lerp(lab_a.1, lab_b.1, t),
+                lerp(lab_a.2, lab_b.2, t),
+                lerp(a.0.a(), b.0.a(), t),
+            ) )
+        }
+    }
\ No newline at end of file
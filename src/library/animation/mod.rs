@@ -0,0 +1,7 @@
+mod easing;
+pub use easing::{*};
+
+mod interpolate;
+
+mod tween;
+pub use tween::{*};
\ No newline at end of file
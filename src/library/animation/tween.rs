@@ -0,0 +1,144 @@
+use std::fmt;
+
+use super::easing::Easing;
+use super::interpolate::Interpolate;
+
+
+
+
+
+
+
+
+//repetition
+    #[derive(Copy, Clone, PartialEq)]
+    pub enum Repetition {
+        Once,
+        Loop(Option<usize>), //how many times to play in total; None plays forever
+        Yoyo(Option<usize>), //as Loop, but every other play runs backwards (so a count of 2 is there and back again)
+    }
+    impl fmt::Display for Repetition {
+        fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result {
+            match self {
+                Repetition::Once => write!(f, "Once"),
+                Repetition::Loop(count) => write!(f, "Loop({:?})", count),
+                Repetition::Yoyo(count) => write!(f, "Yoyo({:?})", count),
+            }
+        }
+    }
+    impl fmt::Debug for Repetition {
+        fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { fmt::Display::fmt(self, f) }
+    }
+
+
+
+
+
+
+
+
+//struct
+    //an animation from a starting value through a chain of segments, each moving on to a new value over a number of
+    //orchestrator ticks with its own easing. Asking for the value at a tick (rather than stepping it along) means a
+    //skipped or repeated revolution doesn't knock it out of time
+    pub struct Tween<T:Interpolate> {
+        start_tick: usize,
+        from: T,
+        segments: Vec<TweenSegment<T>>,
+        repetition: Repetition,
+    }
+    struct TweenSegment<T:Interpolate> {
+        to: T,
+        duration: usize, //ticks
+        easing: Easing,
+    }
+//new
+    impl<T:Interpolate> Tween<T> {
+        pub fn new(start_tick:usize, from:T, to:T, duration:usize, easing:Easing) -> Tween<T> {
+            let mut tween = Tween::new_chain(start_tick, from);
+            tween.add_segment(to, duration, easing);
+            tween
+        }
+        //a tween that holds at its starting value until segments are added to it
+        pub fn new_chain(start_tick:usize, from:T) -> Tween<T> {
+            Tween {
+                start_tick,
+                from,
+                segments: vec![],
+                repetition: Repetition::Once,
+            }
+        }
+    }
+//getters
+    impl<T:Interpolate> Tween<T> {
+        pub fn get_start_tick(&self) -> usize { self.start_tick }
+        pub fn get_repetition(&self) -> Repetition { self.repetition }
+        pub fn get_segments_count(&self) -> usize { self.segments.len() }
+        //ticks for one play through every segment
+        pub fn get_duration(&self) -> usize {
+            self.segments.iter().map(|segment| segment.duration).sum()
+        }
+        //the tick after which the value stops changing, if it ever does
+        pub fn get_end_tick(&self) -> Option<usize> {
+            let plays = match self.repetition {
+                Repetition::Once => 1,
+                Repetition::Loop(count) | Repetition::Yoyo(count) => count?,
+            };
+            Some(self.start_tick + self.get_duration() * plays)
+        }
+        pub fn is_finished(&self, tick:usize) -> bool {
+            match self.get_end_tick() {
+                Some(end_tick) => tick >= end_tick,
+                None => false,
+            }
+        }
+    }
+//setters
+    impl<T:Interpolate> Tween<T> {
+        pub fn set_start_tick(&mut self, new:usize) { self.start_tick = new; }
+        pub fn set_repetition(&mut self, new:Repetition) { self.repetition = new; }
+        pub fn add_segment(&mut self, to:T, duration:usize, easing:Easing) {
+            self.segments.push(TweenSegment { to, duration, easing });
+        }
+    }
+//value
+    impl<T:Interpolate> Tween<T> {
+        pub fn get_value(&self, tick:usize) -> T {
+            let duration = self.get_duration();
+            if self.segments.is_empty() || duration == 0 || tick <= self.start_tick {
+                return self.value_at_position(0); //zero length segments jump straight to their end values
+            }
+
+            if self.is_finished(tick) {
+                let plays = self.get_end_tick().unwrap_or(0).saturating_sub(self.start_tick) / duration;
+                let ends_backwards = matches!(self.repetition, Repetition::Yoyo(_)) && plays % 2 == 0;
+                return self.value_at_position(if ends_backwards { 0 } else { duration });
+            }
+
+            let elapsed = tick - self.start_tick;
+            let play = elapsed / duration;
+            let position = elapsed - play * duration;
+
+            match self.repetition {
+                Repetition::Yoyo(_) if play % 2 == 1 => self.value_at_position(duration - position),
+                _ => self.value_at_position(position),
+            }
+        }
+
+        //position is in ticks, from 0 to the duration of one play
+        fn value_at_position(&self, position:usize) -> T {
+            let mut segment_start = 0;
+            let mut previous = &self.from;
+
+            for segment in &self.segments {
+                if position < segment_start + segment.duration {
+                    let t = (position - segment_start) as f32 / segment.duration as f32;
+                    return T::interpolate(previous, &segment.to, segment.easing.apply(t));
+                }
+                segment_start += segment.duration;
+                previous = &segment.to;
+            }
+
+            T::interpolate(previous, previous, 0.0)
+        }
+    }
\ No newline at end of file
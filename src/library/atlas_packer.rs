@@ -0,0 +1,531 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use super::data_type::BoundingBox;
+
+
+
+
+
+
+
+
+//rectangle
+    //in whole pixels, from the top left of a page
+    #[derive(Copy, Clone, PartialEq, Eq)]
+    struct Rectangle {
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+    }
+    impl Rectangle {
+        fn right(&self) -> u32 { self.x + self.width }
+        fn bottom(&self) -> u32 { self.y + self.height }
+        fn contains(&self, other:&Rectangle) -> bool {
+            other.x >= self.x && other.y >= self.y && other.right() <= self.right() && other.bottom() <= self.bottom()
+        }
+        fn intersects(&self, other:&Rectangle) -> bool {
+            other.x < self.right() && other.right() > self.x && other.y < self.bottom() && other.bottom() > self.y
+        }
+    }
+
+
+
+
+
+
+
+
+//packed rectangle
+    //where a rectangle was put. width and height are as it was given; if it was rotated it's stored a quarter turn
+    //clockwise, so takes up height by width on the page
+    #[derive(Copy, Clone, PartialEq, Eq, Debug)]
+    pub struct PackedRectangle {
+        pub page: usize,
+        pub x: u32,
+        pub y: u32,
+        pub width: u32,
+        pub height: u32,
+        pub rotated: bool,
+    }
+    impl PackedRectangle {
+        //the area taken up on the page, whichever way round it is
+        pub fn get_page_width(&self) -> u32 { if self.rotated { self.height } else { self.width } }
+        pub fn get_page_height(&self) -> u32 { if self.rotated { self.width } else { self.height } }
+    }
+
+
+
+
+
+
+
+
+//page
+    struct Page {
+        width: u32,
+        height: u32,
+        free: Vec<Rectangle>, //as large as each can be, so they overlap one another
+        rectangles: Vec<usize>,
+        used_area: u64,
+        revision: usize, //changes whenever the page grows
+    }
+
+
+
+
+
+
+
+
+//atlas packer
+    //packs rectangles (images, glyphs) into pages, using the "maximal rectangles" method: each page keeps every largest
+    //free rectangle it has, and each new rectangle goes into whichever free one it fits most snugly (by its shorter
+    //leftover side), turned a quarter if that fits better and rotation is allowed. When nothing fits, a page is grown
+    //(doubling its shorter side, up to the maximum size) and failing that a new page is started, up to the maximum
+    //page count. Rectangles are kept apart, and from the pages' edges, by the padding
+    //
+    //Positions are in pixels and never move once packed, but pages growing changes their UVs, so those should be got
+    //again whenever a page's revision changes
+    pub struct AtlasPacker {
+        page_width: u32, //for new pages
+        page_height: u32,
+        maximum_page_width: u32,
+        maximum_page_height: u32,
+        maximum_page_count: usize,
+        padding: u32,
+        allow_rotation: bool,
+
+        pages: Vec<Page>,
+        rectangles: HashMap<usize, PackedRectangle>,
+        next_id: usize,
+    }
+    //new
+        impl AtlasPacker {
+            pub fn new(page_width:u32, page_height:u32, maximum_page_width:u32, maximum_page_height:u32, maximum_page_count:usize, padding:u32, allow_rotation:bool) -> AtlasPacker {
+                //pages with no size could never grow into having one
+                if page_width == 0 || page_height == 0 {
+                    println!("WARNING - AtlasPacker::new : a page size of {}x{} was given; pages are at least 1x1", page_width, page_height);
+                }
+                let page_width = page_width.max(1);
+                let page_height = page_height.max(1);
+
+                AtlasPacker {
+                    page_width,
+                    page_height,
+                    maximum_page_width: maximum_page_width.max(page_width),
+                    maximum_page_height: maximum_page_height.max(page_height),
+                    maximum_page_count,
+                    padding,
+                    allow_rotation,
+                    pages: vec![],
+                    rectangles: HashMap::new(),
+                    next_id: 0,
+                }
+            }
+            pub fn new_default() -> AtlasPacker {
+                AtlasPacker::new(256, 256, 2048, 2048, 16, 1, true)
+            }
+        }
+    //getters
+        impl AtlasPacker {
+            pub fn get_padding(&self) -> u32 { self.padding }
+            pub fn get_allow_rotation(&self) -> bool { self.allow_rotation }
+            pub fn get_maximum_page_count(&self) -> usize { self.maximum_page_count }
+
+            pub fn get_page_count(&self) -> usize { self.pages.len() }
+            pub fn get_page_size(&self, page:usize) -> Option<(u32, u32)> {
+                self.pages.get(page).map(|page| (page.width, page.height))
+            }
+            pub fn get_page_revision(&self, page:usize) -> Option<usize> {
+                self.pages.get(page).map(|page| page.revision)
+            }
+            //how much of the page is taken up by rectangles (not counting padding), from 0 to 1
+            pub fn get_page_occupancy(&self, page:usize) -> Option<f32> {
+                self.pages.get(page).map(|page| page.used_area as f32 / (page.width as u64 * page.height as u64) as f32)
+            }
+            //in the order they were packed
+            pub fn get_page_rectangles(&self, page:usize) -> Option<&Vec<usize>> {
+                self.pages.get(page).map(|page| &page.rectangles)
+            }
+
+            pub fn get_rectangle_count(&self) -> usize { self.rectangles.len() }
+            pub fn get_rectangle(&self, id:usize) -> Option<&PackedRectangle> { self.rectangles.get(&id) }
+            //the area the rectangle takes up, as fractions of its page's size. For rotated rectangles the image has
+            //to be turned back as it's drawn (the rectangle's top left is at the UV's top right)
+            pub fn get_uv(&self, id:usize) -> Option<BoundingBox> {
+                let rectangle = self.rectangles.get(&id)?;
+                let page = &self.pages[rectangle.page];
+                Some(BoundingBox::new(
+                    rectangle.x as f32 / page.width as f32,
+                    rectangle.y as f32 / page.height as f32,
+                    (rectangle.x + rectangle.get_page_width()) as f32 / page.width as f32,
+                    (rectangle.y + rectangle.get_page_height()) as f32 / page.height as f32,
+                ))
+            }
+        }
+    //packing
+        impl AtlasPacker {
+            //returns an id for the rectangle, or None if it can't be fitted in (with a warning)
+            pub fn insert(&mut self, width:u32, height:u32) -> Option<usize> {
+                if width == 0 || height == 0 {
+                    println!("WARNING - AtlasPacker::insert : the rectangle has no area ({}x{}); nothing packed", width, height);
+                    return None;
+                }
+                let fits = |width:u32, height:u32| width + self.padding * 2 <= self.maximum_page_width && height + self.padding * 2 <= self.maximum_page_height;
+                let fits_either_way = fits(width, height) || (self.allow_rotation && fits(height, width));
+                if !fits_either_way {
+                    println!("WARNING - AtlasPacker::insert : {}x{} (with padding of {}) is too large for a page of at most {}x{}; nothing packed", width, height, self.padding, self.maximum_page_width, self.maximum_page_height);
+                    return None;
+                }
+
+                //into a page as it is
+                    for page in 0..self.pages.len() {
+                        if let Some(id) = self.insert_into_page(page, width, height) {
+                            return Some(id);
+                        }
+                    }
+
+                //into a grown page; those it wouldn't fit in even at their largest are left as they are
+                    for page in 0..self.pages.len() {
+                        let (page_width, page_height) = (self.maximum_page_width, self.maximum_page_height);
+                        if self.find_best_fit(&self.get_free_at_size(page, page_width, page_height), width, height).is_none() {
+                            continue;
+                        }
+                        while self.grow_page(page) {
+                            if let Some(id) = self.insert_into_page(page, width, height) {
+                                return Some(id);
+                            }
+                        }
+                    }
+
+                //into a new page, grown as far as it needs to be
+                    if self.pages.len() >= self.maximum_page_count {
+                        println!("WARNING - AtlasPacker::insert : every page is full, and there can't be more than {}; {}x{} left out", self.maximum_page_count, width, height);
+                        return None;
+                    }
+                    let page = self.pages.len();
+                    self.pages.push(Page {
+                        width: self.page_width,
+                        height: self.page_height,
+                        free: vec![],
+                        rectangles: vec![],
+                        used_area: 0,
+                        revision: 0,
+                    });
+                    self.rebuild_free(page);
+                    loop {
+                        if let Some(id) = self.insert_into_page(page, width, height) {
+                            return Some(id);
+                        }
+                        if !self.grow_page(page) {
+                            //can't happen, as it was checked to fit on an empty page of the maximum size
+                            self.pages.pop();
+                            return None;
+                        }
+                    }
+            }
+            //packs the largest first (which packs much more tightly than going in order), but returns the ids in the
+            //order given
+            pub fn insert_many(&mut self, sizes:&[(u32, u32)]) -> Vec<Option<usize>> {
+                let mut order:Vec<usize> = (0..sizes.len()).collect();
+                order.sort_by_key(|index| {
+                    let (width, height) = sizes[*index];
+                    std::cmp::Reverse((width.max(height), width.min(height)))
+                });
+
+                let mut output = vec![None; sizes.len()];
+                for index in order {
+                    output[index] = self.insert(sizes[index].0, sizes[index].1);
+                }
+                output
+            }
+
+            pub fn remove(&mut self, id:usize) -> bool {
+                let rectangle = match self.rectangles.remove(&id) {
+                    Some(rectangle) => rectangle,
+                    None => return false,
+                };
+                let page = &mut self.pages[rectangle.page];
+                page.rectangles.retain(|other| *other != id);
+                page.used_area -= rectangle.width as u64 * rectangle.height as u64;
+                self.rebuild_free(rectangle.page);
+                true
+            }
+
+            //pages are kept (at whatever size they've grown to), but emptied
+            pub fn clear(&mut self) {
+                self.rectangles.clear();
+                for page in 0..self.pages.len() {
+                    self.pages[page].rectangles.clear();
+                    self.pages[page].used_area = 0;
+                    self.rebuild_free(page);
+                }
+            }
+        }
+    //pages
+        impl AtlasPacker {
+            fn insert_into_page(&mut self, page_index:usize, width:u32, height:u32) -> Option<usize> {
+                let (used, rotated) = self.find_best_fit(&self.pages[page_index].free, width, height)?;
+
+                let id = self.next_id;
+                self.next_id += 1;
+                self.rectangles.insert(id, PackedRectangle { page: page_index, x: used.x, y: used.y, width, height, rotated });
+
+                let page = &mut self.pages[page_index];
+                page.rectangles.push(id);
+                page.used_area += width as u64 * height as u64;
+                AtlasPacker::split_free(&mut page.free, &used);
+                Some(id)
+            }
+
+            //where in the free rectangles the rectangle (with its padding) would go, and whether it'd be turned. The best
+            //fit has the least left over on its shorter side, then on its longer side
+            fn find_best_fit(&self, free_rectangles:&[Rectangle], width:u32, height:u32) -> Option<(Rectangle, bool)> {
+                let padding = self.padding;
+                let mut best:Option<((u32, u32), Rectangle, bool)> = None;
+                let orientations:&[bool] = if self.allow_rotation && width != height { &[false, true] } else { &[false] };
+                for free in free_rectangles {
+                    for rotated in orientations {
+                        let (needed_width, needed_height) = if *rotated { (height + padding, width + padding) } else { (width + padding, height + padding) };
+                        if needed_width > free.width || needed_height > free.height {
+                            continue;
+                        }
+                        let leftover_width = free.width - needed_width;
+                        let leftover_height = free.height - needed_height;
+                        let score = (leftover_width.min(leftover_height), leftover_width.max(leftover_height));
+                        let better = match best {
+                            Some((best_score, _, _)) => score < best_score,
+                            None => true,
+                        };
+                        if better {
+                            best = Some((score, Rectangle { x: free.x, y: free.y, width: needed_width, height: needed_height }, *rotated));
+                        }
+                    }
+                }
+                best.map(|(_, used, rotated)| (used, rotated))
+            }
+
+            //doubles the page's shorter side (or whichever side can still grow), returning false if it's as large as
+            //it can be
+            fn grow_page(&mut self, page_index:usize) -> bool {
+                let page = &mut self.pages[page_index];
+                let (old_width, old_height) = (page.width, page.height);
+                let can_grow_width = page.width < self.maximum_page_width;
+                let can_grow_height = page.height < self.maximum_page_height;
+                if can_grow_width && (page.width <= page.height || !can_grow_height) {
+                    page.width = page.width.saturating_mul(2).min(self.maximum_page_width);
+                } else if can_grow_height {
+                    page.height = page.height.saturating_mul(2).min(self.maximum_page_height);
+                }
+                if (page.width, page.height) == (old_width, old_height) {
+                    return false;
+                }
+                page.revision += 1;
+                self.rebuild_free(page_index);
+                true
+            }
+
+            //works out the page's free space again from the rectangles on it, which is slower than keeping track as
+            //rectangles come and go, but keeps every free rectangle as large as it can be
+            fn rebuild_free(&mut self, page_index:usize) {
+                let (width, height) = (self.pages[page_index].width, self.pages[page_index].height);
+                self.pages[page_index].free = self.get_free_at_size(page_index, width, height);
+            }
+            //the free rectangles the page would have were it the given size, with the rectangles on it where they are
+            fn get_free_at_size(&self, page_index:usize, width:u32, height:u32) -> Vec<Rectangle> {
+                let padding = self.padding;
+                let mut free = vec![];
+                if width > padding && height > padding {
+                    free.push(Rectangle { x: padding, y: padding, width: width - padding, height: height - padding });
+                }
+                for id in &self.pages[page_index].rectangles {
+                    let rectangle = &self.rectangles[id];
+                    let used = Rectangle {
+                        x: rectangle.x,
+                        y: rectangle.y,
+                        width: rectangle.get_page_width() + padding,
+                        height: rectangle.get_page_height() + padding,
+                    };
+                    AtlasPacker::split_free(&mut free, &used);
+                }
+                free
+            }
+
+            //takes the used rectangle out of the free ones, leaving the largest rectangles left of each either side of
+            //it, then drops those of the new pieces that are within others. (The free rectangles it missed can't be
+            //within a piece, as each piece is within a free rectangle it hit, and free rectangles are never within
+            //one another)
+            fn split_free(free:&mut Vec<Rectangle>, used:&Rectangle) {
+                let mut pieces = vec![];
+                free.retain(|free| {
+                    if !free.intersects(used) {
+                        return true;
+                    }
+                    if used.x > free.x {
+                        pieces.push(Rectangle { x: free.x, y: free.y, width: used.x - free.x, height: free.height });
+                    }
+                    if used.right() < free.right() {
+                        pieces.push(Rectangle { x: used.right(), y: free.y, width: free.right() - used.right(), height: free.height });
+                    }
+                    if used.y > free.y {
+                        pieces.push(Rectangle { x: free.x, y: free.y, width: free.width, height: used.y - free.y });
+                    }
+                    if used.bottom() < free.bottom() {
+                        pieces.push(Rectangle { x: free.x, y: used.bottom(), width: free.width, height: free.bottom() - used.bottom() });
+                    }
+                    false
+                });
+
+                let untouched = free.len();
+                for (index, piece) in pieces.iter().enumerate() {
+                    let contained = free[..untouched].iter().any(|other| other.contains(piece)) || pieces.iter().enumerate().any(|(other_index, other)| {
+                        //of two the same, the later goes
+                        other_index != index && other.contains(piece) && (other != piece || other_index < index)
+                    });
+                    if !contained {
+                        free.push(*piece);
+                    }
+                }
+            }
+        }
+    //printing
+        impl fmt::Display for AtlasPacker {
+            fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result {
+                write!(f, "{{rectangle_count:{}, pages:[", self.rectangles.len())?;
+                for (index, page) in self.pages.iter().enumerate() {
+                    write!(
+                        f, "{}{{width:{}, height:{}, rectangle_count:{}, occupancy:{}}}",
+                        if index == 0 { "" } else { ", " },
+                        page.width, page.height, page.rectangles.len(), self.get_page_occupancy(index).unwrap_or(0.0),
+                    )?;
+                }
+                write!(f, "]}}")
+            }
+        }
+        impl fmt::Debug for AtlasPacker {
+            fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { fmt::Display::fmt(self, f) }
+        }
+
+
+
+
+
+
+
+//tests
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        //every rectangle within its page, and kept apart from the others (and the edges) by the padding
+        fn assert_padded(packer:&AtlasPacker) {
+            let padding = packer.get_padding();
+            let placed:Vec<PackedRectangle> = (0..packer.next_id).filter_map(|id| packer.get_rectangle(id).copied()).collect();
+            for (index, rectangle) in placed.iter().enumerate() {
+                let (page_width, page_height) = packer.get_page_size(rectangle.page).unwrap();
+                assert!(rectangle.x >= padding && rectangle.y >= padding);
+                assert!(rectangle.x + rectangle.get_page_width() + padding <= page_width);
+                assert!(rectangle.y + rectangle.get_page_height() + padding <= page_height);
+
+                for other in placed[index + 1..].iter().filter(|other| other.page == rectangle.page) {
+                    let apart =
+                        rectangle.x + rectangle.get_page_width() + padding <= other.x || other.x + other.get_page_width() + padding <= rectangle.x ||
+                        rectangle.y + rectangle.get_page_height() + padding <= other.y || other.y + other.get_page_height() + padding <= rectangle.y
+                    ;
+                    assert!(apart, "{:?} and {:?} are closer than {}", rectangle, other, padding);
+                }
+            }
+        }
+
+        #[test]
+        fn keeps_rectangles_apart_by_the_padding() {
+            let mut packer = AtlasPacker::new(64, 64, 64, 64, 1, 2, false);
+            let ids:Vec<usize> = (0..9).map(|_| packer.insert(17, 17).unwrap()).collect();
+            assert_eq!(packer.get_rectangle(ids[0]).map(|rectangle| (rectangle.x, rectangle.y)), Some((2, 2)));
+            assert_padded(&packer);
+
+            //three across is all that fits: 2 + (17 + 2) * 3 = 59
+            assert_eq!(packer.insert(17, 17), None);
+        }
+
+        #[test]
+        fn turns_rectangles_that_only_fit_on_their_side() {
+            let mut packer = AtlasPacker::new(10, 30, 10, 30, 1, 0, true);
+            let id = packer.insert(30, 10).unwrap();
+            let rectangle = *packer.get_rectangle(id).unwrap();
+            assert!(rectangle.rotated);
+            assert_eq!((rectangle.width, rectangle.height, rectangle.get_page_width(), rectangle.get_page_height()), (30, 10, 10, 30));
+
+            let mut packer = AtlasPacker::new(10, 30, 10, 30, 1, 0, false);
+            assert_eq!(packer.insert(30, 10), None);
+        }
+
+        #[test]
+        fn grows_pages_before_starting_new_ones() {
+            let mut packer = AtlasPacker::new(16, 16, 32, 32, 4, 0, false);
+            packer.insert(16, 16).unwrap();
+            assert_eq!((packer.get_page_size(0), packer.get_page_revision(0)), (Some((16, 16)), Some(0)));
+
+            let id = packer.insert(16, 16).unwrap();
+            assert_eq!(packer.get_page_count(), 1);
+            assert_eq!((packer.get_page_size(0), packer.get_page_revision(0)), (Some((32, 16)), Some(1)));
+            assert_eq!(packer.get_uv(id).map(|uv| uv.get_top_left().get_x()), Some(0.5));
+
+            //a full page at its largest gives way to a new one
+            packer.insert(32, 16).unwrap();
+            assert_eq!(packer.get_page_size(0), Some((32, 32)));
+            let id = packer.insert(20, 20).unwrap();
+            assert_eq!((packer.get_page_count(), packer.get_rectangle(id).map(|rectangle| rectangle.page)), (2, Some(1)));
+            assert_eq!(packer.get_page_size(1), Some((32, 32)));
+            assert_padded(&packer);
+        }
+
+        #[test]
+        fn leaves_pages_alone_that_still_would_not_fit() {
+            let mut packer = AtlasPacker::new(16, 16, 32, 32, 1, 0, false);
+            packer.insert(16, 16).unwrap();
+
+            //would fit an empty page at the largest size, but not this one (and there can't be another)
+            assert_eq!(packer.insert(32, 32), None);
+            assert_eq!((packer.get_page_size(0), packer.get_page_revision(0)), (Some((16, 16)), Some(0)));
+        }
+
+        #[test]
+        fn starts_pages_with_no_size_at_one_pixel() {
+            let mut packer = AtlasPacker::new(0, 0, 16, 16, 1, 0, false);
+            let id = packer.insert(8, 8).unwrap();
+            assert_eq!(packer.get_rectangle(id).map(|rectangle| rectangle.page), Some(0));
+            assert_eq!(packer.get_page_size(0), Some((8, 8)));
+        }
+
+        #[test]
+        fn stops_at_the_maximum_page_count() {
+            let mut packer = AtlasPacker::new(16, 16, 16, 16, 2, 0, false);
+            assert!(packer.insert(16, 16).is_some());
+            assert!(packer.insert(16, 16).is_some());
+            assert_eq!(packer.get_page_count(), 2);
+            assert_eq!(packer.insert(16, 16), None);
+        }
+
+        #[test]
+        fn rebuilds_free_space_on_removal() {
+            let mut packer = AtlasPacker::new(32, 32, 32, 32, 1, 0, false);
+            let ids:Vec<usize> = (0..4).map(|_| packer.insert(16, 16).unwrap()).collect();
+            assert_eq!(packer.insert(16, 16), None);
+
+            //taking out two side by side leaves room for one as wide as both
+            let top:Vec<usize> = ids.iter().copied().filter(|id| packer.get_rectangle(*id).unwrap().y == 0).collect();
+            assert_eq!(top.len(), 2);
+            for id in &top {
+                assert!(packer.remove(*id));
+            }
+            assert!(!packer.remove(top[0]));
+            assert_eq!(packer.get_page_occupancy(0), Some(0.5));
+
+            let id = packer.insert(32, 16).unwrap();
+            assert_eq!(packer.get_rectangle(id).map(|rectangle| (rectangle.x, rectangle.y)), Some((0, 0)));
+            assert_padded(&packer);
+        }
+    }
@@ -0,0 +1,478 @@
+use std::fmt;
+
+use super::animation::{
+    Easing,
+    Tween,
+};
+use super::data_type::{
+    BoundingBox,
+    Point,
+    Viewbox,
+    ViewboxConstraints,
+};
+
+
+
+
+
+
+
+
+//drag
+    #[derive(Copy, Clone, PartialEq, Debug)]
+    enum DragMode {
+        Pan,
+        Rotate,
+    }
+    struct Drag {
+        mode: DragMode,
+        last_point: Point,
+        movement_since_update: (f32, f32), //screen pixels, for measuring the velocity inertia carries on with
+        angle: f32, //where a rotation has got to before any snapping (see ViewboxConstraints), so small movements add up
+    }
+
+
+
+
+
+
+
+
+//struct
+    //turns mouse, wheel, touch and keyboard input into changes to a Viewbox, which it owns. Everything it's told is in
+    //device pixels, as winit reports them; a drag moves the world along with the pointer, the wheel and pinching zoom around the
+    //pointer (keeping the world point underneath it where it is), and a drag with the rotation modifier held spins the
+    //world around the middle of the view. Inertia and transitions are stepped along by update, which wants calling once
+    //per orchestrator revolution with the current tick
+    pub struct CameraController {
+        viewbox: Viewbox,
+        initial_viewbox: Viewbox, //what reset returns to
+
+        //settings
+            wheel_zoom_factor: f32, //per line of scrolling; pixel scrolling is treated as 100 pixels per line
+            keyboard_zoom_factor: f32,
+            keyboard_pan_distance: f32, //logical pixels
+            keyboard_rotation_angle: f32, //radians
+            rotation_modifier: winit::event::ModifiersState, //held while dragging to rotate rather than pan
+            inertia_friction: Option<f32>, //the fraction of the velocity kept per tick once a drag is let go; None for no inertia
+            transition_duration: usize, //ticks that keyboard moves and animate_to take; 0 jumps straight there
+            transition_easing: Easing,
+
+        //state
+            tick: usize,
+            drag: Option<Drag>,
+            touches: Vec<(u64, Point)>,
+            velocity: (f32, f32), //screen pixels per tick
+            transition: Option<Tween<Viewbox>>,
+    }
+//new
+    impl CameraController {
+        pub fn new(viewbox:Viewbox) -> CameraController {
+            CameraController {
+                initial_viewbox: viewbox.clone(),
+                viewbox,
+
+                wheel_zoom_factor: 1.1,
+                keyboard_zoom_factor: 1.25,
+                keyboard_pan_distance: 50.0,
+                keyboard_rotation_angle: std::f32::consts::PI / 12.0,
+                rotation_modifier: winit::event::ModifiersState::SHIFT,
+                inertia_friction: Some(0.8),
+                transition_duration: 3,
+                transition_easing: Easing::CubicOut,
+
+                tick: 0,
+                drag: None,
+                touches: vec![],
+                velocity: (0.0, 0.0),
+                transition: None,
+            }
+        }
+    }
+//getters
+    impl CameraController {
+        pub fn get_viewbox(&self) -> &Viewbox { &self.viewbox }
+        pub fn get_wheel_zoom_factor(&self) -> f32 { self.wheel_zoom_factor }
+        pub fn get_keyboard_zoom_factor(&self) -> f32 { self.keyboard_zoom_factor }
+        pub fn get_keyboard_pan_distance(&self) -> f32 { self.keyboard_pan_distance }
+        pub fn get_keyboard_rotation_angle(&self) -> f32 { self.keyboard_rotation_angle }
+        pub fn get_rotation_modifier(&self) -> winit::event::ModifiersState { self.rotation_modifier }
+        pub fn get_inertia_friction(&self) -> Option<f32> { self.inertia_friction }
+        pub fn get_transition_duration(&self) -> usize { self.transition_duration }
+        pub fn get_transition_easing(&self) -> Easing { self.transition_easing }
+
+        pub fn is_dragging(&self) -> bool { self.drag.is_some() }
+        //whether update still has inertia or a transition to work through
+        pub fn is_moving(&self) -> bool {
+            self.transition.is_some() || self.velocity != (0.0, 0.0)
+        }
+    }
+//setters
+    impl CameraController {
+        //replaces the viewbox outright, stopping any movement
+        pub fn set_viewbox(&mut self, new:Viewbox) {
+            self.stop();
+            self.viewbox = new;
+        }
+        pub fn set_initial_viewbox(&mut self, new:Viewbox) { self.initial_viewbox = new; }
+        pub fn set_wheel_zoom_factor(&mut self, new:f32) { self.wheel_zoom_factor = new; }
+        pub fn set_keyboard_zoom_factor(&mut self, new:f32) { self.keyboard_zoom_factor = new; }
+        pub fn set_keyboard_pan_distance(&mut self, new:f32) { self.keyboard_pan_distance = new; }
+        pub fn set_keyboard_rotation_angle(&mut self, new:f32) { self.keyboard_rotation_angle = new; }
+        pub fn set_rotation_modifier(&mut self, new:winit::event::ModifiersState) { self.rotation_modifier = new; }
+        pub fn set_inertia_friction(&mut self, new:Option<f32>) {
+            if let Some(friction) = new {
+                if !(0.0..1.0).contains(&friction) {
+                    println!("WARNING - CameraController::set_inertia_friction : friction must be at least 0 and under 1 (was given {}); inertia left as it was", friction);
+                    return;
+                }
+            }
+            self.inertia_friction = new;
+            if new.is_none() {
+                self.velocity = (0.0, 0.0);
+            }
+        }
+        pub fn set_transition_duration(&mut self, new:usize) { self.transition_duration = new; }
+        pub fn set_transition_easing(&mut self, new:Easing) { self.transition_easing = new; }
+        //applies to the view now, and to where reset takes it
+        pub fn set_constraints(&mut self, new:ViewboxConstraints) {
+            self.stop();
+            self.initial_viewbox.set_constraints(new.clone());
+            self.viewbox.set_constraints(new);
+        }
+
+        //the window changed size (in logical pixels); the view keeps its position, scale and angle
+        pub fn set_size(&mut self, width:u32, height:u32) {
+            self.finish_transition();
+            self.viewbox.set_width(width);
+            self.viewbox.set_height(height);
+            self.initial_viewbox.set_width(width);
+            self.initial_viewbox.set_height(height);
+        }
+        //the window's scale factor, which changes when it moves to a display with a different density
+        pub fn set_device_pixel_ratio(&mut self, new:f32) {
+            self.finish_transition();
+            self.viewbox.set_device_pixel_ratio(new);
+            self.initial_viewbox.set_device_pixel_ratio(new);
+        }
+    }
+
+
+
+
+
+
+
+
+//movement
+    //each of these changes the viewbox straight away; input handling and transitions are built on top of them
+    impl CameraController {
+        //in device pixels, like everything else the controller is told
+        fn screen_centre(viewbox:&Viewbox) -> Point {
+            Point::new(
+                viewbox.get_width() as f32 * viewbox.get_device_pixel_ratio() / 2.0,
+                viewbox.get_height() as f32 * viewbox.get_device_pixel_ratio() / 2.0,
+            )
+        }
+        //moves the view so that the world point ends up at the screen point, given the scale and angle it has
+        fn place_world_point_at(viewbox:&mut Viewbox, world_point:&Point, screen_point:&Point) {
+            let current = viewbox.convert_point_to_world(screen_point);
+            viewbox.set_x( viewbox.get_x() + world_point.get_x() - current.get_x() );
+            viewbox.set_y( viewbox.get_y() + world_point.get_y() - current.get_y() );
+        }
+
+        fn pan_viewbox(viewbox:&mut Viewbox, x:f32, y:f32) {
+            let start = viewbox.convert_point_to_world(&Point::new(0.0, 0.0));
+            let end = viewbox.convert_point_to_world(&Point::new(x, y));
+            viewbox.set_x( viewbox.get_x() - (end.get_x() - start.get_x()) );
+            viewbox.set_y( viewbox.get_y() - (end.get_y() - start.get_y()) );
+        }
+        fn zoom_viewbox(viewbox:&mut Viewbox, factor:f32, screen_point:&Point) {
+            if !factor.is_finite() || factor <= 0.0 {
+                return;
+            }
+            let world_point = viewbox.convert_point_to_world(screen_point);
+            viewbox.set_scale( viewbox.get_scale() * factor );
+            CameraController::place_world_point_at(viewbox, &world_point, screen_point);
+        }
+        fn rotate_viewbox(viewbox:&mut Viewbox, angle:f32, screen_point:&Point) {
+            let angle = viewbox.get_angle() + angle;
+            CameraController::turn_viewbox_to(viewbox, angle, screen_point);
+        }
+        fn turn_viewbox_to(viewbox:&mut Viewbox, angle:f32, screen_point:&Point) {
+            let world_point = viewbox.convert_point_to_world(screen_point);
+            viewbox.set_angle(angle);
+            CameraController::place_world_point_at(viewbox, &world_point, screen_point);
+        }
+
+        //moves the world by x and y screen pixels (so the view itself goes the other way)
+        pub fn pan(&mut self, x:f32, y:f32) {
+            CameraController::pan_viewbox(&mut self.viewbox, x, y);
+        }
+        //multiplies the scale by the factor, keeping the world point at the screen point in place
+        pub fn zoom(&mut self, factor:f32, screen_point:&Point) {
+            CameraController::zoom_viewbox(&mut self.viewbox, factor, screen_point);
+        }
+        //spins the world by the angle (clockwise on screen) around the screen point
+        pub fn rotate(&mut self, angle:f32, screen_point:&Point) {
+            CameraController::rotate_viewbox(&mut self.viewbox, angle, screen_point);
+        }
+
+        //drops any inertia and transition
+        pub fn stop(&mut self) {
+            self.velocity = (0.0, 0.0);
+            self.transition = None;
+        }
+    }
+//transitions
+    impl CameraController {
+        //where the view will be once any running transition has finished
+        fn get_destination(&self) -> Viewbox {
+            match &self.transition {
+                Some(transition) => transition.get_value(usize::MAX),
+                None => self.viewbox.clone(),
+            }
+        }
+        //jumps to where a running transition was headed. The transition's values were taken before a resize (or change
+        //of pixel ratio), so it can't be left running through one without dragging the old size back in
+        fn finish_transition(&mut self) {
+            if self.transition.is_some() {
+                self.viewbox = self.get_destination();
+                self.transition = None;
+            }
+        }
+        //moves smoothly to the destination, over the transition duration
+        pub fn animate_to(&mut self, destination:Viewbox) {
+            self.velocity = (0.0, 0.0);
+
+            if self.transition_duration == 0 {
+                self.transition = None;
+                self.viewbox = destination;
+                return;
+            }
+
+            self.transition = Some(Tween::new(
+                self.tick,
+                self.viewbox.clone(),
+                destination,
+                self.transition_duration,
+                self.transition_easing,
+            ));
+        }
+        //back to the viewbox the controller started with
+        pub fn reset(&mut self) {
+            let destination = self.initial_viewbox.clone();
+            self.animate_to(destination);
+        }
+        //zoom to fit, keeping the current angle; padding is in screen pixels
+        pub fn fit_bounding_box(&mut self, bounding_box:&BoundingBox, padding:f32) {
+            let destination = self.viewbox.get_fitted_to_bounding_box(bounding_box, padding);
+            self.animate_to(destination);
+        }
+        pub fn centre_on_point(&mut self, point:&Point) {
+            let destination = self.viewbox.get_centred_on_point(point);
+            self.animate_to(destination);
+        }
+
+        //called once per revolution, with the orchestrator's tick
+        pub fn update(&mut self, tick:usize) {
+            self.tick = tick;
+
+            //transition
+                if let Some(transition) = &self.transition {
+                    self.viewbox = transition.get_value(tick);
+                    if transition.is_finished(tick) {
+                        self.transition = None;
+                    }
+                }
+
+            //velocity
+                if let Some(drag) = &mut self.drag {
+                    self.velocity = drag.movement_since_update;
+                    drag.movement_since_update = (0.0, 0.0);
+                    return;
+                }
+
+            //inertia
+                match self.inertia_friction {
+                    None => { self.velocity = (0.0, 0.0); },
+                    Some(friction) => {
+                        if self.velocity.0.hypot(self.velocity.1) < 0.5 {
+                            self.velocity = (0.0, 0.0);
+                            return;
+                        }
+                        self.pan(self.velocity.0, self.velocity.1);
+                        self.velocity = (self.velocity.0 * friction, self.velocity.1 * friction);
+                    },
+                }
+        }
+    }
+
+
+
+
+
+
+
+
+//input
+    impl CameraController {
+        //the primary button went down; the modifiers decide between panning and rotating
+        pub fn press(&mut self, point:&Point, modifiers:&winit::event::ModifiersState) {
+            self.stop();
+            self.drag = Some(Drag {
+                mode: if !self.rotation_modifier.is_empty() && modifiers.contains(self.rotation_modifier) { DragMode::Rotate } else { DragMode::Pan },
+                last_point: *point,
+                movement_since_update: (0.0, 0.0),
+                angle: self.viewbox.get_angle(),
+            });
+        }
+        pub fn pointer_move(&mut self, point:&Point) {
+            let drag = match &mut self.drag {
+                Some(drag) => drag,
+                None => return,
+            };
+
+            let (x, y) = ( point.get_x() - drag.last_point.get_x(), point.get_y() - drag.last_point.get_y() );
+            let last_point = drag.last_point;
+            drag.last_point = *point;
+
+            match drag.mode {
+                DragMode::Pan => {
+                    drag.movement_since_update = ( drag.movement_since_update.0 + x, drag.movement_since_update.1 + y );
+                    self.pan(x, y);
+                },
+                DragMode::Rotate => {
+                    let centre = CameraController::screen_centre(&self.viewbox);
+                    let before = (last_point.get_y() - centre.get_y()).atan2(last_point.get_x() - centre.get_x());
+                    let after = (point.get_y() - centre.get_y()).atan2(point.get_x() - centre.get_x());
+                    drag.angle += (after - before + std::f32::consts::PI).rem_euclid(std::f32::consts::PI * 2.0) - std::f32::consts::PI; //the short way round
+                    let angle = drag.angle;
+                    CameraController::turn_viewbox_to(&mut self.viewbox, angle, &centre);
+                },
+            }
+        }
+        //the primary button came back up; a pan that was still moving carries on under inertia
+        pub fn release(&mut self) {
+            if let Some(drag) = self.drag.take() {
+                if drag.mode == DragMode::Rotate || self.inertia_friction.is_none() {
+                    self.velocity = (0.0, 0.0);
+                }
+            }
+        }
+
+        pub fn wheel(&mut self, delta:&winit::event::MouseScrollDelta, point:&Point) {
+            let lines = match delta {
+                winit::event::MouseScrollDelta::LineDelta(_, y) => *y,
+                winit::event::MouseScrollDelta::PixelDelta(position) => position.y as f32 / 100.0,
+            };
+            self.stop();
+            self.zoom(self.wheel_zoom_factor.powf(lines), point);
+        }
+
+        //fingers on a touch screen; one drags the view, two pinch to zoom (around the point between them) and pan
+        //along with that point
+        pub fn touch(&mut self, id:u64, phase:winit::event::TouchPhase, point:&Point) {
+            match phase {
+                winit::event::TouchPhase::Started => {
+                    self.touches.retain(|(touch_id, _)| *touch_id != id);
+                    self.touches.push((id, *point));
+                    self.stop();
+                },
+                winit::event::TouchPhase::Moved => {
+                    let index = match self.touches.iter().position(|(touch_id, _)| *touch_id == id) {
+                        Some(index) => index,
+                        None => return,
+                    };
+                    let previous = self.touches[index].1;
+                    self.touches[index].1 = *point;
+
+                    match self.touches.len() {
+                        1 => {
+                            self.pan( point.get_x() - previous.get_x(), point.get_y() - previous.get_y() );
+                        },
+                        _ => {
+                            //the first two fingers down are the ones that count
+                            if index > 1 {
+                                return;
+                            }
+                            let other = self.touches[1 - index].1;
+
+                            let previous_middle = Point::new( (previous.get_x() + other.get_x()) / 2.0, (previous.get_y() + other.get_y()) / 2.0 );
+                            let middle = Point::new( (point.get_x() + other.get_x()) / 2.0, (point.get_y() + other.get_y()) / 2.0 );
+                            let previous_distance = (previous.get_x() - other.get_x()).hypot(previous.get_y() - other.get_y());
+                            let distance = (point.get_x() - other.get_x()).hypot(point.get_y() - other.get_y());
+
+                            self.pan( middle.get_x() - previous_middle.get_x(), middle.get_y() - previous_middle.get_y() );
+                            if previous_distance > 0.0 {
+                                self.zoom(distance / previous_distance, &middle);
+                            }
+                        },
+                    }
+                },
+                winit::event::TouchPhase::Ended | winit::event::TouchPhase::Cancelled => {
+                    self.touches.retain(|(touch_id, _)| *touch_id != id);
+                },
+            }
+        }
+
+        //arrows pan, plus and minus zoom, Q and E rotate (all around the middle of the view) and Home or 0 resets.
+        //Returns whether the key was one the controller uses
+        pub fn key_press(&mut self, key:winit::event::VirtualKeyCode) -> bool {
+            use winit::event::VirtualKeyCode;
+
+            let mut destination = self.get_destination();
+            let centre = CameraController::screen_centre(&destination);
+            let distance = self.keyboard_pan_distance * destination.get_device_pixel_ratio();
+
+            match key {
+                VirtualKeyCode::Left => CameraController::pan_viewbox(&mut destination, distance, 0.0),
+                VirtualKeyCode::Right => CameraController::pan_viewbox(&mut destination, -distance, 0.0),
+                VirtualKeyCode::Up => CameraController::pan_viewbox(&mut destination, 0.0, distance),
+                VirtualKeyCode::Down => CameraController::pan_viewbox(&mut destination, 0.0, -distance),
+                VirtualKeyCode::Equals | VirtualKeyCode::Plus | VirtualKeyCode::NumpadAdd => {
+                    CameraController::zoom_viewbox(&mut destination, self.keyboard_zoom_factor, &centre)
+                },
+                VirtualKeyCode::Minus | VirtualKeyCode::NumpadSubtract => {
+                    CameraController::zoom_viewbox(&mut destination, 1.0 / self.keyboard_zoom_factor, &centre)
+                },
+                VirtualKeyCode::Q => CameraController::rotate_viewbox(&mut destination, -self.keyboard_rotation_angle, &centre),
+                VirtualKeyCode::E => CameraController::rotate_viewbox(&mut destination, self.keyboard_rotation_angle, &centre),
+                VirtualKeyCode::Home | VirtualKeyCode::Key0 | VirtualKeyCode::Numpad0 => {
+                    self.reset();
+                    return true;
+                },
+                _ => return false,
+            }
+
+            self.animate_to(destination);
+            true
+        }
+    }
+
+
+
+
+
+
+
+
+//printing
+    impl CameraController {
+        fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result {
+            write!(
+                f, "{{viewbox:{}, dragging:{}, touches:{}, velocity:{:?}, transitioning:{}}}",
+                self.viewbox,
+                self.drag.is_some(),
+                self.touches.len(),
+                self.velocity,
+                self.transition.is_some(),
+            )
+        }
+    }
+    impl fmt::Display for CameraController {
+        fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { self.fmt(f) }
+    }
+    impl fmt::Debug for CameraController {
+        fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { self.fmt(f) }
+    }
\ No newline at end of file
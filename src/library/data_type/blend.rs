@@ -0,0 +1,81 @@
+use std::fmt;
+
+
+
+
+
+
+
+
+//composite operation
+    //the Porter-Duff operators; how much of the source (the colour being drawn) and destination (what's already there)
+    //survive, depending on where each of them is covered
+    #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+    pub enum CompositeOperation {
+        Clear,
+        Source,
+        Destination,
+        SourceOver,
+        DestinationOver,
+        SourceIn,
+        DestinationIn,
+        SourceOut,
+        DestinationOut,
+        SourceAtop,
+        DestinationAtop,
+        Xor,
+        Plus,
+    }
+    impl fmt::Display for CompositeOperation {
+        fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { fmt::Debug::fmt(self, f) }
+    }
+
+
+
+
+
+
+
+
+//blend mode
+    //how the source and destination colours mix where they overlap (composited source-over everywhere else)
+    #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+    pub enum BlendMode {
+        Normal,
+        Multiply,
+        Screen,
+        Overlay,
+        Darken,
+        Lighten,
+    }
+    impl fmt::Display for BlendMode {
+        fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { fmt::Debug::fmt(self, f) }
+    }
+
+
+
+
+
+
+
+
+//draw blend
+    //the choice made for each draw
+    #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+    pub enum DrawBlend {
+        Composite(CompositeOperation),
+        Mode(BlendMode),
+    }
+    impl DrawBlend {
+        pub fn new_default() -> DrawBlend {
+            DrawBlend::Composite(CompositeOperation::SourceOver)
+        }
+    }
+    impl fmt::Display for DrawBlend {
+        fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result {
+            match self {
+                DrawBlend::Composite(operation) => write!(f, "Composite({})", operation),
+                DrawBlend::Mode(mode) => write!(f, "Mode({})", mode),
+            }
+        }
+    }
\ No newline at end of file
@@ -0,0 +1,19 @@
+use std::fmt;
+
+
+
+
+
+
+
+
+//which of the elements under the pointer a callback answers to. Elements can overlap, so there may be several
+//under the pointer at once; the first hit is the top-most of them
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum CallbackActivationMode {
+    FirstHit, //only the top-most element (or, for a callback on a group, when the top-most element is in that group)
+    AllHits, //every element under the pointer; a callback on a group is called once for each element in it that's hit
+}
+impl fmt::Display for CallbackActivationMode {
+    fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { fmt::Debug::fmt(self, f) }
+}
\ No newline at end of file
@@ -0,0 +1,31 @@
+use std::fmt;
+
+
+
+
+
+
+
+
+//the kinds of pointer event that callbacks can be added for
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum CallbackType {
+    PointerEnter,
+    PointerLeave,
+    PointerMove,
+    PointerDown,
+    PointerUp,
+    Click,
+    DoubleClick,
+    Wheel,
+}
+impl CallbackType {
+    //entering and leaving go straight to each node that the pointer has come onto or gone off; everything else goes
+    //down through the groups above the element hit (capture), to the element, and back up again (bubble)
+    pub fn propagates(&self) -> bool {
+        !matches!(self, CallbackType::PointerEnter | CallbackType::PointerLeave)
+    }
+}
+impl fmt::Display for CallbackType {
+    fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { fmt::Debug::fmt(self, f) }
+}
\ No newline at end of file
@@ -1,5 +1,11 @@
 use std::fmt;
 
+use super::blend::{
+    BlendMode,
+    CompositeOperation,
+};
+use super::css_colour_names::lookup_css_colour_name;
+
 
 
 
@@ -46,6 +52,245 @@ impl Colour {
     pub fn premultiplied_b(&self) -> f32 { self.premultiplied_b }
 }
 
+//linear
+//the r, g and b values are sRGB encoded, as picked in a design tool or written in CSS. The GPU blends in linear light
+//(encoding back to sRGB as it writes to the Bgra8UnormSrgb swap chain), so anything headed to the GPU wants these instead
+impl Colour {
+    pub fn new_from_linear(r:f32, g:f32, b:f32, a:f32) -> Colour {
+        Colour::new(
+            linear_to_srgb(r.clamp(0.0, 1.0)),
+            linear_to_srgb(g.clamp(0.0, 1.0)),
+            linear_to_srgb(b.clamp(0.0, 1.0)),
+            a,
+        )
+    }
+
+    pub fn linear_r(&self) -> f32 { srgb_to_linear(self.r) }
+    pub fn linear_g(&self) -> f32 { srgb_to_linear(self.g) }
+    pub fn linear_b(&self) -> f32 { srgb_to_linear(self.b) }
+
+    //what the renderer's (premultiplied alpha) blending expects
+    pub fn to_premultiplied_linear(self) -> [f32; 4] {
+        [
+            self.linear_r() * self.a,
+            self.linear_g() * self.a,
+            self.linear_b() * self.a,
+            self.a,
+        ]
+    }
+}
+
+//compositing
+//the same sums the renderer's blending does, worked on the linear premultiplied values, so these can stand as a reference
+//for what should end up on screen
+impl Colour {
+    fn new_from_premultiplied_linear(values:[f32; 4]) -> Colour {
+        let a = values[3].clamp(0.0, 1.0);
+        if a <= 0.0 {
+            return Colour::new(0.0, 0.0, 0.0, 0.0);
+        }
+        Colour::new_from_linear(values[0] / a, values[1] / a, values[2] / a, a)
+    }
+
+    pub fn composite(source:&Colour, destination:&Colour, operation:CompositeOperation) -> Colour {
+        let s = source.to_premultiplied_linear();
+        let d = destination.to_premultiplied_linear();
+        let (source_alpha, destination_alpha) = (s[3], d[3]);
+
+        //the fraction of each that survives
+        let (source_factor, destination_factor) = match operation {
+            CompositeOperation::Clear => (0.0, 0.0),
+            CompositeOperation::Source => (1.0, 0.0),
+            CompositeOperation::Destination => (0.0, 1.0),
+            CompositeOperation::SourceOver => (1.0, 1.0 - source_alpha),
+            CompositeOperation::DestinationOver => (1.0 - destination_alpha, 1.0),
+            CompositeOperation::SourceIn => (destination_alpha, 0.0),
+            CompositeOperation::DestinationIn => (0.0, source_alpha),
+            CompositeOperation::SourceOut => (1.0 - destination_alpha, 0.0),
+            CompositeOperation::DestinationOut => (0.0, 1.0 - source_alpha),
+            CompositeOperation::SourceAtop => (destination_alpha, 1.0 - source_alpha),
+            CompositeOperation::DestinationAtop => (1.0 - destination_alpha, source_alpha),
+            CompositeOperation::Xor => (1.0 - destination_alpha, 1.0 - source_alpha),
+            CompositeOperation::Plus => (1.0, 1.0),
+        };
+
+        let mut output = [0.0; 4];
+        for channel in 0..4 {
+            output[channel] = (s[channel] * source_factor + d[channel] * destination_factor).min(1.0);
+        }
+        Colour::new_from_premultiplied_linear(output)
+    }
+
+    pub fn blend(source:&Colour, destination:&Colour, mode:BlendMode) -> Colour {
+        let s = source.to_premultiplied_linear();
+        let d = destination.to_premultiplied_linear();
+        let (source_alpha, destination_alpha) = (s[3], d[3]);
+
+        let mix = |backdrop:f32, source:f32| -> f32 {
+            match mode {
+                BlendMode::Normal => source,
+                BlendMode::Multiply => backdrop * source,
+                BlendMode::Screen => backdrop + source - backdrop * source,
+                BlendMode::Overlay => if backdrop <= 0.5 {
+                    2.0 * backdrop * source
+                } else {
+                    let doubled = 2.0 * backdrop - 1.0;
+                    doubled + source - doubled * source
+                },
+                BlendMode::Darken => backdrop.min(source),
+                BlendMode::Lighten => backdrop.max(source),
+            }
+        };
+
+        //where both are present they mix, elsewhere each shows through as it would with source-over
+        let mut output = [0.0; 4];
+        for channel in 0..3 {
+            let source_straight = if source_alpha > 0.0 { s[channel] / source_alpha } else { 0.0 };
+            let destination_straight = if destination_alpha > 0.0 { d[channel] / destination_alpha } else { 0.0 };
+            output[channel] =
+                s[channel] * (1.0 - destination_alpha) +
+                d[channel] * (1.0 - source_alpha) +
+                source_alpha * destination_alpha * mix(destination_straight, source_straight)
+            ;
+        }
+        output[3] = source_alpha + destination_alpha - source_alpha * destination_alpha;
+        Colour::new_from_premultiplied_linear(output)
+    }
+}
+
+//other colour spaces
+//hues are in degrees, everything else (saturation, lightness, value) runs from 0 to 1, with the exception of OKLab's
+//a and b and OKLCH's chroma which are roughly within -0.4 to 0.4 and 0 to 0.4 respectively
+impl Colour {
+    pub fn new_from_rgba8(r:u8, g:u8, b:u8, a:u8) -> Colour {
+        Colour::new( r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0, a as f32 / 255.0 )
+    }
+    pub fn to_rgba8(self) -> (u8, u8, u8, u8) {
+        let convert = |value:f32| (value * 255.0).round() as u8;
+        (convert(self.r), convert(self.g), convert(self.b), convert(self.a))
+    }
+
+    pub fn new_from_hsl(h:f32, s:f32, l:f32, a:f32) -> Colour {
+        let s = s.clamp(0.0, 1.0);
+        let l = l.clamp(0.0, 1.0);
+        let chroma = (1.0 - (2.0 * l - 1.0).abs()) * s;
+        let (r, g, b) = hue_to_rgb(h, chroma);
+        let m = l - chroma / 2.0;
+        Colour::new(r + m, g + m, b + m, a)
+    }
+    pub fn to_hsl(self) -> (f32, f32, f32) {
+        let (hue, maximum, minimum) = rgb_to_hue(self.r, self.g, self.b);
+        let l = (maximum + minimum) / 2.0;
+        let s = if maximum == minimum { 0.0 } else { (maximum - minimum) / (1.0 - (2.0 * l - 1.0).abs()) };
+        (hue, s, l)
+    }
+
+    pub fn new_from_hsv(h:f32, s:f32, v:f32, a:f32) -> Colour {
+        let s = s.clamp(0.0, 1.0);
+        let v = v.clamp(0.0, 1.0);
+        let chroma = v * s;
+        let (r, g, b) = hue_to_rgb(h, chroma);
+        let m = v - chroma;
+        Colour::new(r + m, g + m, b + m, a)
+    }
+    pub fn to_hsv(self) -> (f32, f32, f32) {
+        let (hue, maximum, minimum) = rgb_to_hue(self.r, self.g, self.b);
+        let s = if maximum == 0.0 { 0.0 } else { (maximum - minimum) / maximum };
+        (hue, s, maximum)
+    }
+
+    //OKLab is perceptual; equal steps through it look like equal changes in colour
+    pub fn new_from_oklab(lightness:f32, a:f32, b:f32, alpha:f32) -> Colour {
+        let l = (lightness + 0.39633778 * a + 0.21580376 * b).powi(3);
+        let m = (lightness - 0.105561346 * a - 0.06385417 * b).powi(3);
+        let s = (lightness - 0.08948418 * a - 1.2914855 * b).powi(3);
+
+        Colour::new(
+            linear_to_srgb( 4.0767417 * l - 3.3077116 * m + 0.23096994 * s ),
+            linear_to_srgb( -1.268438 * l + 2.6097574 * m - 0.34131938 * s ),
+            linear_to_srgb( -0.0041960864 * l - 0.7034186 * m + 1.7076147 * s ),
+            alpha,
+        )
+    }
+    pub fn to_oklab(self) -> (f32, f32, f32) {
+        let (r, g, b) = (srgb_to_linear(self.r), srgb_to_linear(self.g), srgb_to_linear(self.b));
+
+        let l = (0.41222146 * r + 0.53633255 * g + 0.051445995 * b).cbrt();
+        let m = (0.2119035 * r + 0.6806995 * g + 0.10739696 * b).cbrt();
+        let s = (0.08830246 * r + 0.28171885 * g + 0.6299787 * b).cbrt();
+
+        (
+            0.21045426 * l + 0.7936178 * m - 0.004072047 * s,
+            1.9779985 * l - 2.4285922 * m + 0.4505937 * s,
+            0.025904037 * l + 0.78277177 * m - 0.80867577 * s,
+        )
+    }
+    pub fn new_from_oklch(lightness:f32, chroma:f32, hue:f32, alpha:f32) -> Colour {
+        let hue = hue.to_radians();
+        Colour::new_from_oklab(lightness, chroma * hue.cos(), chroma * hue.sin(), alpha)
+    }
+    pub fn to_oklch(self) -> (f32, f32, f32) {
+        let (lightness, a, b) = self.to_oklab();
+        (lightness, (a * a + b * b).sqrt(), b.atan2(a).to_degrees().rem_euclid(360.0))
+    }
+}
+
+//parsing and formatting
+impl Colour {
+    //accepts hex (#rgb, #rgba, #rrggbb, #rrggbbaa), the CSS rgb()/rgba() and hsl()/hsla() functions (with commas or
+    //spaces, and an optional "/ alpha"), the CSS named colours and "transparent". Case and surrounding space are ignored
+    pub fn new_from_css(text:&str) -> Option<Colour> {
+        let text = text.trim().to_ascii_lowercase();
+
+        if let Some(hex) = text.strip_prefix('#') {
+            return parse_hex(hex);
+        }
+        if text == "transparent" {
+            return Some(Colour::new(0.0, 0.0, 0.0, 0.0));
+        }
+        if let Some((r, g, b)) = lookup_css_colour_name(&text) {
+            return Some(Colour::new_from_rgba8(r, g, b, 255));
+        }
+
+        let open = text.find('(')?;
+        let name = text[..open].trim();
+        let arguments = text[open+1..].strip_suffix(')')?;
+        let values:Vec<&str> = arguments.split(|c:char| c == ',' || c == '/' || c.is_whitespace()).filter(|value| !value.is_empty()).collect();
+        if values.len() != 3 && values.len() != 4 {
+            return None;
+        }
+        let alpha = match values.get(3) {
+            Some(value) => parse_css_number(value, 1.0)?,
+            None => 1.0,
+        };
+
+        match name {
+            "rgb" | "rgba" => Some(Colour::new(
+                parse_css_number(values[0], 255.0)? / 255.0,
+                parse_css_number(values[1], 255.0)? / 255.0,
+                parse_css_number(values[2], 255.0)? / 255.0,
+                alpha,
+            )),
+            "hsl" | "hsla" => Some(Colour::new_from_hsl(
+                parse_css_hue(values[0])?,
+                parse_css_number(values[1], 1.0)?,
+                parse_css_number(values[2], 1.0)?,
+                alpha,
+            )),
+            _ => None,
+        }
+    }
+    //#rrggbb, or #rrggbbaa when the colour isn't opaque
+    pub fn to_hex(self) -> String {
+        let (r, g, b, a) = self.to_rgba8();
+        if a == 255 {
+            format!("#{:02x}{:02x}{:02x}", r, g, b)
+        } else {
+            format!("#{:02x}{:02x}{:02x}{:02x}", r, g, b, a)
+        }
+    }
+}
+
 impl Colour {
     fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result {
         write!(
@@ -66,4 +311,80 @@ impl fmt::Display for Colour {
 }
 impl fmt::Debug for Colour {
     fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { self.fmt(f) }
-}
\ No newline at end of file
+}
+
+
+
+
+
+
+
+
+//utilities
+    pub fn srgb_to_linear(value:f32) -> f32 {
+        if value <= 0.04045 { value / 12.92 } else { ((value + 0.055) / 1.055).powf(2.4) }
+    }
+    pub fn linear_to_srgb(value:f32) -> f32 {
+        if value <= 0.0031308 { value * 12.92 } else { 1.055 * value.powf(1.0 / 2.4) - 0.055 }
+    }
+    //the red, green and blue for a hue at the given chroma, before lightness (or value) is added on
+    fn hue_to_rgb(hue:f32, chroma:f32) -> (f32, f32, f32) {
+        let hue = hue.rem_euclid(360.0) / 60.0;
+        let x = chroma * (1.0 - (hue % 2.0 - 1.0).abs());
+        match hue as usize {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        }
+    }
+    //the hue (in degrees), along with the largest and smallest of the three channels
+    fn rgb_to_hue(r:f32, g:f32, b:f32) -> (f32, f32, f32) {
+        let maximum = r.max(g).max(b);
+        let minimum = r.min(g).min(b);
+        let delta = maximum - minimum;
+
+        let hue = if delta == 0.0 {
+            0.0
+        } else if maximum == r {
+            60.0 * ((g - b) / delta).rem_euclid(6.0)
+        } else if maximum == g {
+            60.0 * ((b - r) / delta + 2.0)
+        } else {
+            60.0 * ((r - g) / delta + 4.0)
+        };
+
+        (hue, maximum, minimum)
+    }
+    fn parse_hex(hex:&str) -> Option<Colour> {
+        if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+        let digit = |index:usize| u8::from_str_radix(&hex[index..index+1], 16).ok().map(|value| value * 17);
+        let pair = |index:usize| u8::from_str_radix(&hex[index..index+2], 16).ok();
+
+        match hex.len() {
+            3 => Some(Colour::new_from_rgba8(digit(0)?, digit(1)?, digit(2)?, 255)),
+            4 => Some(Colour::new_from_rgba8(digit(0)?, digit(1)?, digit(2)?, digit(3)?)),
+            6 => Some(Colour::new_from_rgba8(pair(0)?, pair(2)?, pair(4)?, 255)),
+            8 => Some(Colour::new_from_rgba8(pair(0)?, pair(2)?, pair(4)?, pair(6)?)),
+            _ => None,
+        }
+    }
+    //a plain number, or a percentage of the given maximum
+    fn parse_css_number(value:&str, maximum:f32) -> Option<f32> {
+        match value.strip_suffix('%') {
+            Some(percentage) => percentage.parse::<f32>().ok().map(|number| number / 100.0 * maximum),
+            None => value.parse::<f32>().ok(),
+        }
+    }
+    //in degrees; accepts the deg, rad, grad and turn units (or none, for degrees)
+    fn parse_css_hue(value:&str) -> Option<f32> {
+        if let Some(number) = value.strip_suffix("deg") { return number.parse::<f32>().ok(); }
+        if let Some(number) = value.strip_suffix("grad") { return number.parse::<f32>().ok().map(|number| number * 0.9); }
+        if let Some(number) = value.strip_suffix("rad") { return number.parse::<f32>().ok().map(|number| number.to_degrees()); }
+        if let Some(number) = value.strip_suffix("turn") { return number.parse::<f32>().ok().map(|number| number * 360.0); }
+        value.parse::<f32>().ok()
+    }
\ No newline at end of file
@@ -0,0 +1,101 @@
+use std::fmt;
+
+use super::callback_type::CallbackType;
+use super::geometry::Point;
+
+
+
+
+
+
+
+
+//event phase
+    //where an event is on its way from the root to the element hit, and back
+    #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+    pub enum EventPhase {
+        Capture, //at a group above the target, on the way down
+        Target, //at the target itself
+        Bubble, //at a group above the target, on the way back up
+    }
+    impl fmt::Display for EventPhase {
+        fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { fmt::Debug::fmt(self, f) }
+    }
+
+
+
+
+
+
+
+
+//event
+    //what a callback is given. Nodes are scene graph ids
+    pub struct Event {
+        callback_type: CallbackType,
+        phase: EventPhase,
+        target: usize, //the element hit (or, for entering and leaving, the node entered or left)
+        current_target: usize, //the node whose callback is being called
+        hit_index: usize, //how far down the target is among the elements under the pointer; 0 is the top-most
+        point: Point, //in world space
+        wheel_delta: Point, //in lines; only for Wheel events
+        propagation_stopped: bool,
+    }
+    //new
+        impl Event {
+            pub fn new(callback_type:CallbackType, target:usize, hit_index:usize, point:Point) -> Event {
+                Event {
+                    callback_type,
+                    phase: EventPhase::Target,
+                    target,
+                    current_target: target,
+                    hit_index,
+                    point,
+                    wheel_delta: Point::new(0.0, 0.0),
+                    propagation_stopped: false,
+                }
+            }
+        }
+    //getters
+        impl Event {
+            pub fn get_callback_type(&self) -> CallbackType { self.callback_type }
+            pub fn get_phase(&self) -> EventPhase { self.phase }
+            pub fn get_target(&self) -> usize { self.target }
+            pub fn get_current_target(&self) -> usize { self.current_target }
+            pub fn get_hit_index(&self) -> usize { self.hit_index }
+            pub fn get_point(&self) -> &Point { &self.point }
+            pub fn get_wheel_delta(&self) -> &Point { &self.wheel_delta }
+            pub fn is_propagation_stopped(&self) -> bool { self.propagation_stopped }
+        }
+    //setters
+        impl Event {
+            pub fn set_phase(&mut self, new:EventPhase) { self.phase = new; }
+            pub fn set_current_target(&mut self, new:usize) { self.current_target = new; }
+            pub fn set_wheel_delta(&mut self, new:Point) { self.wheel_delta = new; }
+
+            //the rest of the node's callbacks are still called, but the event goes no further; not to the nodes after
+            //it, nor to any other elements under the pointer
+            pub fn stop_propagation(&mut self) { self.propagation_stopped = true; }
+        }
+    //printing
+        impl Event {
+            fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result {
+                write!(
+                    f, "{{callback_type:{}, phase:{}, target:{}, current_target:{}, hit_index:{}, point:{}, wheel_delta:{}, propagation_stopped:{}}}",
+                    self.callback_type,
+                    self.phase,
+                    self.target,
+                    self.current_target,
+                    self.hit_index,
+                    self.point,
+                    self.wheel_delta,
+                    self.propagation_stopped,
+                )
+            }
+        }
+        impl fmt::Display for Event {
+            fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { self.fmt(f) }
+        }
+        impl fmt::Debug for Event {
+            fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { self.fmt(f) }
+        }
\ No newline at end of file
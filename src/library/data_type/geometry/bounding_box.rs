@@ -1,6 +1,11 @@
 use std::fmt;
 
 use super::point::Point;
+use super::polyline::Polyline;
+use super::ray::Ray;
+use super::infinite_line::InfiniteLine;
+
+use super::super::offset::Offset;
 
 //struct
     #[derive(Copy, Clone)]
@@ -133,6 +138,65 @@ use super::point::Point;
         pub fn get_bottom_right(&self) -> &Point {
             &self.bottom_right
         }
+        pub fn get_expanded(&self, margin:f32) -> BoundingBox {
+            BoundingBox::new(
+                self.top_left.get_x() - margin,
+                self.top_left.get_y() - margin,
+                self.bottom_right.get_x() + margin,
+                self.bottom_right.get_y() + margin,
+            )
+        }
+    }
+//transform
+    //a bounding box can't itself be rotated, so rotating one (or applying an offset with an angle) gives the box that
+    //surrounds the rotated corners. The get_ versions leave the box alone and return a moved copy
+    impl BoundingBox {
+        fn get_corners(&self) -> [Point;4] {
+            [
+                self.top_left,
+                Point::new(self.bottom_right.get_x(), self.top_left.get_y()),
+                self.bottom_right,
+                Point::new(self.top_left.get_x(), self.bottom_right.get_y()),
+            ]
+        }
+
+        pub fn translate(&mut self, x:f32, y:f32) {
+            self.top_left.translate(x, y);
+            self.bottom_right.translate(x, y);
+        }
+        pub fn rotate(&mut self, angle:f32, anchor:&Point) {
+            let corners = self.get_corners();
+            *self = BoundingBox::new_from_points( &corners.iter().map(|corner| corner.get_rotated(angle, anchor)).collect::<Vec<Point>>() );
+        }
+        pub fn scale(&mut self, scale:f32, anchor:&Point) {
+            //a negative scale swaps the corners over
+            *self = BoundingBox::new_from_points(&[ self.top_left.get_scaled(scale, anchor), self.bottom_right.get_scaled(scale, anchor) ]);
+        }
+        pub fn apply_offset(&mut self, offset:&Offset) {
+            let corners = self.get_corners();
+            *self = BoundingBox::new_from_points( &corners.iter().map(|corner| corner.get_with_offset_applied(offset)).collect::<Vec<Point>>() );
+        }
+
+        pub fn get_translated(&self, x:f32, y:f32) -> BoundingBox {
+            let mut output = *self;
+            output.translate(x, y);
+            output
+        }
+        pub fn get_rotated(&self, angle:f32, anchor:&Point) -> BoundingBox {
+            let mut output = *self;
+            output.rotate(angle, anchor);
+            output
+        }
+        pub fn get_scaled(&self, scale:f32, anchor:&Point) -> BoundingBox {
+            let mut output = *self;
+            output.scale(scale, anchor);
+            output
+        }
+        pub fn get_with_offset_applied(&self, offset:&Offset) -> BoundingBox {
+            let mut output = *self;
+            output.apply_offset(offset);
+            output
+        }
     }
 //printing
     impl BoundingBox {
@@ -165,6 +229,15 @@ use super::point::Point;
             self.get_top_left().get_x() <= other.get_bottom_right().get_x()
         }
         //line //currently not needed
+        pub fn intersect_with_polyline(&self, polyline:&Polyline) -> bool {
+            polyline.intersect_with_bounding_box(self)
+        }
+        pub fn intersect_with_ray(&self, ray:&Ray) -> bool {
+            ray.intersect_with_bounding_box(self)
+        }
+        pub fn intersect_with_infinite_line(&self, infinite_line:&InfiniteLine) -> bool {
+            infinite_line.intersect_with_bounding_box(self)
+        }
         //simple_poly //currently not needed
         //complex_polygon  //currently not needed
     }
\ No newline at end of file
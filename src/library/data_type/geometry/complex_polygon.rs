@@ -1,6 +1,8 @@
 use std::fmt;
 
+use super::fill_rule::FillRule;
 use super::intersect_result_types::{
+    EdgeContact,
     LinePolygonIntersectionResult,
     PolygonIntersectionResult,
     PolySide,
@@ -9,8 +11,16 @@ use super::intersect_result_types::{
 use super::bounding_box::BoundingBox;
 use super::point::Point;
 use super::line::Line;
+use super::polyline::Polyline;
 use super::simple_polygon::SimplePolygon;
 
+use super::super::offset::Offset;
+
+use super::super::super::math::triangulation::{
+    constrained_delaunay,
+    TriangulationRefinement,
+};
+
 
 
 
@@ -89,6 +99,100 @@ use super::simple_polygon::SimplePolygon;
         pub fn get_bounding_box(&self) -> &BoundingBox {
             self.body.get_bounding_box()
         }
+        //changes whenever any of the polygon's rings do
+        pub fn get_revision(&self) -> u64 {
+            let mut revision = self.body.get_revision();
+            for hole in &self.holes {
+                revision = revision.wrapping_add(hole.get_revision());
+            }
+            revision
+        }
+        //every edge of the polygon; the body's first, followed by those of each hole in turn
+        pub fn get_all_lines(&self) -> Vec<Line> {
+            let mut output:Vec<Line> = self.body.get_lines().clone();
+            for hole in &self.holes {
+                output.extend_from_slice(hole.get_lines());
+            }
+            output
+        }
+        pub fn get_fill_rule(&self) -> FillRule {
+            self.body.get_fill_rule()
+        }
+    }
+//setters
+    impl ComplexPolygon {
+        pub fn set_fill_rule(&mut self, new:FillRule) {
+            self.body.set_fill_rule(new);
+            for hole in &mut self.holes {
+                hole.set_fill_rule(new);
+            }
+        }
+    }
+//transform
+    //the get_ versions leave the polygon alone and return a moved copy
+    impl ComplexPolygon {
+        pub fn translate(&mut self, x:f32, y:f32) {
+            self.body.translate(x, y);
+            for hole in &mut self.holes {
+                hole.translate(x, y);
+            }
+        }
+        pub fn rotate(&mut self, angle:f32, anchor:&Point) {
+            self.body.rotate(angle, anchor);
+            for hole in &mut self.holes {
+                hole.rotate(angle, anchor);
+            }
+        }
+        pub fn scale(&mut self, scale:f32, anchor:&Point) {
+            self.body.scale(scale, anchor);
+            for hole in &mut self.holes {
+                hole.scale(scale, anchor);
+            }
+        }
+        pub fn apply_offset(&mut self, offset:&Offset) {
+            self.body.apply_offset(offset);
+            for hole in &mut self.holes {
+                hole.apply_offset(offset);
+            }
+        }
+
+        pub fn get_translated(&self, x:f32, y:f32) -> ComplexPolygon {
+            let mut output = self.clone();
+            output.translate(x, y);
+            output
+        }
+        pub fn get_rotated(&self, angle:f32, anchor:&Point) -> ComplexPolygon {
+            let mut output = self.clone();
+            output.rotate(angle, anchor);
+            output
+        }
+        pub fn get_scaled(&self, scale:f32, anchor:&Point) -> ComplexPolygon {
+            let mut output = self.clone();
+            output.scale(scale, anchor);
+            output
+        }
+        pub fn get_with_offset_applied(&self, offset:&Offset) -> ComplexPolygon {
+            let mut output = self.clone();
+            output.apply_offset(offset);
+            output
+        }
+    }
+//measurement
+    impl ComplexPolygon {
+        pub fn get_area(&self) -> f32 {
+            let mut area = self.body.get_area();
+            for hole in &self.holes {
+                area -= hole.get_area();
+            }
+            area
+        }
+        pub fn get_perimeter(&self) -> f32 {
+            let mut perimeter = self.body.get_perimeter();
+            for hole in &self.holes {
+                perimeter += hole.get_perimeter();
+            }
+            perimeter
+        }
     }
 //sub triangles
     impl ComplexPolygon {
@@ -126,6 +230,10 @@ use super::simple_polygon::SimplePolygon;
 
             output
         }
+        //triangles that keep to the body's and holes' edges, and are otherwise as close to equilateral as possible
+        pub fn to_constrained_delaunay_triangles(&self, refinement:&TriangulationRefinement) -> Vec<SimplePolygon> {
+            constrained_delaunay(&self.body, &self.holes, refinement)
+        }
     }
 //printing
     impl ComplexPolygon {
@@ -153,7 +261,10 @@ use super::simple_polygon::SimplePolygon;
     //intersect
     impl ComplexPolygon {
         pub fn intersect_with_point(&self, point:&Point) -> PolySide {
-            let body_result = self.body.intersect_with_point(point);
+            self.intersect_with_point_with_fill_rule(point, self.get_fill_rule())
+        }
+        pub fn intersect_with_point_with_fill_rule(&self, point:&Point, fill_rule:FillRule) -> PolySide {
+            let body_result = self.body.intersect_with_point_with_fill_rule(point, fill_rule);
 
             if body_result != PolySide::Inside {
                 return body_result;
@@ -163,7 +274,7 @@ use super::simple_polygon::SimplePolygon;
             //so, better check if it is or not
 
             for hole in &self.holes {
-                match hole.intersect_with_point(point) {
+                match hole.intersect_with_point_with_fill_rule(point, fill_rule) {
                     PolySide::Outside => { continue; },
                     PolySide::Inside => { return PolySide::Outside; },
                     on_point_or_on_edge => { return on_point_or_on_edge; },
@@ -173,6 +284,9 @@ use super::simple_polygon::SimplePolygon;
             body_result
         }
         //bounding box //currently not needed
+        pub fn intersect_with_polyline(&self, polyline:&Polyline) -> LinePolygonIntersectionResult {
+            polyline.intersect_with_complex_polygon(self)
+        }
         pub fn intersect_with_line(&self, line:&Line) -> LinePolygonIntersectionResult {
             let mut body_result = self.body.intersect_with_line(line);
             if !body_result.intersect && !body_result.contact {
@@ -271,6 +385,18 @@ use super::simple_polygon::SimplePolygon;
 
             body_result
         }
+        pub fn intersect_with_simple_polygon_with_fill_rule(&self, simple_polygon:&SimplePolygon, fill_rule:FillRule) -> PolygonIntersectionResult {
+            //containment is decided by each polygon's own fill rule, so if either one differs, work with copies that use the requested rule
+            if self.get_fill_rule() == fill_rule && simple_polygon.get_fill_rule() == fill_rule {
+                return self.intersect_with_simple_polygon(simple_polygon);
+            }
+
+            let mut self_copy = self.clone();
+            let mut simple_polygon_copy = simple_polygon.clone();
+            self_copy.set_fill_rule(fill_rule);
+            simple_polygon_copy.set_fill_rule(fill_rule);
+            self_copy.intersect_with_simple_polygon(&simple_polygon_copy)
+        }
         // pub fn intersect_with_complex_polygon(&self, complex_polygon:&ComplexPolygon) -> PolygonIntersectionResult {
         //     let mut body_result = self.body.intersect_with_complex_polygon(complex_polygon);
         //     console_log!("{}", body_result);
@@ -322,4 +448,32 @@ use super::simple_polygon::SimplePolygon;
 
         //     body_result
         // }
+    }
+//intersect (extended results)
+    //contacts give the index of the edge (matching get_all_lines) and position along it, for both shapes
+    impl ComplexPolygon {
+        pub fn intersect_with_line__contacts(&self, line:&Line) -> Vec<EdgeContact> {
+            if !self.get_bounding_box().intersect_with_bounding_box(&line.get_bounding_box()) {
+                return vec![];
+            }
+            Line::intersect_lines_with_lines__contacts(&self.get_all_lines(), &[*line])
+        }
+        pub fn intersect_with_polyline__contacts(&self, polyline:&Polyline) -> Vec<EdgeContact> {
+            if !self.get_bounding_box().intersect_with_bounding_box(polyline.get_bounding_box()) {
+                return vec![];
+            }
+            Line::intersect_lines_with_lines__contacts(&self.get_all_lines(), polyline.get_lines())
+        }
+        pub fn intersect_with_simple_polygon__contacts(&self, simple_polygon:&SimplePolygon) -> Vec<EdgeContact> {
+            if !self.get_bounding_box().intersect_with_bounding_box(simple_polygon.get_bounding_box()) {
+                return vec![];
+            }
+            Line::intersect_lines_with_lines__contacts(&self.get_all_lines(), simple_polygon.get_lines())
+        }
+        pub fn intersect_with_complex_polygon__contacts(&self, other:&ComplexPolygon) -> Vec<EdgeContact> {
+            if !self.get_bounding_box().intersect_with_bounding_box(other.get_bounding_box()) {
+                return vec![];
+            }
+            Line::intersect_lines_with_lines__contacts(&self.get_all_lines(), &other.get_all_lines())
+        }
     }
\ No newline at end of file
@@ -0,0 +1,33 @@
+use std::fmt;
+
+
+
+
+
+//the rule used to decide which areas of a (possibly self-overlapping) polygon count as "inside"
+//EvenOdd - a point is inside if a ray cast from it crosses the perimeter an odd number of times
+//NonZero - a point is inside if the perimeter winds around it a non-zero number of times (SVG's default)
+#[derive(Copy, Clone, PartialEq)]
+pub enum FillRule {
+    EvenOdd,
+    NonZero,
+}
+impl FillRule {
+    pub fn new_default() -> FillRule {
+        FillRule::EvenOdd
+    }
+}
+impl FillRule {
+    fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            FillRule::EvenOdd => write!(f,"EvenOdd"),
+            FillRule::NonZero => write!(f,"NonZero"),
+        }
+    }
+}
+impl fmt::Display for FillRule {
+    fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { self.fmt(f) }
+}
+impl fmt::Debug for FillRule {
+    fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { self.fmt(f) }
+}
\ No newline at end of file
@@ -0,0 +1,158 @@
+use std::fmt;
+
+use super::intersect_result_types::{
+    LineSide,
+    LineIntersectionResult,
+    LinePolygonIntersectionResult,
+    PathIntersectionResult,
+};
+
+use super::point::Point;
+use super::bounding_box::BoundingBox;
+use super::line::Line;
+use super::polyline::Polyline;
+use super::ray::Ray;
+use super::simple_polygon::SimplePolygon;
+use super::complex_polygon::ComplexPolygon;
+use super::multi_polygon::MultiPolygon;
+
+use super::super::super::math::{
+    cartesian_angle_adjust,
+    parametric_line_clip,
+    parametric_line_intersect,
+};
+
+
+
+
+
+
+
+
+//struct
+    //a line that extends forever in both directions, running through point_a and point_b
+    #[derive(Copy, Clone)]
+    pub struct InfiniteLine {
+        point_a: Point,
+        point_b: Point,
+    }
+//new
+    impl InfiniteLine {
+        pub fn new_from_points(point_a:Point, point_b:Point) -> InfiniteLine {
+            InfiniteLine {
+                point_a,
+                point_b,
+            }
+        }
+        pub fn new_from_values(point_a_x:f32, point_a_y:f32, point_b_x:f32, point_b_y:f32) -> InfiniteLine {
+            InfiniteLine::new_from_points(Point::new(point_a_x,point_a_y), Point::new(point_b_x,point_b_y))
+        }
+        pub fn new_from_point_and_angle(point:Point, angle:f32) -> InfiniteLine {
+            let direction = cartesian_angle_adjust(1.0, 0.0, angle);
+            InfiniteLine::new_from_points(
+                point,
+                Point::new(point.get_x() + direction.get_x(), point.get_y() + direction.get_y()),
+            )
+        }
+        pub fn new_from_line(line:&Line) -> InfiniteLine {
+            InfiniteLine::new_from_points(*line.get_point_a(), *line.get_point_b())
+        }
+    }
+//getters
+    impl InfiniteLine {
+        pub fn get_point_a(&self) -> &Point { &self.point_a }
+        pub fn get_point_b(&self) -> &Point { &self.point_b }
+        pub fn get_angle(&self) -> f32 {
+            (self.point_b.get_y() - self.point_a.get_y()).atan2(self.point_b.get_x() - self.point_a.get_x())
+        }
+        //the section of the line that falls within the bounding box, if any
+        pub fn get_segment_within_bounding_box(&self, bounding_box:&BoundingBox) -> Option<Line> {
+            parametric_line_clip(&self.point_a, &self.point_b, f32::NEG_INFINITY, f32::INFINITY, bounding_box).map(|(t_min, t_max)| {
+                Line::new_from_points(self.get_point_at_parameter(t_min), self.get_point_at_parameter(t_max))
+            })
+        }
+        fn get_point_at_parameter(&self, t:f32) -> Point {
+            Point::new(
+                self.point_a.get_x() + (self.point_b.get_x() - self.point_a.get_x()) * t,
+                self.point_a.get_y() + (self.point_b.get_y() - self.point_a.get_y()) * t,
+            )
+        }
+        //a finite stand-in for the line that's guaranteed to run clean past the bounding box, so its ends don't land on anything within it
+        fn get_segment_beyond_bounding_box(&self, bounding_box:&BoundingBox) -> Option<Line> {
+            let width = bounding_box.get_bottom_right().get_x() - bounding_box.get_top_left().get_x();
+            let height = bounding_box.get_bottom_right().get_y() - bounding_box.get_top_left().get_y();
+            self.get_segment_within_bounding_box( &bounding_box.get_expanded(width.max(height) * 0.1 + 1.0) )
+        }
+    }
+//setters
+    impl InfiniteLine {
+        pub fn set_point_a(&mut self, new:Point) { self.point_a = new; }
+        pub fn set_point_b(&mut self, new:Point) { self.point_b = new; }
+    }
+//printing
+    impl InfiniteLine {
+        fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result {
+            write!(
+                f, "{{point_a:{},point_b:{}}}",
+                self.point_a,
+                self.point_b,
+            )
+        }
+    }
+    impl fmt::Display for InfiniteLine {
+        fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { self.fmt(f) }
+    }
+    impl fmt::Debug for InfiniteLine {
+        fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { self.fmt(f) }
+    }
+//intersect
+    impl InfiniteLine {
+        pub fn intersect_with_point(&self, point:&Point) -> LineSide {
+            match Line::new_from_points(self.point_a, self.point_b).intersect_with_point(point) {
+                LineSide::AlignedButOff => LineSide::Upon, //there is no "off" for a line without ends
+                side => side,
+            }
+        }
+        pub fn intersect_with_bounding_box(&self, bounding_box:&BoundingBox) -> bool {
+            self.get_segment_within_bounding_box(bounding_box).is_some()
+        }
+        pub fn intersect_with_line(&self, line:&Line) -> LineIntersectionResult {
+            match self.get_segment_beyond_bounding_box(&line.get_bounding_box()) {
+                None => LineIntersectionResult { point:None, range:None, contact:false, intersect:false, direction:None },
+                Some(segment) => segment.intersect_with_line(line),
+            }
+        }
+        pub fn intersect_with_polyline(&self, polyline:&Polyline) -> PathIntersectionResult {
+            match self.get_segment_beyond_bounding_box(polyline.get_bounding_box()) {
+                None => PathIntersectionResult::new(vec![], false, false),
+                Some(segment) => polyline.intersect_with_line(&segment),
+            }
+        }
+        pub fn intersect_with_ray(&self, ray:&Ray) -> Option<Point> {
+            ray.intersect_with_infinite_line(self)
+        }
+        pub fn intersect_with_infinite_line(&self, other:&InfiniteLine) -> Option<Point> {
+            //parallel lines (including identical ones) have no single point of intersection
+            parametric_line_intersect(&self.point_a, &self.point_b, &other.point_a, &other.point_b).map(|(t, _)| self.get_point_at_parameter(t))
+        }
+        pub fn intersect_with_simple_polygon(&self, simple_polygon:&SimplePolygon) -> LinePolygonIntersectionResult {
+            self.intersect_with_polygon(simple_polygon.get_bounding_box(), |segment| simple_polygon.intersect_with_line(segment))
+        }
+        pub fn intersect_with_complex_polygon(&self, complex_polygon:&ComplexPolygon) -> LinePolygonIntersectionResult {
+            self.intersect_with_polygon(complex_polygon.get_bounding_box(), |segment| complex_polygon.intersect_with_line(segment))
+        }
+        pub fn intersect_with_multi_polygon(&self, multi_polygon:&MultiPolygon) -> LinePolygonIntersectionResult {
+            self.intersect_with_polygon(multi_polygon.get_bounding_box(), |segment| multi_polygon.intersect_with_line(segment))
+        }
+
+        fn intersect_with_polygon<F:Fn(&Line) -> LinePolygonIntersectionResult>(&self, bounding_box:&BoundingBox, segment_test:F) -> LinePolygonIntersectionResult {
+            match self.get_segment_beyond_bounding_box(bounding_box) {
+                None => LinePolygonIntersectionResult { points:vec![], contact:false, intersect:false, inverse_intersect:true, traverse:false },
+                Some(segment) => {
+                    let mut output_result = segment_test(&segment);
+                    output_result.inverse_intersect = true; //the line always carries on out beyond the polygon
+                    output_result
+                },
+            }
+        }
+    }
\ No newline at end of file
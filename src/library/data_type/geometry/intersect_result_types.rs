@@ -2,6 +2,7 @@ use std::fmt;
 
 use super::point::Point;
 use super::line::Line;
+use super::ray::Ray;
 
 
 
@@ -222,4 +223,136 @@ impl PartialEq for PolygonIntersectionResult {
         }
         points
     }
+}
+
+pub struct PathIntersectionResult {
+    pub points: Vec<Point>,
+    pub contact: bool, //the paths touch in some way
+    pub intersect: bool, //the paths cross each other
+}
+impl PathIntersectionResult {
+    pub fn new(points:Vec<Point>, contact:bool, intersect:bool) -> PathIntersectionResult {
+        PathIntersectionResult {
+            points,
+            contact,
+            intersect,
+        }
+    }
+}
+impl PathIntersectionResult {
+    fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result {
+        write!(f,"{{points:{:?}, contact:{}, intersect:{}}}", self.points, self.contact, self.intersect)
+    }
+}
+impl fmt::Display for PathIntersectionResult {
+    fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { self.fmt(f) }
+}
+impl fmt::Debug for PathIntersectionResult {
+    fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { self.fmt(f) }
+}
+impl PartialEq for PathIntersectionResult {
+    fn eq(&self, other:&Self) -> bool {
+        self.points == other.points && self.contact == other.contact && self.intersect == other.intersect
+    }
+}
+
+//where two rays meet; rays that lie along one another share a segment (facing each other) or a ray (facing the same way)
+#[derive(Copy, Clone)]
+pub enum RayIntersectionResult {
+    Point(Point),
+    Segment(Line),
+    Ray(Ray),
+}
+impl RayIntersectionResult {
+    fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RayIntersectionResult::Point(point) => write!(f,"{{point:{}}}", point),
+            RayIntersectionResult::Segment(segment) => write!(f,"{{segment:{}}}", segment),
+            RayIntersectionResult::Ray(ray) => write!(f,"{{ray:{}}}", ray),
+        }
+    }
+}
+impl fmt::Display for RayIntersectionResult {
+    fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { self.fmt(f) }
+}
+impl fmt::Debug for RayIntersectionResult {
+    fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { self.fmt(f) }
+}
+impl PartialEq for RayIntersectionResult {
+    fn eq(&self, other:&Self) -> bool {
+        match (self, other) {
+            (RayIntersectionResult::Point(a), RayIntersectionResult::Point(b)) => a == b,
+            (RayIntersectionResult::Segment(a), RayIntersectionResult::Segment(b)) => a == b,
+            (RayIntersectionResult::Ray(a), RayIntersectionResult::Ray(b)) => a.get_origin() == b.get_origin() && a.get_point_b() == b.get_point_b(),
+            _ => false,
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq)]
+pub enum ContactKind {
+    Crossing, //the edges cross each other, somewhere other than their ends
+    Touch, //the edges meet at a single point, at the end of at least one of them
+    Overlap, //the edges lie along one another; contacts of this kind come in pairs, marking the start and end of the shared range
+}
+impl ContactKind {
+    fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ContactKind::Crossing => write!(f,"Crossing"),
+            ContactKind::Touch => write!(f,"Touch"),
+            ContactKind::Overlap => write!(f,"Overlap"),
+        }
+    }
+}
+impl fmt::Display for ContactKind {
+    fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { self.fmt(f) }
+}
+impl fmt::Debug for ContactKind {
+    fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { self.fmt(f) }
+}
+
+#[derive(Copy, Clone)]
+pub struct EdgeContact {
+    pub point: Point,
+    pub first_edge_index: usize, //the index of the edge on the first shape (the one the function was called on)
+    pub first_t: f32, //how far along that edge the contact is (0.0 at its start, 1.0 at its end)
+    pub second_edge_index: usize, //the index of the edge on the second shape
+    pub second_t: f32, //how far along that edge the contact is
+    pub kind: ContactKind,
+}
+impl EdgeContact {
+    pub fn new(point:Point, first_edge_index:usize, first_t:f32, second_edge_index:usize, second_t:f32, kind:ContactKind) -> EdgeContact {
+        EdgeContact {
+            point,
+            first_edge_index,
+            first_t,
+            second_edge_index,
+            second_t,
+            kind,
+        }
+    }
+}
+impl EdgeContact {
+    fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f, "{{point:{}, first_edge_index:{}, first_t:{}, second_edge_index:{}, second_t:{}, kind:{}}}",
+            self.point, self.first_edge_index, self.first_t, self.second_edge_index, self.second_t, self.kind,
+        )
+    }
+}
+impl fmt::Display for EdgeContact {
+    fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { self.fmt(f) }
+}
+impl fmt::Debug for EdgeContact {
+    fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { self.fmt(f) }
+}
+impl PartialEq for EdgeContact {
+    fn eq(&self, other:&Self) -> bool {
+        self.point == other.point &&
+        self.first_edge_index == other.first_edge_index &&
+        self.first_t == other.first_t &&
+        self.second_edge_index == other.second_edge_index &&
+        self.second_t == other.second_t &&
+        self.kind == other.kind
+    }
 }
\ No newline at end of file
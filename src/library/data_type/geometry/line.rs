@@ -1,16 +1,72 @@
 use std::fmt;
 
 use super::intersect_result_types::{
+    ContactKind,
     Direction,
+    EdgeContact,
     LineSide,
     LineIntersectionResult,
     LinePolygonIntersectionResult,
+    PathIntersectionResult,
 };
 
 use super::point::Point;
 use super::bounding_box::BoundingBox;
+use super::polyline::Polyline;
 use super::simple_polygon::SimplePolygon;
 use super::complex_polygon::ComplexPolygon;
+use super::multi_polygon::MultiPolygon;
+
+use super::super::offset::Offset;
+
+
+
+
+
+
+
+
+//utilities
+    //the far ends of the edges that meet at a contact's point, for one of the shapes
+    fn gather_edge_ends(lines:&[Line], contacts:&[EdgeContact], first:bool, output:&mut Vec<Point>) {
+        let mut seen:Vec<usize> = vec![];
+        for contact in contacts {
+            let (index, t) = if first { (contact.first_edge_index, contact.first_t) } else { (contact.second_edge_index, contact.second_t) };
+            if seen.contains(&index) {
+                continue;
+            }
+            seen.push(index);
+
+            let line = &lines[index];
+            if t != 1.0 { output.push(line.point_b); }
+            if t != 0.0 { output.push(line.point_a); }
+        }
+    }
+    //looking around the point, the first shape crosses the second if exactly one of the second's two edges falls
+    //between the first's two. Anything other than a simple pass through on each side (a path's end, several edges
+    //meeting at the point, or edges lying along one another) is a Touch
+    fn classify_vertex_contact(first:&[Line], second:&[Line], contacts:&[EdgeContact]) -> ContactKind {
+        let point = contacts[0].point;
+        let mut first_ends:Vec<Point> = vec![];
+        let mut second_ends:Vec<Point> = vec![];
+        gather_edge_ends(first, contacts, true, &mut first_ends);
+        gather_edge_ends(second, contacts, false, &mut second_ends);
+        if first_ends.len() != 2 || second_ends.len() != 2 {
+            return ContactKind::Touch;
+        }
+
+        let angle = |end:&Point| (end.get_y() - point.get_y()).atan2(end.get_x() - point.get_x());
+        let start = angle(&first_ends[0]);
+        let around = |end:&Point| (angle(end) - start).rem_euclid(std::f32::consts::PI * 2.0);
+        let first_sweep = around(&first_ends[1]);
+        let second_a = around(&second_ends[0]);
+        let second_b = around(&second_ends[1]);
+
+        if [second_a, second_b].iter().any(|&angle| angle == 0.0 || angle == first_sweep) {
+            return ContactKind::Touch;
+        }
+        if (second_a < first_sweep) != (second_b < first_sweep) { ContactKind::Crossing } else { ContactKind::Touch }
+    }
 
 
 
@@ -69,6 +125,27 @@ use super::complex_polygon::ComplexPolygon;
         pub fn get_bounding_box(&self) -> BoundingBox {
             BoundingBox::new_from_points(&[self.point_a, self.point_b])
         }
+        pub fn get_point_at_parameter(&self, t:f32) -> Point {
+            if t == 0.0 { return self.point_a; }
+            if t == 1.0 { return self.point_b; }
+            Point::new(
+                self.point_a.get_x() + (self.point_b.get_x() - self.point_a.get_x()) * t,
+                self.point_a.get_y() + (self.point_b.get_y() - self.point_a.get_y()) * t,
+            )
+        }
+        //how far along the line (0.0 at point a, 1.0 at point b) the closest position to the point is
+        pub fn get_parameter_of_point(&self, point:&Point) -> f32 {
+            let delta_x = self.point_b.get_x() - self.point_a.get_x();
+            let delta_y = self.point_b.get_y() - self.point_a.get_y();
+            let length_squared = delta_x * delta_x + delta_y * delta_y;
+            if length_squared == 0.0 {
+                return 0.0;
+            }
+            ((point.get_x() - self.point_a.get_x()) * delta_x + (point.get_y() - self.point_a.get_y()) * delta_y) / length_squared
+        }
+        pub fn get_length(&self) -> f32 {
+            (self.point_b.get_x() - self.point_a.get_x()).hypot(self.point_b.get_y() - self.point_a.get_y())
+        }
     }
 //setters
     impl Line {
@@ -79,6 +156,47 @@ use super::complex_polygon::ComplexPolygon;
             self.point_b.set_from_point_ref(b);
         }
     }
+//transform
+    //the get_ versions leave the line alone and return a moved copy
+    impl Line {
+        pub fn translate(&mut self, x:f32, y:f32) {
+            self.point_a.translate(x, y);
+            self.point_b.translate(x, y);
+        }
+        pub fn rotate(&mut self, angle:f32, anchor:&Point) {
+            self.point_a.rotate(angle, anchor);
+            self.point_b.rotate(angle, anchor);
+        }
+        pub fn scale(&mut self, scale:f32, anchor:&Point) {
+            self.point_a.scale(scale, anchor);
+            self.point_b.scale(scale, anchor);
+        }
+        pub fn apply_offset(&mut self, offset:&Offset) {
+            self.point_a.apply_offset(offset);
+            self.point_b.apply_offset(offset);
+        }
+
+        pub fn get_translated(&self, x:f32, y:f32) -> Line {
+            let mut output = *self;
+            output.translate(x, y);
+            output
+        }
+        pub fn get_rotated(&self, angle:f32, anchor:&Point) -> Line {
+            let mut output = *self;
+            output.rotate(angle, anchor);
+            output
+        }
+        pub fn get_scaled(&self, scale:f32, anchor:&Point) -> Line {
+            let mut output = *self;
+            output.scale(scale, anchor);
+            output
+        }
+        pub fn get_with_offset_applied(&self, offset:&Offset) -> Line {
+            let mut output = *self;
+            output.apply_offset(offset);
+            output
+        }
+    }
 //printing
     impl Line {
         fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result {
@@ -247,10 +365,120 @@ use super::complex_polygon::ComplexPolygon;
                     direction: direction,
                 }
         }
+
+        //extended results, giving the edge and position on each line of every contact
+        pub fn intersect_with_line__contacts(&self, other:&Line) -> Vec<EdgeContact> {
+            if !self.get_bounding_box().intersect_with_bounding_box(&other.get_bounding_box()) {
+                return vec![];
+            }
+
+            let self_delta_x = self.point_b.get_x() - self.point_a.get_x();
+            let self_delta_y = self.point_b.get_y() - self.point_a.get_y();
+            let other_delta_x = other.point_b.get_x() - other.point_a.get_x();
+            let other_delta_y = other.point_b.get_y() - other.point_a.get_y();
+            let between_x = other.point_a.get_x() - self.point_a.get_x();
+            let between_y = other.point_a.get_y() - self.point_a.get_y();
+
+            let denominator = self_delta_x * other_delta_y - self_delta_y * other_delta_x;
+
+            //parallel lines
+                if denominator == 0.0 {
+                    //parallel but not on the same line, so no contact
+                        if between_x * self_delta_y - between_y * self_delta_x != 0.0 {
+                            return vec![];
+                        }
+
+                    //this line is just a point
+                        let self_length_squared = self_delta_x * self_delta_x + self_delta_y * self_delta_y;
+                        if self_length_squared == 0.0 {
+                            return if other.intersect_with_point(&self.point_a) == LineSide::Upon {
+                                vec![ EdgeContact::new(self.point_a, 0, 0.0, 0, other.get_parameter_of_point(&self.point_a), ContactKind::Touch) ]
+                            } else {
+                                vec![]
+                            };
+                        }
+
+                    //project the other line onto this one, to find the range they share
+                        let t_other_a = (between_x * self_delta_x + between_y * self_delta_y) / self_length_squared;
+                        let t_other_b = t_other_a + (other_delta_x * self_delta_x + other_delta_y * self_delta_y) / self_length_squared;
+                        let t_start = t_other_a.min(t_other_b).max(0.0);
+                        let t_end = t_other_a.max(t_other_b).min(1.0);
+
+                        if t_start > t_end {
+                            return vec![];
+                        }
+
+                        let point_start = self.get_point_at_parameter(t_start);
+                        if t_start == t_end {
+                            return vec![ EdgeContact::new(point_start, 0, t_start, 0, other.get_parameter_of_point(&point_start), ContactKind::Touch) ];
+                        }
+                        let point_end = self.get_point_at_parameter(t_end);
+                        return vec![
+                            EdgeContact::new(point_start, 0, t_start, 0, other.get_parameter_of_point(&point_start), ContactKind::Overlap),
+                            EdgeContact::new(point_end, 0, t_end, 0, other.get_parameter_of_point(&point_end), ContactKind::Overlap),
+                        ];
+                }
+
+            //otherwise...
+                let t = (between_x * other_delta_y - between_y * other_delta_x) / denominator;
+                let u = (between_x * self_delta_y - between_y * self_delta_x) / denominator;
+                if !(0.0..=1.0).contains(&t) || !(0.0..=1.0).contains(&u) {
+                    return vec![];
+                }
+
+                let kind = if t > 0.0 && t < 1.0 && u > 0.0 && u < 1.0 { ContactKind::Crossing } else { ContactKind::Touch };
+                //contacts at an end of the other line take its point exactly, so that contacts at a shared vertex agree
+                let point = if u == 0.0 {
+                    other.point_a
+                } else if u == 1.0 {
+                    other.point_b
+                } else {
+                    self.get_point_at_parameter(t)
+                };
+                vec![ EdgeContact::new(point, 0, t, 0, u, kind) ]
+        }
+        //run every line in the first set against every line in the second, with contacts carrying the index of the lines involved
+        //
+        //a line passing through a vertex touches both edges that meet there; those contacts are merged into one (the
+        //first found), which is a Crossing if the edges either side of the vertex are on opposite sides of the other
+        //shape's path and a Touch if they're on the same side
+        pub fn intersect_lines_with_lines__contacts(first:&[Line], second:&[Line]) -> Vec<EdgeContact> {
+            let mut output:Vec<EdgeContact> = vec![];
+            let mut vertex_groups:Vec<(usize, Vec<EdgeContact>)> = vec![]; //index in the output, and every Touch at that point
+            for (first_index, first_line) in first.iter().enumerate() {
+                for (second_index, second_line) in second.iter().enumerate() {
+                    for mut contact in first_line.intersect_with_line__contacts(second_line) {
+                        contact.first_edge_index = first_index;
+                        contact.second_edge_index = second_index;
+
+                        if contact.kind == ContactKind::Touch {
+                            match vertex_groups.iter_mut().find(|(_, group)| group[0].point == contact.point) {
+                                Some((_, group)) => { group.push(contact); continue; },
+                                None => vertex_groups.push((output.len(), vec![contact])),
+                            }
+                        }
+                        output.push(contact);
+                    }
+                }
+            }
+
+            for (output_index, group) in vertex_groups {
+                if group.len() > 1 {
+                    output[output_index].kind = classify_vertex_contact(first, second, &group);
+                }
+            }
+            output
+        }
+        pub fn intersect_with_polyline(&self, polyline:&Polyline) -> PathIntersectionResult {
+            polyline.intersect_with_line(self)
+        }
         pub fn intersect_with_simple_polygon(&self, simple_polygon:&SimplePolygon) -> LinePolygonIntersectionResult {
             simple_polygon.intersect_with_line(self)
         }
         pub fn intersect_with_complex_polygon(&self, complex_polygon:&ComplexPolygon) -> LinePolygonIntersectionResult {
             complex_polygon.intersect_with_line(self)
         }
+        pub fn intersect_with_multi_polygon(&self, multi_polygon:&MultiPolygon) -> LinePolygonIntersectionResult {
+            multi_polygon.intersect_with_line(self)
+        }
     }
\ No newline at end of file
@@ -1,6 +1,10 @@
 mod intersect_result_types;
 pub use intersect_result_types::{*};
 
+mod fill_rule;
+#[allow(unused_imports)]
+pub use fill_rule::{*};
+
 mod point;
 pub use point::{*};
 
@@ -10,8 +14,24 @@ pub use bounding_box::{*};
 mod line;
 pub use line::{*};
 
+mod polyline;
+#[allow(unused_imports)]
+pub use polyline::{*};
+
+mod ray;
+#[allow(unused_imports)]
+pub use ray::{*};
+
+mod infinite_line;
+#[allow(unused_imports)]
+pub use infinite_line::{*};
+
 mod simple_polygon;
 pub use simple_polygon::{*};
 
 mod complex_polygon;
-pub use complex_polygon::{*};
\ No newline at end of file
+pub use complex_polygon::{*};
+
+mod multi_polygon;
+#[allow(unused_imports)]
+pub use multi_polygon::{*};
\ No newline at end of file
@@ -0,0 +1,412 @@
+use std::fmt;
+
+use super::intersect_result_types::{
+    EdgeContact,
+    LinePolygonIntersectionResult,
+    PolygonIntersectionResult,
+    PolySide,
+};
+
+use super::fill_rule::FillRule;
+use super::bounding_box::BoundingBox;
+use super::point::Point;
+use super::line::Line;
+use super::polyline::Polyline;
+use super::simple_polygon::SimplePolygon;
+use super::complex_polygon::ComplexPolygon;
+
+
+
+
+
+
+
+
+//utilities
+    fn search_for_duplicate_point_in_vector(vector:&[Point], point:&Point) -> bool {
+        vector.iter().any(|p| p == point)
+    }
+    fn produce_bounding_box(polygons:&[ComplexPolygon]) -> BoundingBox {
+        let mut corners:Vec<Point> = vec![];
+        for polygon in polygons {
+            corners.push(*polygon.get_bounding_box().get_top_left());
+            corners.push(*polygon.get_bounding_box().get_bottom_right());
+        }
+        BoundingBox::new_from_points(&corners)
+    }
+    fn ring_contains_ring(outer:&SimplePolygon, inner:&SimplePolygon) -> bool {
+        //rings given to the classifier aren't expected to cross one another, so the first of the inner ring's points
+        //that isn't sitting on the outer ring's perimeter is enough to tell whether it's inside or not
+        if !outer.get_bounding_box().intersect_with_bounding_box(inner.get_bounding_box()) {
+            return false;
+        }
+        for point in inner.get_points() {
+            match outer.intersect_with_point(point) {
+                PolySide::Inside => { return true; },
+                PolySide::Outside => { return false; },
+                _ => {},
+            }
+        }
+        false
+    }
+    fn intersect_complex_polygon_with_complex_polygon(polygon:&ComplexPolygon, other:&ComplexPolygon) -> PolygonIntersectionResult {
+        //the polygon against the other's body first, as the other's holes only matter where that's intersecting
+        let mut output_result = polygon.intersect_with_simple_polygon(other.get_body());
+        if !output_result.intersect {
+            return output_result;
+        }
+
+        for index in 0..other.get_holes_count() {
+            let hole = other.get_hole(index);
+            let hole_result = polygon.intersect_with_simple_polygon(hole);
+
+            //cleanly inside the hole, thus doesn't touch the other polygon
+                if hole_result.first_contains_second_without_contact == Some(false) {
+                    return PolygonIntersectionResult::new(vec![], false, false, false, None);
+                }
+
+            //inside the hole, but touching its edges
+                if hole_result.contact && !hole_result.traverse && polygon.get_body().get_points().iter().all(|point| hole.intersect_with_point(point) != PolySide::Outside) {
+                    return PolygonIntersectionResult::new(hole_result.points, true, false, false, None);
+                }
+
+            //add contact, its points and traverse
+                output_result.contact |= hole_result.contact;
+                for point in hole_result.points {
+                    if !search_for_duplicate_point_in_vector(&output_result.points, &point) {
+                        output_result.points.push(point);
+                    }
+                }
+                output_result.traverse |= hole_result.traverse;
+
+            //the polygon cleanly containing the other's hole means the other can't cleanly contain the polygon
+                if output_result.first_contains_second_without_contact == Some(false) && hole_result.first_contains_second_without_contact == Some(true) {
+                    output_result.first_contains_second_without_contact = None;
+                }
+        }
+
+        if output_result.contact {
+            output_result.first_contains_second_without_contact = None;
+        }
+
+        output_result
+    }
+    fn wind(ring:SimplePolygon, clockwise:bool) -> SimplePolygon {
+        if ring.is_clockwise() == clockwise {
+            return ring;
+        }
+
+        let mut points = ring.get_points().clone();
+        points.reverse();
+        let mut output = SimplePolygon::new_from_point_vector(points);
+        output.set_fill_rule(ring.get_fill_rule());
+        output
+    }
+
+
+
+
+
+
+
+
+//struct
+    pub struct MultiPolygon {
+        polygons: Vec<ComplexPolygon>,
+        bounding_box: BoundingBox,
+    }
+//new
+    impl MultiPolygon {
+        pub fn new_from_complex_polygons(polygons:Vec<ComplexPolygon>) -> MultiPolygon {
+            let bounding_box = produce_bounding_box(&polygons);
+            MultiPolygon {
+                polygons,
+                bounding_box,
+            }
+        }
+        pub fn new_default() -> MultiPolygon {
+            MultiPolygon::new_from_complex_polygons(vec![])
+        }
+        //takes an unordered collection of non-crossing rings, works out how they nest within one another, and produces the
+        //matching set of complex polygons; rings at an even depth are bodies, rings at an odd depth are holes in their parent,
+        //and rings within those holes (islands) start new bodies. Bodies are rewound clockwise and holes anti-clockwise
+        //along the way, so that the rings still describe the same area when flattened and filled with either rule
+        pub fn new_from_rings(rings:Vec<SimplePolygon>) -> MultiPolygon {
+            //sort the rings from largest to smallest; a ring can then only be contained by rings that come before it
+                let mut rings:Vec<SimplePolygon> = rings.into_iter()
+                    .filter(|ring| ring.get_points_length() >= 3)
+                    .collect();
+                rings.sort_by(|a, b| b.get_area().partial_cmp(&a.get_area()).unwrap_or(std::cmp::Ordering::Equal));
+
+            //find each ring's parent (the smallest ring that contains it) and from that, whether it's a body or a hole
+                let mut parents:Vec<Option<usize>> = vec![None; rings.len()];
+                let mut is_body:Vec<bool> = vec![true; rings.len()];
+                for index in 0..rings.len() {
+                    for candidate in (0..index).rev() {
+                        if ring_contains_ring(&rings[candidate], &rings[index]) {
+                            parents[index] = Some(candidate);
+                            is_body[index] = !is_body[candidate];
+                            break;
+                        }
+                    }
+                }
+
+            //gather the bodies and their holes
+                let mut bodies:Vec<(SimplePolygon, Vec<SimplePolygon>)> = vec![];
+                let mut body_lookup:Vec<Option<usize>> = vec![None; rings.len()];
+                let mut hole_parents:Vec<(usize, SimplePolygon)> = vec![];
+                for (index, ring) in rings.into_iter().enumerate() {
+                    if is_body[index] {
+                        body_lookup[index] = Some(bodies.len());
+                        bodies.push((wind(ring, true), vec![]));
+                    } else {
+                        hole_parents.push((parents[index].unwrap(), wind(ring, false)));
+                    }
+                }
+                for (parent, hole) in hole_parents {
+                    match body_lookup[parent] {
+                        Some(body_index) => bodies[body_index].1.push(hole),
+                        None => println!("WARNING - MultiPolygon::new_from_rings - hole found without a body"),
+                    }
+                }
+
+            MultiPolygon::new_from_complex_polygons(
+                bodies.into_iter().map(|(body, holes)| ComplexPolygon::new_from_simple_polygons(body, holes)).collect()
+            )
+        }
+    }
+//clone
+    impl MultiPolygon {
+        pub fn clone(&self) -> MultiPolygon {
+            MultiPolygon {
+                polygons: self.polygons.iter().map(|polygon| polygon.clone()).collect(),
+                bounding_box: self.bounding_box,
+            }
+        }
+    }
+//getters
+    impl MultiPolygon {
+        pub fn get_polygons_count(&self) -> usize {
+            self.polygons.len()
+        }
+        pub fn get_polygons(&self) -> &Vec<ComplexPolygon> {
+            &self.polygons
+        }
+        pub fn get_polygon(&self, index:usize) -> &ComplexPolygon {
+            &self.polygons[index]
+        }
+        pub fn get_bounding_box(&self) -> &BoundingBox {
+            &self.bounding_box
+        }
+        //every edge of every polygon, in polygon order (see ComplexPolygon::get_all_lines for the order within each)
+        pub fn get_all_lines(&self) -> Vec<Line> {
+            let mut output:Vec<Line> = vec![];
+            for polygon in &self.polygons {
+                output.append(&mut polygon.get_all_lines());
+            }
+            output
+        }
+    }
+//setters
+    impl MultiPolygon {
+        //for every polygon in the collection
+        pub fn set_fill_rule(&mut self, new:FillRule) {
+            for polygon in &mut self.polygons {
+                polygon.set_fill_rule(new);
+            }
+        }
+        pub fn add_polygon(&mut self, polygon:ComplexPolygon) {
+            self.polygons.push(polygon);
+            self.bounding_box = produce_bounding_box(&self.polygons);
+        }
+        pub fn remove_polygon(&mut self, index:usize) -> ComplexPolygon {
+            let polygon = self.polygons.remove(index);
+            self.bounding_box = produce_bounding_box(&self.polygons);
+            polygon
+        }
+    }
+//measurement
+    impl MultiPolygon {
+        pub fn get_area(&self) -> f32 {
+            self.polygons.iter().map(|polygon| polygon.get_area()).sum()
+        }
+        pub fn get_perimeter(&self) -> f32 {
+            self.polygons.iter().map(|polygon| polygon.get_perimeter()).sum()
+        }
+    }
+//printing
+    impl MultiPolygon {
+        fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result {
+            let mut polygon_string:String = String::from("[");
+            for (index, polygon) in self.polygons.iter().enumerate() {
+                polygon_string = format!("{}{}", polygon_string, polygon);
+                if index != self.polygons.len()-1 { polygon_string = format!("{},", polygon_string); }
+            }
+            polygon_string = format!("{}]", polygon_string);
+
+            write!(f, "{}", polygon_string)
+        }
+    }
+    impl fmt::Display for MultiPolygon {
+        fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { self.fmt(f) }
+    }
+    impl fmt::Debug for MultiPolygon {
+        fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { self.fmt(f) }
+    }
+//intersect
+    impl MultiPolygon {
+        pub fn intersect_with_point(&self, point:&Point) -> PolySide {
+            if !self.bounding_box.intersect_with_point(point) {
+                return PolySide::Outside;
+            }
+
+            //the polygons don't overlap, so the first one that isn't outside has the answer
+            for polygon in &self.polygons {
+                match polygon.intersect_with_point(point) {
+                    PolySide::Outside => { continue; },
+                    result => { return result; },
+                }
+            }
+
+            PolySide::Outside
+        }
+        pub fn intersect_with_point_with_fill_rule(&self, point:&Point, fill_rule:FillRule) -> PolySide {
+            if !self.bounding_box.intersect_with_point(point) {
+                return PolySide::Outside;
+            }
+
+            for polygon in &self.polygons {
+                match polygon.intersect_with_point_with_fill_rule(point, fill_rule) {
+                    PolySide::Outside => { continue; },
+                    result => { return result; },
+                }
+            }
+
+            PolySide::Outside
+        }
+        //bounding box //currently not needed
+        pub fn intersect_with_polyline(&self, polyline:&Polyline) -> LinePolygonIntersectionResult {
+            polyline.intersect_with_multi_polygon(self)
+        }
+        pub fn intersect_with_line(&self, line:&Line) -> LinePolygonIntersectionResult {
+            let mut output_result = LinePolygonIntersectionResult {
+                points: vec![],
+                contact: false,
+                intersect: false,
+                inverse_intersect: true,
+                traverse: false,
+            };
+
+            for polygon in &self.polygons {
+                let polygon_result = polygon.intersect_with_line(line);
+
+                for point in polygon_result.points {
+                    if !search_for_duplicate_point_in_vector(&output_result.points, &point) {
+                        output_result.points.push(point);
+                    }
+                }
+                output_result.contact |= polygon_result.contact;
+                output_result.intersect |= polygon_result.intersect;
+                output_result.traverse |= polygon_result.traverse;
+
+                //the line is only over the area around the collection if it's over the area around every polygon in it
+                output_result.inverse_intersect &= polygon_result.inverse_intersect;
+            }
+
+            output_result
+        }
+        pub fn intersect_with_simple_polygon(&self, simple_polygon:&SimplePolygon) -> PolygonIntersectionResult {
+            self.intersect_with_polygon(simple_polygon.get_bounding_box(), |polygon| polygon.intersect_with_simple_polygon(simple_polygon))
+        }
+        pub fn intersect_with_complex_polygon(&self, complex_polygon:&ComplexPolygon) -> PolygonIntersectionResult {
+            self.intersect_with_polygon(complex_polygon.get_bounding_box(), |polygon| intersect_complex_polygon_with_complex_polygon(polygon, complex_polygon))
+        }
+        pub fn intersect_with_simple_polygon_with_fill_rule(&self, simple_polygon:&SimplePolygon, fill_rule:FillRule) -> PolygonIntersectionResult {
+            //as with the complex polygon; containment is decided by each polygon's own fill rule, so work with copies
+            //that use the requested rule
+            let mut self_copy = self.clone();
+            let mut simple_polygon_copy = simple_polygon.clone();
+            self_copy.set_fill_rule(fill_rule);
+            simple_polygon_copy.set_fill_rule(fill_rule);
+            self_copy.intersect_with_simple_polygon(&simple_polygon_copy)
+        }
+
+        //reduced functionality, for pure speed
+        pub fn intersect_with_simple_polygon__intersect_only(&self, simple_polygon:&SimplePolygon) -> bool {
+            if !self.bounding_box.intersect_with_bounding_box(simple_polygon.get_bounding_box()) {
+                return false;
+            }
+
+            //a polygon's body not intersecting is enough to rule it out; only those that do need their holes looked at
+            self.polygons.iter().any(|polygon| {
+                polygon.get_body().intersect_with_simple_polygon__intersect_only(simple_polygon) &&
+                (polygon.get_holes_count() == 0 || polygon.intersect_with_simple_polygon(simple_polygon).intersect)
+            })
+        }
+
+        fn intersect_with_polygon<F:Fn(&ComplexPolygon) -> PolygonIntersectionResult>(&self, bounding_box:&BoundingBox, polygon_test:F) -> PolygonIntersectionResult {
+            let mut output_result = PolygonIntersectionResult::new(vec![], false, false, false, None);
+            if !self.bounding_box.intersect_with_bounding_box(bounding_box) {
+                return output_result;
+            }
+
+            let mut any_contains_other = false;
+            let mut all_contained_by_other = !self.polygons.is_empty();
+
+            for polygon in &self.polygons {
+                let polygon_result = polygon_test(polygon);
+
+                for point in polygon_result.points {
+                    if !search_for_duplicate_point_in_vector(&output_result.points, &point) {
+                        output_result.points.push(point);
+                    }
+                }
+                output_result.contact |= polygon_result.contact;
+                output_result.intersect |= polygon_result.intersect;
+                output_result.traverse |= polygon_result.traverse;
+
+                any_contains_other |= polygon_result.first_contains_second_without_contact == Some(true);
+                all_contained_by_other &= polygon_result.first_contains_second_without_contact == Some(false);
+            }
+
+            if !output_result.contact {
+                output_result.first_contains_second_without_contact = if any_contains_other {
+                    Some(true)
+                } else if all_contained_by_other {
+                    Some(false)
+                } else {
+                    None
+                };
+            }
+
+            output_result
+        }
+    }
+//intersect (extended results)
+    //contacts give the index of the edge (matching get_all_lines) and position along it, for both shapes
+    impl MultiPolygon {
+        pub fn intersect_with_line__contacts(&self, line:&Line) -> Vec<EdgeContact> {
+            if !self.bounding_box.intersect_with_bounding_box(&line.get_bounding_box()) {
+                return vec![];
+            }
+            Line::intersect_lines_with_lines__contacts(&self.get_all_lines(), &[*line])
+        }
+        pub fn intersect_with_polyline__contacts(&self, polyline:&Polyline) -> Vec<EdgeContact> {
+            if !self.bounding_box.intersect_with_bounding_box(polyline.get_bounding_box()) {
+                return vec![];
+            }
+            Line::intersect_lines_with_lines__contacts(&self.get_all_lines(), polyline.get_lines())
+        }
+        pub fn intersect_with_simple_polygon__contacts(&self, simple_polygon:&SimplePolygon) -> Vec<EdgeContact> {
+            if !self.bounding_box.intersect_with_bounding_box(simple_polygon.get_bounding_box()) {
+                return vec![];
+            }
+            Line::intersect_lines_with_lines__contacts(&self.get_all_lines(), simple_polygon.get_lines())
+        }
+        pub fn intersect_with_complex_polygon__contacts(&self, complex_polygon:&ComplexPolygon) -> Vec<EdgeContact> {
+            if !self.bounding_box.intersect_with_bounding_box(complex_polygon.get_bounding_box()) {
+                return vec![];
+            }
+            Line::intersect_lines_with_lines__contacts(&self.get_all_lines(), &complex_polygon.get_all_lines())
+        }
+    }
\ No newline at end of file
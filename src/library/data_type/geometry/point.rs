@@ -1,5 +1,6 @@
 use std::fmt;
 
+use super::fill_rule::FillRule;
 use super::intersect_result_types::{
     LineSide,
     PolySide,
@@ -7,8 +8,16 @@ use super::intersect_result_types::{
 
 use super::bounding_box::BoundingBox;
 use super::line::Line;
+use super::polyline::Polyline;
+use super::ray::Ray;
+use super::infinite_line::InfiniteLine;
 use super::simple_polygon::SimplePolygon;
 use super::complex_polygon::ComplexPolygon;
+use super::multi_polygon::MultiPolygon;
+
+use super::super::offset::Offset;
+
+use super::super::super::math::cartesian_angle_adjust;
 
 
 
@@ -69,6 +78,50 @@ use super::complex_polygon::ComplexPolygon;
             self.y = other_point.y;
         }
     }
+//transform
+    //the get_ versions leave the point alone and return a moved copy
+    impl Point {
+        pub fn translate(&mut self, x:f32, y:f32) {
+            self.x += x;
+            self.y += y;
+        }
+        pub fn rotate(&mut self, angle:f32, anchor:&Point) {
+            let point = cartesian_angle_adjust(self.x - anchor.x, self.y - anchor.y, angle);
+            self.x = point.x + anchor.x;
+            self.y = point.y + anchor.y;
+        }
+        pub fn scale(&mut self, scale:f32, anchor:&Point) {
+            self.x = (self.x - anchor.x) * scale + anchor.x;
+            self.y = (self.y - anchor.y) * scale + anchor.y;
+        }
+        //rotate and scale about the origin, then translate; the same order Offset::combine uses
+        pub fn apply_offset(&mut self, offset:&Offset) {
+            let point = cartesian_angle_adjust(self.x, self.y, offset.get_angle());
+            self.x = point.x * offset.get_scale() + offset.get_x();
+            self.y = point.y * offset.get_scale() + offset.get_y();
+        }
+
+        pub fn get_translated(&self, x:f32, y:f32) -> Point {
+            let mut output = *self;
+            output.translate(x, y);
+            output
+        }
+        pub fn get_rotated(&self, angle:f32, anchor:&Point) -> Point {
+            let mut output = *self;
+            output.rotate(angle, anchor);
+            output
+        }
+        pub fn get_scaled(&self, scale:f32, anchor:&Point) -> Point {
+            let mut output = *self;
+            output.scale(scale, anchor);
+            output
+        }
+        pub fn get_with_offset_applied(&self, offset:&Offset) -> Point {
+            let mut output = *self;
+            output.apply_offset(offset);
+            output
+        }
+    }
 //printing
     impl Point {
         fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result {
@@ -110,10 +163,28 @@ use super::complex_polygon::ComplexPolygon;
         pub fn intersect_with_line(&self, line:&Line) -> LineSide {
             line.intersect_with_point(self)
         }
+        pub fn intersect_with_polyline(&self, polyline:&Polyline) -> bool {
+            polyline.intersect_with_point(self)
+        }
+        pub fn intersect_with_ray(&self, ray:&Ray) -> LineSide {
+            ray.intersect_with_point(self)
+        }
+        pub fn intersect_with_infinite_line(&self, infinite_line:&InfiniteLine) -> LineSide {
+            infinite_line.intersect_with_point(self)
+        }
         pub fn intersect_with_simple_polygon(&self, simple_polygon:&SimplePolygon) -> PolySide {
             simple_polygon.intersect_with_point(self)
         }
         pub fn intersect_with_complex_polygon(&self, complex_polygon:&ComplexPolygon) -> PolySide {
             complex_polygon.intersect_with_point(self)
         }
+        pub fn intersect_with_multi_polygon(&self, multi_polygon:&MultiPolygon) -> PolySide {
+            multi_polygon.intersect_with_point(self)
+        }
+        pub fn intersect_with_simple_polygon_with_fill_rule(&self, simple_polygon:&SimplePolygon, fill_rule:FillRule) -> PolySide {
+            simple_polygon.intersect_with_point_with_fill_rule(self, fill_rule)
+        }
+        pub fn intersect_with_complex_polygon_with_fill_rule(&self, complex_polygon:&ComplexPolygon, fill_rule:FillRule) -> PolySide {
+            complex_polygon.intersect_with_point_with_fill_rule(self, fill_rule)
+        }
     }
\ No newline at end of file
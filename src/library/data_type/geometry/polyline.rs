@@ -0,0 +1,439 @@
+use std::fmt;
+
+use super::intersect_result_types::{
+    EdgeContact,
+    LineSide,
+    LinePolygonIntersectionResult,
+    PathIntersectionResult,
+};
+
+use super::bounding_box::BoundingBox;
+use super::point::Point;
+use super::line::Line;
+use super::ray::Ray;
+use super::infinite_line::InfiniteLine;
+use super::simple_polygon::SimplePolygon;
+use super::complex_polygon::ComplexPolygon;
+use super::multi_polygon::MultiPolygon;
+
+
+
+
+
+
+
+
+//utilities
+    fn search_for_duplicate_point_in_vector(vector:&[Point], point:&Point) -> bool {
+        vector.iter().any(|p| p == point)
+    }
+
+
+
+
+
+
+
+
+//struct
+    pub struct Polyline {
+        points: Vec<Point>,
+        closed: bool,
+        bounding_box: BoundingBox,
+        lines: Vec<Line>,
+        distances: Vec<f32>, //the distance along the path at which each line starts, with the total length at the end
+    }
+//calculation
+    impl Polyline {
+        fn produce_lines(points:&[Point], closed:bool) -> Vec<Line> {
+            if points.len() < 2 {
+                return vec![];
+            }
+
+            let mut output:Vec<Line> = points.windows(2).map(|pair| Line::new_from_points_ref(&pair[0], &pair[1])).collect();
+            if closed && points.len() > 2 {
+                output.push( Line::new_from_points_ref(&points[points.len()-1], &points[0]) );
+            }
+            output
+        }
+        fn produce_distances(lines:&[Line]) -> Vec<f32> {
+            let mut output:Vec<f32> = vec![0.0];
+            for line in lines {
+                output.push( output[output.len()-1] + line.get_length() );
+            }
+            output
+        }
+        fn update(&mut self) {
+            self.bounding_box = BoundingBox::new_from_points(&self.points);
+            self.lines = Polyline::produce_lines(&self.points, self.closed);
+            self.distances = Polyline::produce_distances(&self.lines);
+        }
+        fn locate_distance(&self, distance:f32) -> Option<(usize, f32)> {
+            //find which line the distance lands on, and how far along that line (0 to 1) it is
+            //closed paths wrap around, open paths are clamped to their ends
+            if self.lines.is_empty() {
+                return None;
+            }
+
+            let length = self.get_length();
+            let distance = if self.closed && length > 0.0 {
+                distance.rem_euclid(length)
+            } else {
+                distance.clamp(0.0, length)
+            };
+
+            let index = match self.distances.iter().position(|&start| start > distance) {
+                Some(0) => 0,
+                Some(index) => index - 1,
+                None => self.lines.len() - 1,
+            }.min(self.lines.len() - 1);
+
+            let line_length = self.distances[index+1] - self.distances[index];
+            let proportion = if line_length == 0.0 { 0.0 } else { (distance - self.distances[index]) / line_length };
+
+            Some((index, proportion.clamp(0.0, 1.0)))
+        }
+    }
+//new
+    impl Polyline {
+        pub fn new_from_point_vector(points:Vec<Point>, closed:bool) -> Polyline {
+            let mut output = Polyline {
+                points,
+                closed,
+                bounding_box: BoundingBox::new(0.0, 0.0, 0.0, 0.0),
+                lines: vec![],
+                distances: vec![],
+            };
+            output.update();
+            output
+        }
+        pub fn new_default() -> Polyline {
+            Polyline::new_from_point_vector(vec![], false)
+        }
+        pub fn new_from_flat_array_reference(points:&[f32], closed:bool) -> Polyline {
+            if !points.chunks_exact(2).remainder().is_empty() {
+                println!("WARNING - Polyline::new_from_flat_array/new_from_flat_array_reference : provided array is not divisible by 2 : this Polyline will use as many points as possible");
+            }
+
+            Polyline::new_from_point_vector(
+                points.chunks_exact(2).map(|pair| Point::new(pair[0], pair[1])).collect(),
+                closed,
+            )
+        }
+        pub fn new_from_flat_array(points:Vec<f32>, closed:bool) -> Polyline {
+            Polyline::new_from_flat_array_reference(&points, closed)
+        }
+        pub fn new_from_line(line:&Line) -> Polyline {
+            Polyline::new_from_point_vector(vec![*line.get_point_a(), *line.get_point_b()], false)
+        }
+        pub fn new_from_simple_polygon(simple_polygon:&SimplePolygon) -> Polyline {
+            Polyline::new_from_point_vector(simple_polygon.get_points().clone(), true)
+        }
+    }
+//clone
+    impl Polyline {
+        pub fn clone(&self) -> Polyline {
+            Polyline {
+                points: self.points.clone(),
+                closed: self.closed,
+                bounding_box: self.bounding_box,
+                lines: self.lines.clone(),
+                distances: self.distances.clone(),
+            }
+        }
+    }
+//getters
+    impl Polyline {
+        //points
+            pub fn get_points_length(&self) -> usize {
+                self.points.len()
+            }
+            pub fn get_points(&self) -> &Vec<Point> {
+                &self.points
+            }
+            pub fn get_point(&self, index:usize) -> &Point {
+                &self.points[index]
+            }
+
+        //lines
+            pub fn get_line_count(&self) -> usize {
+                self.lines.len()
+            }
+            pub fn get_lines(&self) -> &Vec<Line> {
+                &self.lines
+            }
+
+        //bounding box
+            pub fn get_bounding_box(&self) -> &BoundingBox {
+                &self.bounding_box
+            }
+
+        //closed
+            pub fn is_closed(&self) -> bool {
+                self.closed
+            }
+    }
+//setters
+    impl Polyline {
+        pub fn add_point(&mut self, point:Point) {
+            self.points.push(point);
+            self.update();
+        }
+        pub fn set_closed(&mut self, new:bool) {
+            self.closed = new;
+            self.update();
+        }
+    }
+//measurement
+    impl Polyline {
+        pub fn get_length(&self) -> f32 {
+            self.distances[self.distances.len()-1]
+        }
+        pub fn get_point_at_distance(&self, distance:f32) -> Point {
+            match self.locate_distance(distance) {
+                None => {
+                    if self.points.is_empty() { Point::new(0.0, 0.0) } else { self.points[0] }
+                },
+                Some((index, proportion)) => {
+                    let line = &self.lines[index];
+                    Point::new(
+                        line.get_point_a().get_x() + (line.get_point_b().get_x() - line.get_point_a().get_x()) * proportion,
+                        line.get_point_a().get_y() + (line.get_point_b().get_y() - line.get_point_a().get_y()) * proportion,
+                    )
+                },
+            }
+        }
+        //unit length direction of travel at the distance along the path (at a joint, the direction of the line being started)
+        pub fn get_tangent_at_distance(&self, distance:f32) -> Point {
+            match self.locate_distance(distance) {
+                None => Point::new(0.0, 0.0),
+                Some((index, _)) => {
+                    let line = &self.lines[index];
+                    let length = line.get_length();
+                    if length == 0.0 {
+                        Point::new(0.0, 0.0)
+                    } else {
+                        Point::new(
+                            (line.get_point_b().get_x() - line.get_point_a().get_x()) / length,
+                            (line.get_point_b().get_y() - line.get_point_a().get_y()) / length,
+                        )
+                    }
+                },
+            }
+        }
+        pub fn get_angle_at_distance(&self, distance:f32) -> f32 {
+            let tangent = self.get_tangent_at_distance(distance);
+            tangent.get_y().atan2(tangent.get_x())
+        }
+    }
+//resampling
+    impl Polyline {
+        //produce a new path with the requested number of points, evenly spaced along this one
+        pub fn resample_by_count(&self, count:usize) -> Polyline {
+            if count == 0 || self.lines.is_empty() {
+                return Polyline::new_from_point_vector(vec![], self.closed);
+            }
+
+            let length = self.get_length();
+            let spacing = if self.closed {
+                length / count as f32
+            } else if count == 1 {
+                0.0
+            } else {
+                length / (count - 1) as f32
+            };
+
+            Polyline::new_from_point_vector(
+                (0..count).map(|index| {
+                    //avoid wrapping the last point of an open path back to its start
+                    let distance = if !self.closed && index == count - 1 { length } else { spacing * index as f32 };
+                    self.get_point_at_distance(distance)
+                }).collect(),
+                self.closed,
+            )
+        }
+        //produce a new path with points placed every 'spacing' along this one (open paths also keep their end point)
+        pub fn resample_by_spacing(&self, spacing:f32) -> Polyline {
+            if spacing <= 0.0 {
+                println!("WARNING - Polyline::resample_by_spacing - spacing must be greater than zero");
+                return self.clone();
+            }
+
+            let length = self.get_length();
+            let mut points:Vec<Point> = vec![];
+            let mut index = 0;
+            while spacing * (index as f32) < length {
+                points.push( self.get_point_at_distance(spacing * index as f32) );
+                index += 1;
+            }
+
+            //closed paths are brought back to their start by the closing line, open paths need their end point
+            if !self.closed || points.is_empty() {
+                points.push( self.get_point_at_distance(length) );
+            }
+
+            Polyline::new_from_point_vector(points, self.closed)
+        }
+    }
+//printing
+    impl Polyline {
+        fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result {
+            let mut point_string:String = String::from("[");
+            for (index, point) in self.points.iter().enumerate() {
+                point_string = format!("{}{}", point_string, point);
+                if index != self.points.len()-1 { point_string = format!("{},", point_string); }
+            }
+            point_string = format!("{}]", point_string);
+
+            write!(f, "{{points:{}, closed:{}}}", point_string, self.closed)
+        }
+    }
+    impl fmt::Display for Polyline {
+        fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { self.fmt(f) }
+    }
+    impl fmt::Debug for Polyline {
+        fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { self.fmt(f) }
+    }
+//comparison
+    impl PartialEq for Polyline {
+        fn eq(&self, other:&Self) -> bool {
+            self.closed == other.closed && self.points == other.points
+        }
+    }
+//intersect
+    impl Polyline {
+        pub fn intersect_with_point(&self, point:&Point) -> bool {
+            if !self.bounding_box.intersect_with_point(point) {
+                return false;
+            }
+            self.lines.iter().any(|line| line.intersect_with_point(point) == LineSide::Upon)
+        }
+        pub fn intersect_with_bounding_box(&self, bounding_box:&BoundingBox) -> bool {
+            if !self.bounding_box.intersect_with_bounding_box(bounding_box) {
+                return false;
+            }
+            if self.points.iter().any(|point| bounding_box.intersect_with_point(point)) {
+                return true;
+            }
+
+            let box_polygon = SimplePolygon::new_from_bounding_box(*bounding_box, true);
+            self.lines.iter().any(|line| box_polygon.intersect_with_line(line).contact)
+        }
+        pub fn intersect_with_line(&self, line:&Line) -> PathIntersectionResult {
+            let mut output_result = PathIntersectionResult::new(vec![], false, false);
+            if !self.bounding_box.intersect_with_bounding_box(&line.get_bounding_box()) {
+                return output_result;
+            }
+
+            for self_line in &self.lines {
+                let line_result = self_line.intersect_with_line(line);
+                if !line_result.contact { continue; }
+
+                output_result.contact = true;
+                output_result.intersect |= line_result.intersect;
+
+                if let Some(point) = line_result.point {
+                    if !search_for_duplicate_point_in_vector(&output_result.points, &point) {
+                        output_result.points.push(point);
+                    }
+                }
+                if let Some(range) = line_result.range {
+                    for point in &[*range.get_point_a(), *range.get_point_b()] {
+                        if !search_for_duplicate_point_in_vector(&output_result.points, point) {
+                            output_result.points.push(*point);
+                        }
+                    }
+                }
+            }
+
+            output_result
+        }
+        pub fn intersect_with_polyline(&self, other:&Polyline) -> PathIntersectionResult {
+            let mut output_result = PathIntersectionResult::new(vec![], false, false);
+            if !self.bounding_box.intersect_with_bounding_box(&other.bounding_box) {
+                return output_result;
+            }
+
+            for other_line in &other.lines {
+                let line_result = self.intersect_with_line(other_line);
+                output_result.contact |= line_result.contact;
+                output_result.intersect |= line_result.intersect;
+                for point in line_result.points {
+                    if !search_for_duplicate_point_in_vector(&output_result.points, &point) {
+                        output_result.points.push(point);
+                    }
+                }
+            }
+
+            output_result
+        }
+        pub fn intersect_with_ray(&self, ray:&Ray) -> PathIntersectionResult {
+            ray.intersect_with_polyline(self)
+        }
+        pub fn intersect_with_infinite_line(&self, infinite_line:&InfiniteLine) -> PathIntersectionResult {
+            infinite_line.intersect_with_polyline(self)
+        }
+        pub fn intersect_with_simple_polygon(&self, simple_polygon:&SimplePolygon) -> LinePolygonIntersectionResult {
+            self.intersect_with_lines_of(|line| simple_polygon.intersect_with_line(line))
+        }
+        pub fn intersect_with_complex_polygon(&self, complex_polygon:&ComplexPolygon) -> LinePolygonIntersectionResult {
+            self.intersect_with_lines_of(|line| complex_polygon.intersect_with_line(line))
+        }
+        pub fn intersect_with_multi_polygon(&self, multi_polygon:&MultiPolygon) -> LinePolygonIntersectionResult {
+            self.intersect_with_lines_of(|line| multi_polygon.intersect_with_line(line))
+        }
+
+        //run each of the path's lines against a polygon, and gather the results together
+        fn intersect_with_lines_of<F:Fn(&Line) -> LinePolygonIntersectionResult>(&self, line_test:F) -> LinePolygonIntersectionResult {
+            let mut output_result = LinePolygonIntersectionResult {
+                points: vec![],
+                contact: false,
+                intersect: false,
+                inverse_intersect: false,
+                traverse: false,
+            };
+
+            for line in &self.lines {
+                let line_result = line_test(line);
+                for point in line_result.points {
+                    if !search_for_duplicate_point_in_vector(&output_result.points, &point) {
+                        output_result.points.push(point);
+                    }
+                }
+                output_result.contact |= line_result.contact;
+                output_result.intersect |= line_result.intersect;
+                output_result.inverse_intersect |= line_result.inverse_intersect;
+                output_result.traverse |= line_result.traverse;
+            }
+
+            output_result
+        }
+    }
+//intersect (extended results)
+    //contacts give the index of the edge (matching get_lines) and position along it, for both shapes
+    impl Polyline {
+        pub fn intersect_with_line__contacts(&self, line:&Line) -> Vec<EdgeContact> {
+            if !self.bounding_box.intersect_with_bounding_box(&line.get_bounding_box()) {
+                return vec![];
+            }
+            Line::intersect_lines_with_lines__contacts(&self.lines, &[*line])
+        }
+        pub fn intersect_with_polyline__contacts(&self, other:&Polyline) -> Vec<EdgeContact> {
+            if !self.bounding_box.intersect_with_bounding_box(&other.bounding_box) {
+                return vec![];
+            }
+            Line::intersect_lines_with_lines__contacts(&self.lines, &other.lines)
+        }
+        pub fn intersect_with_simple_polygon__contacts(&self, simple_polygon:&SimplePolygon) -> Vec<EdgeContact> {
+            if !self.bounding_box.intersect_with_bounding_box(simple_polygon.get_bounding_box()) {
+                return vec![];
+            }
+            Line::intersect_lines_with_lines__contacts(&self.lines, simple_polygon.get_lines())
+        }
+        pub fn intersect_with_complex_polygon__contacts(&self, complex_polygon:&ComplexPolygon) -> Vec<EdgeContact> {
+            if !self.bounding_box.intersect_with_bounding_box(complex_polygon.get_bounding_box()) {
+                return vec![];
+            }
+            Line::intersect_lines_with_lines__contacts(&self.lines, &complex_polygon.get_all_lines())
+        }
+    }
\ No newline at end of file
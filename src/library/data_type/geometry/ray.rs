@@ -0,0 +1,196 @@
+use std::fmt;
+
+use super::intersect_result_types::{
+    LineSide,
+    LineIntersectionResult,
+    LinePolygonIntersectionResult,
+    PathIntersectionResult,
+    RayIntersectionResult,
+};
+
+use super::point::Point;
+use super::bounding_box::BoundingBox;
+use super::line::Line;
+use super::polyline::Polyline;
+use super::infinite_line::InfiniteLine;
+use super::simple_polygon::SimplePolygon;
+use super::complex_polygon::ComplexPolygon;
+use super::multi_polygon::MultiPolygon;
+
+use super::super::super::math::{
+    cartesian_angle_adjust,
+    parametric_line_clip,
+    parametric_line_intersect,
+};
+
+
+
+
+
+
+
+
+//struct
+    //a line that starts at the origin and extends forever in the direction of point_b
+    #[derive(Copy, Clone)]
+    pub struct Ray {
+        origin: Point,
+        point_b: Point,
+    }
+//new
+    impl Ray {
+        pub fn new_from_points(origin:Point, point_b:Point) -> Ray {
+            Ray {
+                origin,
+                point_b,
+            }
+        }
+        pub fn new_from_values(origin_x:f32, origin_y:f32, point_b_x:f32, point_b_y:f32) -> Ray {
+            Ray::new_from_points(Point::new(origin_x,origin_y), Point::new(point_b_x,point_b_y))
+        }
+        pub fn new_from_point_and_angle(origin:Point, angle:f32) -> Ray {
+            let direction = cartesian_angle_adjust(1.0, 0.0, angle);
+            Ray::new_from_points(
+                origin,
+                Point::new(origin.get_x() + direction.get_x(), origin.get_y() + direction.get_y()),
+            )
+        }
+        pub fn new_from_line(line:&Line) -> Ray {
+            Ray::new_from_points(*line.get_point_a(), *line.get_point_b())
+        }
+    }
+//getters
+    impl Ray {
+        pub fn get_origin(&self) -> &Point { &self.origin }
+        pub fn get_point_b(&self) -> &Point { &self.point_b }
+        pub fn get_angle(&self) -> f32 {
+            (self.point_b.get_y() - self.origin.get_y()).atan2(self.point_b.get_x() - self.origin.get_x())
+        }
+        //the section of the ray that falls within the bounding box, if any
+        pub fn get_segment_within_bounding_box(&self, bounding_box:&BoundingBox) -> Option<Line> {
+            parametric_line_clip(&self.origin, &self.point_b, 0.0, f32::INFINITY, bounding_box).map(|(t_min, t_max)| {
+                Line::new_from_points(self.get_point_at_parameter(t_min), self.get_point_at_parameter(t_max))
+            })
+        }
+        fn get_point_at_parameter(&self, t:f32) -> Point {
+            if t == 0.0 { return self.origin; }
+            Point::new(
+                self.origin.get_x() + (self.point_b.get_x() - self.origin.get_x()) * t,
+                self.origin.get_y() + (self.point_b.get_y() - self.origin.get_y()) * t,
+            )
+        }
+        //a finite stand-in for the ray that's guaranteed to run clean past the bounding box, so its far end doesn't land on anything within it
+        fn get_segment_beyond_bounding_box(&self, bounding_box:&BoundingBox) -> Option<Line> {
+            let width = bounding_box.get_bottom_right().get_x() - bounding_box.get_top_left().get_x();
+            let height = bounding_box.get_bottom_right().get_y() - bounding_box.get_top_left().get_y();
+            self.get_segment_within_bounding_box( &bounding_box.get_expanded(width.max(height) * 0.1 + 1.0) )
+        }
+    }
+//setters
+    impl Ray {
+        pub fn set_origin(&mut self, new:Point) { self.origin = new; }
+        pub fn set_point_b(&mut self, new:Point) { self.point_b = new; }
+    }
+//printing
+    impl Ray {
+        fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result {
+            write!(
+                f, "{{origin:{},point_b:{}}}",
+                self.origin,
+                self.point_b,
+            )
+        }
+    }
+    impl fmt::Display for Ray {
+        fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { self.fmt(f) }
+    }
+    impl fmt::Debug for Ray {
+        fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { self.fmt(f) }
+    }
+//intersect
+    impl Ray {
+        pub fn intersect_with_point(&self, point:&Point) -> LineSide {
+            match Line::new_from_points(self.origin, self.point_b).intersect_with_point(point) {
+                LineSide::Upon | LineSide::AlignedButOff => {
+                    //on the ray, so long as it's not behind the origin
+                    let dot =
+                        (point.get_x() - self.origin.get_x()) * (self.point_b.get_x() - self.origin.get_x()) +
+                        (point.get_y() - self.origin.get_y()) * (self.point_b.get_y() - self.origin.get_y())
+                    ;
+                    if dot >= 0.0 { LineSide::Upon } else { LineSide::AlignedButOff }
+                },
+                side => side,
+            }
+        }
+        pub fn intersect_with_bounding_box(&self, bounding_box:&BoundingBox) -> bool {
+            self.get_segment_within_bounding_box(bounding_box).is_some()
+        }
+        pub fn intersect_with_line(&self, line:&Line) -> LineIntersectionResult {
+            match self.get_segment_beyond_bounding_box(&line.get_bounding_box()) {
+                None => LineIntersectionResult { point:None, range:None, contact:false, intersect:false, direction:None },
+                Some(segment) => segment.intersect_with_line(line),
+            }
+        }
+        pub fn intersect_with_polyline(&self, polyline:&Polyline) -> PathIntersectionResult {
+            match self.get_segment_beyond_bounding_box(polyline.get_bounding_box()) {
+                None => PathIntersectionResult::new(vec![], false, false),
+                Some(segment) => polyline.intersect_with_line(&segment),
+            }
+        }
+        pub fn intersect_with_ray(&self, other:&Ray) -> Option<RayIntersectionResult> {
+            if let Some((t, u)) = parametric_line_intersect(&self.origin, &self.point_b, &other.origin, &other.point_b) {
+                return if t >= 0.0 && u >= 0.0 { Some(RayIntersectionResult::Point(self.get_point_at_parameter(t))) } else { None };
+            }
+
+            //parallel; only rays along the same line can meet at all
+                match Line::new_from_points(self.origin, self.point_b).intersect_with_point(&other.origin) {
+                    LineSide::Upon | LineSide::AlignedButOff => {},
+                    _ => { return None; },
+                }
+
+            //where the other ray starts along this one, and whether it heads the same way
+                let delta_x = self.point_b.get_x() - self.origin.get_x();
+                let delta_y = self.point_b.get_y() - self.origin.get_y();
+                let other_delta_x = other.point_b.get_x() - other.origin.get_x();
+                let other_delta_y = other.point_b.get_y() - other.origin.get_y();
+                let start = (other.origin.get_x() - self.origin.get_x()) * delta_x + (other.origin.get_y() - self.origin.get_y()) * delta_y;
+                let same_way = delta_x * other_delta_x + delta_y * other_delta_y > 0.0;
+
+            if same_way {
+                //whichever starts further along is within the other
+                Some(RayIntersectionResult::Ray( if start >= 0.0 { *other } else { *self } ))
+            } else if start > 0.0 {
+                Some(RayIntersectionResult::Segment(Line::new_from_points(self.origin, other.origin)))
+            } else if start == 0.0 {
+                Some(RayIntersectionResult::Point(self.origin))
+            } else {
+                None
+            }
+        }
+        pub fn intersect_with_infinite_line(&self, infinite_line:&InfiniteLine) -> Option<Point> {
+            match parametric_line_intersect(&self.origin, &self.point_b, infinite_line.get_point_a(), infinite_line.get_point_b()) {
+                Some((t, _)) if t >= 0.0 => Some(self.get_point_at_parameter(t)),
+                _ => None,
+            }
+        }
+        pub fn intersect_with_simple_polygon(&self, simple_polygon:&SimplePolygon) -> LinePolygonIntersectionResult {
+            self.intersect_with_polygon(simple_polygon.get_bounding_box(), |segment| simple_polygon.intersect_with_line(segment))
+        }
+        pub fn intersect_with_complex_polygon(&self, complex_polygon:&ComplexPolygon) -> LinePolygonIntersectionResult {
+            self.intersect_with_polygon(complex_polygon.get_bounding_box(), |segment| complex_polygon.intersect_with_line(segment))
+        }
+        pub fn intersect_with_multi_polygon(&self, multi_polygon:&MultiPolygon) -> LinePolygonIntersectionResult {
+            self.intersect_with_polygon(multi_polygon.get_bounding_box(), |segment| multi_polygon.intersect_with_line(segment))
+        }
+
+        fn intersect_with_polygon<F:Fn(&Line) -> LinePolygonIntersectionResult>(&self, bounding_box:&BoundingBox, segment_test:F) -> LinePolygonIntersectionResult {
+            match self.get_segment_beyond_bounding_box(bounding_box) {
+                None => LinePolygonIntersectionResult { points:vec![], contact:false, intersect:false, inverse_intersect:true, traverse:false },
+                Some(segment) => {
+                    let mut output_result = segment_test(&segment);
+                    output_result.inverse_intersect = true; //the ray always carries on out beyond the polygon
+                    output_result
+                },
+            }
+        }
+    }
\ No newline at end of file
@@ -3,8 +3,10 @@
 
 use std::fmt;
 
+use super::fill_rule::FillRule;
 use super::intersect_result_types::{
     Direction,
+    EdgeContact,
     LinePolygonIntersectionResult,
     LineSide,
     PolygonIntersectionResult,
@@ -14,7 +16,16 @@ use super::intersect_result_types::{
 use super::bounding_box::BoundingBox;
 use super::point::Point;
 use super::line::Line;
+use super::polyline::Polyline;
 use super::complex_polygon::ComplexPolygon;
+use super::multi_polygon::MultiPolygon;
+
+use super::super::offset::Offset;
+
+use super::super::super::math::triangulation::{
+    constrained_delaunay,
+    TriangulationRefinement,
+};
 
 
 
@@ -28,9 +39,27 @@ use super::complex_polygon::ComplexPolygon;
         points: Vec<Point>,
         bounding_box: BoundingBox,
         lines: Vec<Line>,
+        fill_rule: FillRule,
+        revision: u64, //incremented with every change, so anything derived from the polygon can tell when it's out of date
     }
 //calculation
     impl SimplePolygon {
+        fn rebuild(&mut self) {
+            self.bounding_box = BoundingBox::new_from_points(&self.points);
+            self.lines = SimplePolygon::produce_lines(&self.points);
+        }
+        fn expand_bounding_box(&mut self, point:&Point) {
+            if !self.bounding_box.intersect_with_point(point) {
+                self.bounding_box = BoundingBox::new_from_points(&[*self.bounding_box.get_top_left(), *self.bounding_box.get_bottom_right(), *point]);
+            }
+        }
+        fn is_on_bounding_box_edge(&self, point:&Point) -> bool {
+            point.get_x() == self.bounding_box.get_top_left().get_x() || point.get_x() == self.bounding_box.get_bottom_right().get_x() ||
+            point.get_y() == self.bounding_box.get_top_left().get_y() || point.get_y() == self.bounding_box.get_bottom_right().get_y()
+        }
+        fn mark_changed(&mut self) {
+            self.revision = self.revision.wrapping_add(1);
+        }
         fn produce_lines(points:&Vec<Point>) -> Vec<Line> {
             if points.len() == 0 {
                 return vec![];
@@ -64,6 +93,8 @@ use super::complex_polygon::ComplexPolygon;
                 points: points,
                 bounding_box: bounding_box,
                 lines: lines,
+                fill_rule: FillRule::new_default(),
+                revision: 0,
             }
         }
         pub fn new_default() -> SimplePolygon {
@@ -132,6 +163,8 @@ use super::complex_polygon::ComplexPolygon;
                 points: self.points.clone(),
                 bounding_box: self.bounding_box.clone(),
                 lines: self.lines.clone(),
+                fill_rule: self.fill_rule,
+                revision: self.revision,
             }
         }
     }
@@ -175,18 +208,208 @@ use super::complex_polygon::ComplexPolygon;
             pub fn get_bounding_box(&self) -> &BoundingBox {
                 &self.bounding_box
             }
+
+        //fill rule
+            pub fn get_fill_rule(&self) -> FillRule {
+                self.fill_rule
+            }
+
+        //revision
+            pub fn get_revision(&self) -> u64 {
+                self.revision
+            }
     }
 //setters
     impl SimplePolygon {
         pub fn prepend_point(&mut self, point:Point) {
-            self.points.insert(0, point);
-            self.bounding_box = BoundingBox::new_from_points(&self.points);
-            self.lines = SimplePolygon::produce_lines(&self.points);
+            self.insert_point(0, point);
         }
         pub fn add_point(&mut self, point:Point) {
-            self.points.push(point);
-            self.bounding_box = BoundingBox::new_from_points(&self.points);
+            self.insert_point(self.points.len(), point);
+        }
+        pub fn set_fill_rule(&mut self, new:FillRule) {
+            self.fill_rule = new;
+            self.mark_changed();
+        }
+    }
+//editing
+    //these only touch the lines either side of the edited point, and only fully recalculate the bounding box when
+    //a point that was holding up one of its edges moves inwards or is removed
+    impl SimplePolygon {
+        pub fn insert_point(&mut self, index:usize, point:Point) {
+            if index > self.points.len() {
+                println!("WARNING - SimplePolygon::insert_point - index {} is beyond the end of the polygon ({} points)", index, self.points.len());
+                return;
+            }
+
+            self.points.insert(index, point);
+            self.mark_changed();
+
+            //with so few points, the lines are quicker to just rebuild
+                if self.points.len() <= 3 {
+                    self.rebuild();
+                    return;
+                }
+
+            //the line that used to end at the point now here, now ends at the new point; and a new line runs from the new point to the next
+                let count = self.points.len();
+                let previous = (index + count - 1) % count;
+                let next = (index + 1) % count;
+                self.lines.insert(index, Line::new_from_points(self.points[index], self.points[next]));
+                self.lines[previous].set_point_b(self.points[index]);
+
+            self.expand_bounding_box(&point);
+        }
+        pub fn remove_point(&mut self, index:usize) -> Option<Point> {
+            if index >= self.points.len() {
+                println!("WARNING - SimplePolygon::remove_point - index {} is beyond the end of the polygon ({} points)", index, self.points.len());
+                return None;
+            }
+
+            let point = self.points.remove(index);
+            self.mark_changed();
+
+            //with so few points, the lines are quicker to just rebuild
+                if self.points.len() <= 2 {
+                    self.rebuild();
+                    return Some(point);
+                }
+
+            //the line that started at the removed point goes, and the line before it is stretched over to the next point
+                let count = self.points.len();
+                let previous = (index + count - 1) % count;
+                self.lines.remove(index);
+                self.lines[previous].set_point_b(self.points[(previous + 1) % count]);
+
+            if self.is_on_bounding_box_edge(&point) {
+                self.bounding_box = BoundingBox::new_from_points(&self.points);
+            }
+
+            Some(point)
+        }
+        pub fn move_point(&mut self, index:usize, point:Point) {
+            if index >= self.points.len() {
+                println!("WARNING - SimplePolygon::move_point - index {} is beyond the end of the polygon ({} points)", index, self.points.len());
+                return;
+            }
+
+            let old_point = self.points[index];
+            self.points[index] = point;
+            self.mark_changed();
+
+            let count = self.points.len();
+            self.lines[index].set_point_a(point);
+            self.lines[(index + count - 1) % count].set_point_b(point);
+
+            if self.is_on_bounding_box_edge(&old_point) {
+                self.bounding_box = BoundingBox::new_from_points(&self.points);
+            } else {
+                self.expand_bounding_box(&point);
+            }
+        }
+        //flip the winding direction; the bounding box doesn't change, but every line does
+        pub fn reverse(&mut self) {
+            self.points.reverse();
             self.lines = SimplePolygon::produce_lines(&self.points);
+            self.mark_changed();
+        }
+    }
+//transform
+    //every point moves, so the lines and bounding box are rebuilt outright (though translation can just shift them along)
+    //the get_ versions leave the polygon alone and return a moved copy
+    impl SimplePolygon {
+        fn transform_points<F:Fn(&mut Point)>(&mut self, transform:F) {
+            self.points.iter_mut().for_each(transform);
+            self.rebuild();
+            self.mark_changed();
+        }
+
+        pub fn translate(&mut self, x:f32, y:f32) {
+            self.points.iter_mut().for_each(|point| point.translate(x, y));
+            self.lines.iter_mut().for_each(|line| line.translate(x, y));
+            self.bounding_box.translate(x, y);
+            self.mark_changed();
+        }
+        pub fn rotate(&mut self, angle:f32, anchor:&Point) {
+            self.transform_points(|point| point.rotate(angle, anchor));
+        }
+        pub fn scale(&mut self, scale:f32, anchor:&Point) {
+            self.transform_points(|point| point.scale(scale, anchor));
+        }
+        pub fn apply_offset(&mut self, offset:&Offset) {
+            self.transform_points(|point| point.apply_offset(offset));
+        }
+
+        pub fn get_translated(&self, x:f32, y:f32) -> SimplePolygon {
+            let mut output = self.clone();
+            output.translate(x, y);
+            output
+        }
+        pub fn get_rotated(&self, angle:f32, anchor:&Point) -> SimplePolygon {
+            let mut output = self.clone();
+            output.rotate(angle, anchor);
+            output
+        }
+        pub fn get_scaled(&self, scale:f32, anchor:&Point) -> SimplePolygon {
+            let mut output = self.clone();
+            output.scale(scale, anchor);
+            output
+        }
+        pub fn get_with_offset_applied(&self, offset:&Offset) -> SimplePolygon {
+            let mut output = self.clone();
+            output.apply_offset(offset);
+            output
+        }
+    }
+//winding
+    impl SimplePolygon {
+        //the number of times the perimeter winds around the point (clockwise and anti-clockwise windings cancel each other out)
+        //points that are on the perimeter itself aren't given any special treatment here, see intersect_with_point for that
+        pub fn get_winding_number(&self, point:&Point) -> isize {
+            let mut winding_number:isize = 0;
+
+            for line in &self.lines {
+                let a_y = line.get_point_a().get_y();
+                let b_y = line.get_point_b().get_y();
+
+                if a_y <= point.get_y() {
+                    //crossing the point's level in increasing y, with the point on the line's right
+                    if b_y > point.get_y() && line.intersect_with_point(point) == LineSide::Right {
+                        winding_number += 1;
+                    }
+                } else {
+                    //crossing the point's level in decreasing y, with the point on the line's left
+                    if b_y <= point.get_y() && line.intersect_with_point(point) == LineSide::Left {
+                        winding_number -= 1;
+                    }
+                }
+            }
+
+            winding_number
+        }
+    }
+//measurement
+    impl SimplePolygon {
+        //shoelace formula; positive for polygons wound clockwise (on screen, where y increases downwards)
+        pub fn get_signed_area(&self) -> f32 {
+            let mut sum = 0.0;
+            for line in &self.lines {
+                sum += line.get_point_a().get_x() * line.get_point_b().get_y() - line.get_point_b().get_x() * line.get_point_a().get_y();
+            }
+            sum / 2.0
+        }
+        pub fn get_area(&self) -> f32 {
+            self.get_signed_area().abs()
+        }
+        pub fn get_perimeter(&self) -> f32 {
+            let mut sum = 0.0;
+            for line in &self.lines {
+                sum += line.get_length();
+            }
+            sum
+        }
+        pub fn is_clockwise(&self) -> bool {
+            self.get_signed_area() >= 0.0
         }
     }
 //sub triangles
@@ -217,6 +440,10 @@ use super::complex_polygon::ComplexPolygon;
 
             output
         }
+        //triangles that keep to the polygon's edges, and are otherwise as close to equilateral as possible
+        pub fn to_constrained_delaunay_triangles(&self, refinement:&TriangulationRefinement) -> Vec<SimplePolygon> {
+            constrained_delaunay(self, &[], refinement)
+        }
     }
 //printing
     impl SimplePolygon {
@@ -256,6 +483,40 @@ use super::complex_polygon::ComplexPolygon;
 //intersect
     impl SimplePolygon {
         pub fn intersect_with_point(&self, point:&Point) -> PolySide {
+            self.intersect_with_point_with_fill_rule(point, self.fill_rule)
+        }
+        pub fn intersect_with_point_with_fill_rule(&self, point:&Point, fill_rule:FillRule) -> PolySide {
+            match fill_rule {
+                FillRule::EvenOdd => self.intersect_with_point__even_odd(point),
+                FillRule::NonZero => self.intersect_with_point__non_zero(point),
+            }
+        }
+        fn intersect_with_point__non_zero(&self, point:&Point) -> PolySide {
+            if !self.get_bounding_box().intersect_with_point(point) {
+                return PolySide::Outside;
+            }
+
+            //check if the point is on a point of the poly; bail and return 'onPoint'
+            for poly_point in &self.points {
+                if point.get_x() == poly_point.get_x() && point.get_y() == poly_point.get_y() {
+                    return PolySide::OnPoint;
+                }
+            }
+
+            //check if the point is on one of the poly's lines; bail and return 'onEdge'
+            for line in &self.lines {
+                if line.intersect_with_point(point) == LineSide::Upon {
+                    return PolySide::OnEdge;
+                }
+            }
+
+            if self.get_winding_number(point) != 0 {
+                PolySide::Inside
+            } else {
+                PolySide::Outside
+            }
+        }
+        fn intersect_with_point__even_odd(&self, point:&Point) -> PolySide {
             if !self.get_bounding_box().intersect_with_bounding_box(&BoundingBox::new_from_points(&[*point])) {
                 return PolySide::Outside; 
             }
@@ -391,6 +652,9 @@ use super::complex_polygon::ComplexPolygon;
             }
         }
         //bounding box //currently not needed
+        pub fn intersect_with_polyline(&self, polyline:&Polyline) -> LinePolygonIntersectionResult {
+            polyline.intersect_with_simple_polygon(self)
+        }
         pub fn intersect_with_line(&self, line:&Line) -> LinePolygonIntersectionResult {
             //quick detection of clearly different shapes
                 if !self.get_bounding_box().intersect_with_bounding_box(&line.get_bounding_box()) {
@@ -708,9 +972,24 @@ use super::complex_polygon::ComplexPolygon;
 
             output_result
         }
+        pub fn intersect_with_simple_polygon_with_fill_rule(&self, other:&SimplePolygon, fill_rule:FillRule) -> PolygonIntersectionResult {
+            //containment is decided by each polygon's own fill rule, so if either one differs, work with copies that use the requested rule
+            if self.fill_rule == fill_rule && other.fill_rule == fill_rule {
+                return self.intersect_with_simple_polygon(other);
+            }
+
+            let mut self_copy = self.clone();
+            let mut other_copy = other.clone();
+            self_copy.set_fill_rule(fill_rule);
+            other_copy.set_fill_rule(fill_rule);
+            self_copy.intersect_with_simple_polygon(&other_copy)
+        }
         pub fn intersect_with_complex_polygon(&self, complex_polygon:&ComplexPolygon) -> PolygonIntersectionResult {
             complex_polygon.intersect_with_simple_polygon(self)
         }
+        pub fn intersect_with_multi_polygon(&self, multi_polygon:&MultiPolygon) -> PolygonIntersectionResult {
+            multi_polygon.intersect_with_simple_polygon(self)
+        }
 
         //reduced functionality, for pure speed
         pub fn intersect_with_simple_polygon__intersect_only(&self, other:&SimplePolygon) -> bool {
@@ -808,4 +1087,32 @@ use super::complex_polygon::ComplexPolygon;
 
             output_result.intersect
         }
+    }
+//intersect (extended results)
+    //contacts give the index of the edge (matching get_lines) and position along it, for both shapes
+    impl SimplePolygon {
+        pub fn intersect_with_line__contacts(&self, line:&Line) -> Vec<EdgeContact> {
+            if !self.get_bounding_box().intersect_with_bounding_box(&line.get_bounding_box()) {
+                return vec![];
+            }
+            Line::intersect_lines_with_lines__contacts(&self.lines, &[*line])
+        }
+        pub fn intersect_with_polyline__contacts(&self, polyline:&Polyline) -> Vec<EdgeContact> {
+            if !self.get_bounding_box().intersect_with_bounding_box(polyline.get_bounding_box()) {
+                return vec![];
+            }
+            Line::intersect_lines_with_lines__contacts(&self.lines, polyline.get_lines())
+        }
+        pub fn intersect_with_simple_polygon__contacts(&self, other:&SimplePolygon) -> Vec<EdgeContact> {
+            if !self.get_bounding_box().intersect_with_bounding_box(other.get_bounding_box()) {
+                return vec![];
+            }
+            Line::intersect_lines_with_lines__contacts(&self.lines, &other.lines)
+        }
+        pub fn intersect_with_complex_polygon__contacts(&self, complex_polygon:&ComplexPolygon) -> Vec<EdgeContact> {
+            if !self.get_bounding_box().intersect_with_bounding_box(complex_polygon.get_bounding_box()) {
+                return vec![];
+            }
+            Line::intersect_lines_with_lines__contacts(&self.lines, &complex_polygon.get_all_lines())
+        }
     }
\ No newline at end of file
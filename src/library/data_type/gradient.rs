@@ -0,0 +1,262 @@
+use std::fmt;
+
+use super::colour::Colour;
+use super::offset::Offset;
+use super::geometry::Point;
+
+use super::super::math::cartesian_angle_adjust;
+
+
+
+
+
+
+
+
+//kind
+    //where the gradient runs, in its own space (see Gradient's transform)
+    #[derive(Copy, Clone, PartialEq)]
+    pub enum GradientKind {
+        Linear { start:Point, end:Point },
+        Radial { centre:Point, radius:f32 },
+        Conic { centre:Point, angle:f32 }, //sweeps clockwise (on screen) from the angle, all the way round
+    }
+    impl fmt::Display for GradientKind {
+        fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result {
+            match self {
+                GradientKind::Linear { start, end } => write!(f, "Linear{{start:{}, end:{}}}", start, end),
+                GradientKind::Radial { centre, radius } => write!(f, "Radial{{centre:{}, radius:{}}}", centre, radius),
+                GradientKind::Conic { centre, angle } => write!(f, "Conic{{centre:{}, angle:{}}}", centre, angle),
+            }
+        }
+    }
+    impl fmt::Debug for GradientKind {
+        fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { fmt::Display::fmt(self, f) }
+    }
+
+
+
+
+
+
+
+
+//spread mode
+    //what happens beyond the first and last stops
+    #[derive(Copy, Clone, PartialEq, Debug)]
+    pub enum SpreadMode {
+        Pad, //the end colours carry on
+        Repeat, //start again from the first stop
+        Reflect, //run back through the stops, then forwards again, and so on
+    }
+    impl SpreadMode {
+        pub fn apply(&self, t:f32) -> f32 {
+            match self {
+                SpreadMode::Pad => t.clamp(0.0, 1.0),
+                SpreadMode::Repeat => t.rem_euclid(1.0),
+                SpreadMode::Reflect => 1.0 - (t.rem_euclid(2.0) - 1.0).abs(),
+            }
+        }
+    }
+    impl fmt::Display for SpreadMode {
+        fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { fmt::Debug::fmt(self, f) }
+    }
+
+
+
+
+
+
+
+
+//colour stop
+    #[derive(Copy, Clone)]
+    pub struct ColourStop {
+        pub offset: f32, //0 to 1
+        pub colour: Colour,
+    }
+    impl ColourStop {
+        pub fn new(offset:f32, colour:Colour) -> ColourStop {
+            ColourStop { offset, colour }
+        }
+    }
+    impl fmt::Display for ColourStop {
+        fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result {
+            write!(f, "{{offset:{}, colour:{}}}", self.offset, self.colour)
+        }
+    }
+    impl fmt::Debug for ColourStop {
+        fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { fmt::Display::fmt(self, f) }
+    }
+
+
+
+
+
+
+
+
+//struct
+    //colours between stops are blended in linear premultiplied terms, as the renderer does, so that a stop fading out
+    //to transparent doesn't darken on the way
+    pub struct Gradient {
+        kind: GradientKind,
+        stops: Vec<ColourStop>, //kept in order of offset
+        spread: SpreadMode,
+        transform: Offset, //takes the gradient's own space into the world
+    }
+//new
+    impl Gradient {
+        pub fn new(kind:GradientKind, stops:Vec<ColourStop>, spread:SpreadMode, transform:Offset) -> Gradient {
+            let mut gradient = Gradient {
+                kind,
+                stops: vec![],
+                spread,
+                transform,
+            };
+            gradient.set_stops(stops);
+            gradient
+        }
+        pub fn new_linear(start:Point, end:Point, stops:Vec<ColourStop>) -> Gradient {
+            Gradient::new(GradientKind::Linear { start, end }, stops, SpreadMode::Pad, Offset::new_default())
+        }
+        pub fn new_radial(centre:Point, radius:f32, stops:Vec<ColourStop>) -> Gradient {
+            Gradient::new(GradientKind::Radial { centre, radius }, stops, SpreadMode::Pad, Offset::new_default())
+        }
+        pub fn new_conic(centre:Point, angle:f32, stops:Vec<ColourStop>) -> Gradient {
+            Gradient::new(GradientKind::Conic { centre, angle }, stops, SpreadMode::Pad, Offset::new_default())
+        }
+    }
+//clone
+    impl Gradient {
+        pub fn clone(&self) -> Gradient {
+            Gradient {
+                kind: self.kind,
+                stops: self.stops.clone(),
+                spread: self.spread,
+                transform: self.transform.clone(),
+            }
+        }
+    }
+//getters
+    impl Gradient {
+        pub fn get_kind(&self) -> &GradientKind { &self.kind }
+        pub fn get_stops(&self) -> &Vec<ColourStop> { &self.stops }
+        pub fn get_spread(&self) -> SpreadMode { self.spread }
+        pub fn get_transform(&self) -> &Offset { &self.transform }
+    }
+//setters
+    impl Gradient {
+        pub fn set_kind(&mut self, new:GradientKind) { self.kind = new; }
+        pub fn set_stops(&mut self, new:Vec<ColourStop>) {
+            self.stops = new;
+            self.stops.iter_mut().for_each(|stop| stop.offset = stop.offset.clamp(0.0, 1.0));
+            self.stops.sort_by(|a, b| a.offset.partial_cmp(&b.offset).unwrap_or(std::cmp::Ordering::Equal));
+        }
+        pub fn add_stop(&mut self, stop:ColourStop) {
+            let mut stops = self.stops.clone();
+            stops.push(stop);
+            self.set_stops(stops);
+        }
+        pub fn set_spread(&mut self, new:SpreadMode) { self.spread = new; }
+        pub fn set_transform(&mut self, new:Offset) { self.transform = new; }
+    }
+//evaluation
+    impl Gradient {
+        //where in the gradient's own space a world point falls; the inverse of the transform
+        pub fn get_local_point(&self, point:&Point) -> Point {
+            let transform = &self.transform;
+            let scale = if transform.get_scale() == 0.0 { 1.0 } else { transform.get_scale() };
+            cartesian_angle_adjust(
+                (point.get_x() - transform.get_x()) / scale,
+                (point.get_y() - transform.get_y()) / scale,
+                -transform.get_angle(),
+            )
+        }
+        //how far along the gradient (before spreading) a world point is
+        pub fn get_parameter_at_point(&self, point:&Point) -> f32 {
+            let point = self.get_local_point(point);
+
+            match self.kind {
+                GradientKind::Linear { start, end } => {
+                    let (dx, dy) = (end.get_x() - start.get_x(), end.get_y() - start.get_y());
+                    let length_squared = dx * dx + dy * dy;
+                    if length_squared == 0.0 { return 0.0; }
+                    ((point.get_x() - start.get_x()) * dx + (point.get_y() - start.get_y()) * dy) / length_squared
+                },
+                GradientKind::Radial { centre, radius } => {
+                    if radius == 0.0 { return 1.0; }
+                    ((point.get_x() - centre.get_x()).powi(2) + (point.get_y() - centre.get_y()).powi(2)).sqrt() / radius
+                },
+                GradientKind::Conic { centre, angle } => {
+                    let point_angle = (point.get_y() - centre.get_y()).atan2(point.get_x() - centre.get_x());
+                    ((point_angle - angle) / (2.0 * std::f32::consts::PI)).rem_euclid(1.0)
+                },
+            }
+        }
+        pub fn get_colour_at_point(&self, point:&Point) -> Colour {
+            self.get_colour_at_parameter( self.spread.apply(self.get_parameter_at_point(point)) )
+        }
+        //t from 0 to 1 along the stops
+        pub fn get_colour_at_parameter(&self, t:f32) -> Colour {
+            let first = match self.stops.first() {
+                None => { return Colour::new(0.0, 0.0, 0.0, 0.0); },
+                Some(stop) => stop,
+            };
+            if t <= first.offset {
+                return first.colour;
+            }
+
+            for pair in self.stops.windows(2) {
+                let (before, after) = (&pair[0], &pair[1]);
+                if t <= after.offset {
+                    let span = after.offset - before.offset;
+                    let fraction = if span <= 0.0 { 1.0 } else { (t - before.offset) / span };
+                    return mix_premultiplied_linear(&before.colour, &after.colour, fraction);
+                }
+            }
+
+            self.stops.last().unwrap().colour
+        }
+    }
+//printing
+    impl Gradient {
+        fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result {
+            write!(
+                f, "{{kind:{}, stops:{:?}, spread:{}, transform:{}}}",
+                self.kind,
+                self.stops,
+                self.spread,
+                self.transform,
+            )
+        }
+    }
+    impl fmt::Display for Gradient {
+        fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { self.fmt(f) }
+    }
+    impl fmt::Debug for Gradient {
+        fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { self.fmt(f) }
+    }
+
+
+
+
+
+
+
+
+//utilities
+    fn mix_premultiplied_linear(a:&Colour, b:&Colour, t:f32) -> Colour {
+        let a = a.to_premultiplied_linear();
+        let b = b.to_premultiplied_linear();
+        let alpha = a[3] + (b[3] - a[3]) * t;
+        if alpha <= 0.0 {
+            return Colour::new(0.0, 0.0, 0.0, 0.0);
+        }
+        Colour::new_from_linear(
+            (a[0] + (b[0] - a[0]) * t) / alpha,
+            (a[1] + (b[1] - a[1]) * t) / alpha,
+            (a[2] + (b[2] - a[2]) * t) / alpha,
+            alpha,
+        )
+    }
\ No newline at end of file
@@ -0,0 +1,227 @@
+use std::fmt;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use super::colour::{
+    linear_to_srgb,
+    srgb_to_linear,
+};
+use super::geometry::BoundingBox;
+
+//every image made gets its own id, so that the renderer can tell which it's already uploaded
+static NEXT_IMAGE_ID:AtomicUsize = AtomicUsize::new(0);
+
+
+
+
+
+
+
+
+//a bitmap, as four bytes (red, green, blue and alpha; sRGB, and not premultiplied) per pixel, row by row from the
+//top. Cloning is cheap (the pixels are shared), and clones keep the id
+#[derive(Clone)]
+pub struct Image {
+    id: usize,
+    width: u32,
+    height: u32,
+    pixels: Arc<Vec<u8>>,
+}
+//new
+    impl Image {
+        pub fn new_from_rgba8(width:u32, height:u32, pixels:Vec<u8>) -> Option<Image> {
+            if width == 0 || height == 0 {
+                println!("WARNING - Image::new_from_rgba8 : the image has no area ({}x{})", width, height);
+                return None;
+            }
+            //in u64, as the byte count of a large enough image doesn't fit in a u32
+            let byte_count = width as u64 * height as u64 * 4;
+            if pixels.len() as u64 != byte_count {
+                println!("WARNING - Image::new_from_rgba8 : {} bytes given, but a {}x{} image needs {}", pixels.len(), width, height, byte_count);
+                return None;
+            }
+
+            Some(Image {
+                id: NEXT_IMAGE_ID.fetch_add(1, Ordering::Relaxed),
+                width,
+                height,
+                pixels: Arc::new(pixels),
+            })
+        }
+        //PNG or JPEG, told apart by the bytes themselves
+        pub fn new_from_bytes(bytes:&[u8]) -> Option<Image> {
+            match ::image::load_from_memory(bytes) {
+                Ok(decoded) => {
+                    let decoded = decoded.into_rgba8();
+                    Image::new_from_rgba8(decoded.width(), decoded.height(), decoded.into_raw())
+                },
+                Err(error) => {
+                    println!("WARNING - Image::new_from_bytes : the image couldn't be decoded ({})", error);
+                    None
+                },
+            }
+        }
+        pub fn new_from_file(path:&str) -> Option<Image> {
+            match std::fs::read(path) {
+                Ok(bytes) => Image::new_from_bytes(&bytes),
+                Err(error) => {
+                    println!("WARNING - Image::new_from_file : couldn't read \"{}\" ({})", path, error);
+                    None
+                },
+            }
+        }
+    }
+//getters
+    impl Image {
+        pub fn get_id(&self) -> usize { self.id }
+        pub fn get_width(&self) -> u32 { self.width }
+        pub fn get_height(&self) -> u32 { self.height }
+        pub fn get_pixels(&self) -> &Vec<u8> { &self.pixels }
+        pub fn get_bounding_box(&self) -> BoundingBox {
+            BoundingBox::new(0.0, 0.0, self.width as f32, self.height as f32)
+        }
+    }
+//source rectangles
+    impl Image {
+        //the part of the image given (in pixels) as fractions of its size, for texture coordinates
+        pub fn get_uv(&self, source:&BoundingBox) -> BoundingBox {
+            BoundingBox::new(
+                source.get_top_left().get_x() / self.width as f32,
+                source.get_top_left().get_y() / self.height as f32,
+                source.get_bottom_right().get_x() / self.width as f32,
+                source.get_bottom_right().get_y() / self.height as f32,
+            )
+        }
+        //the source rectangle pulled within the image (and put the right way round), with a warning if it wasn't
+        pub fn get_clamped_source(&self, source:&BoundingBox) -> BoundingBox {
+            let clamp = |value:f32, maximum:u32| value.clamp(0.0, maximum as f32);
+            let (left, right) = (source.get_top_left().get_x(), source.get_bottom_right().get_x());
+            let (top, bottom) = (source.get_top_left().get_y(), source.get_bottom_right().get_y());
+            let clamped = BoundingBox::new(
+                clamp(left.min(right), self.width), clamp(top.min(bottom), self.height),
+                clamp(left.max(right), self.width), clamp(top.max(bottom), self.height),
+            );
+            if clamped.get_top_left() != source.get_top_left() || clamped.get_bottom_right() != source.get_bottom_right() {
+                println!("WARNING - Image::get_clamped_source : the source {} isn't within the {}x{} image; using {}", source, self.width, self.height, clamped);
+            }
+            clamped
+        }
+    }
+//mipmaps
+    impl Image {
+        //down to a single pixel
+        pub fn get_mip_level_count(&self) -> u32 {
+            32 - self.width.max(self.height).leading_zeros()
+        }
+
+        //the pixels premultiplied (done in linear space, then taken back to sRGB) as the pipeline wants them, followed
+        //by the smaller levels if asked for. Each level is half the size of the one before (rounding down, but never
+        //below a pixel), with each of its pixels the average of the four beneath it
+        pub fn get_premultiplied_mip_levels(&self, mipmaps:bool) -> Vec<(u32, u32, Vec<u8>)> {
+            let mut linear:Vec<[f32; 4]> = self.pixels.chunks(4).map(|pixel| {
+                let a = pixel[3] as f32 / 255.0;
+                [
+                    srgb_to_linear(pixel[0] as f32 / 255.0) * a,
+                    srgb_to_linear(pixel[1] as f32 / 255.0) * a,
+                    srgb_to_linear(pixel[2] as f32 / 255.0) * a,
+                    a,
+                ]
+            }).collect();
+            let encode = |linear:&[[f32; 4]]| -> Vec<u8> {
+                linear.iter().flat_map(|pixel| {
+                    let to_byte = |value:f32| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+                    vec![to_byte(linear_to_srgb(pixel[0])), to_byte(linear_to_srgb(pixel[1])), to_byte(linear_to_srgb(pixel[2])), to_byte(pixel[3])]
+                }).collect()
+            };
+
+            let (mut width, mut height) = (self.width, self.height);
+            let mut output = vec![(width, height, encode(&linear))];
+            if !mipmaps {
+                return output;
+            }
+
+            while width > 1 || height > 1 {
+                let (next_width, next_height) = ((width / 2).max(1), (height / 2).max(1));
+                let mut next = vec![[0.0; 4]; (next_width * next_height) as usize];
+                for y in 0..next_height {
+                    for x in 0..next_width {
+                        //odd sizes leave the last row or column out, which is near enough
+                        let sources = [
+                            (x * 2, y * 2),
+                            ((x * 2 + 1).min(width - 1), y * 2),
+                            (x * 2, (y * 2 + 1).min(height - 1)),
+                            ((x * 2 + 1).min(width - 1), (y * 2 + 1).min(height - 1)),
+                        ];
+                        let pixel = &mut next[(y * next_width + x) as usize];
+                        for (source_x, source_y) in sources.iter() {
+                            let source = linear[(source_y * width + source_x) as usize];
+                            for (total, value) in pixel.iter_mut().zip(source.iter()) {
+                                *total += value / 4.0;
+                            }
+                        }
+                    }
+                }
+
+                linear = next;
+                width = next_width;
+                height = next_height;
+                output.push((width, height, encode(&linear)));
+            }
+            output
+        }
+    }
+//printing
+    impl fmt::Display for Image {
+        fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result {
+            write!(f, "{{id:{}, width:{}, height:{}}}", self.id, self.width, self.height)
+        }
+    }
+    impl fmt::Debug for Image {
+        fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { fmt::Display::fmt(self, f) }
+    }
+
+
+
+
+
+
+
+
+//tests
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn checks_the_byte_count() {
+            assert!(Image::new_from_rgba8(2, 1, vec![0; 8]).is_some());
+            assert!(Image::new_from_rgba8(2, 1, vec![0; 7]).is_none());
+            assert!(Image::new_from_rgba8(0, 1, vec![]).is_none());
+            //65536 by 16384 by 4 bytes wraps around to 0 in a u32
+            assert!(Image::new_from_rgba8(65536, 16384, vec![]).is_none());
+        }
+
+        #[test]
+        fn decodes_png() {
+            //2x2; red, green, blue, and half transparent white
+            let image = Image::new_from_bytes(include_bytes!("test_image.png")).unwrap();
+            assert_eq!((image.get_width(), image.get_height()), (2, 2));
+            assert_eq!(image.get_pixels(), &vec![255,0,0,255, 0,255,0,255, 0,0,255,255, 255,255,255,128]);
+        }
+
+        #[test]
+        fn decodes_jpeg() {
+            //8x8, all (200, 100, 50); lossy, so only near enough
+            let image = Image::new_from_bytes(include_bytes!("test_image.jpg")).unwrap();
+            assert_eq!((image.get_width(), image.get_height()), (8, 8));
+            for pixel in image.get_pixels().chunks(4) {
+                let expected:[u8;4] = [200, 100, 50, 255];
+                assert!(pixel.iter().zip(expected.iter()).all(|(value, expected)| (*value as i32 - *expected as i32).abs() <= 3), "{:?}", pixel);
+            }
+        }
+
+        #[test]
+        fn rejects_other_bytes() {
+            assert!(Image::new_from_bytes(&[0, 1, 2, 3]).is_none());
+        }
+    }
\ No newline at end of file
@@ -0,0 +1,63 @@
+use std::fmt;
+
+
+
+
+
+
+
+
+//image filter
+    //how an image's pixels are picked out when it's drawn at some other size than its own
+    #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+    pub enum ImageFilter {
+        Nearest, //hard edged pixels, for pixel art
+        Linear,
+    }
+    impl fmt::Display for ImageFilter {
+        fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { fmt::Debug::fmt(self, f) }
+    }
+
+
+
+
+
+
+
+
+//image sampling
+    //mipmaps (the image again at half the size, and half that, and so on) keep an image from shimmering when it's
+    //drawn much smaller than it is, at the cost of a third more memory
+    #[derive(Copy, Clone, PartialEq, Eq, Hash)]
+    pub struct ImageSampling {
+        filter: ImageFilter,
+        mipmaps: bool,
+    }
+    //new
+        impl ImageSampling {
+            pub fn new(filter:ImageFilter, mipmaps:bool) -> ImageSampling {
+                ImageSampling { filter, mipmaps }
+            }
+            pub fn new_default() -> ImageSampling {
+                ImageSampling::new(ImageFilter::Linear, false)
+            }
+        }
+    //getters
+        impl ImageSampling {
+            pub fn get_filter(&self) -> ImageFilter { self.filter }
+            pub fn get_mipmaps(&self) -> bool { self.mipmaps }
+        }
+    //setters
+        impl ImageSampling {
+            pub fn set_filter(&mut self, new:ImageFilter) { self.filter = new; }
+            pub fn set_mipmaps(&mut self, new:bool) { self.mipmaps = new; }
+        }
+    //printing
+        impl fmt::Display for ImageSampling {
+            fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result {
+                write!(f, "{{filter:{}, mipmaps:{}}}", self.filter, self.mipmaps)
+            }
+        }
+        impl fmt::Debug for ImageSampling {
+            fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { fmt::Display::fmt(self, f) }
+        }
\ No newline at end of file
@@ -3,35 +3,55 @@ pub use geometry::{*};
 
 pub mod wgpu;
 
-// mod callback_activation_mode;
-// pub use callback_activation_mode::{*};
+mod blend;
+pub use blend::{*};
 
-// mod callback_type;
-// pub use callback_type::{*};
+mod callback_activation_mode;
+pub use callback_activation_mode::{*};
+
+mod callback_type;
+pub use callback_type::{*};
 
 mod colour;
 pub use colour::{*};
 
-// mod event;
-// pub use event::{*};
+mod css_colour_names;
+
+mod event;
+pub use event::{*};
+
+mod gradient;
+pub use gradient::{*};
+
+mod image;
+pub use self::image::{*};
+
+mod image_sampling;
+pub use image_sampling::{*};
 
 mod offset;
 pub use offset::{*};
 
+mod palette;
+pub use palette::{*};
+
 // mod path_cap_type;
 // pub use path_cap_type::{*};
 
 // mod path_joint_type;
 // pub use path_joint_type::{*};
 
-// mod printing_mode_position;
-// pub use printing_mode_position::{*};
+mod printing_mode_position;
+pub use printing_mode_position::{*};
 
-// mod render_decision;
-// pub use render_decision::{*};
+mod render_decision;
+pub use render_decision::{*};
 
 mod viewbox;
 pub use viewbox::{*};
 
+mod viewbox_constraints;
+pub use viewbox_constraints::{*};
+
 mod dimensions;
 pub use dimensions::Dimensions;
\ No newline at end of file
@@ -96,6 +96,17 @@ impl Offset {
         self.scale = other.get_scale() * scale;
         self.angle = other.get_angle() + angle;
     }
+    //the offset that undoes this one; applying one then the other leaves a point where it started. A scale of zero
+    //can't be undone, so gives the default offset instead
+    pub fn get_inverse(&self) -> Offset {
+        if self.scale == 0.0 {
+            println!("WARNING - Offset::get_inverse : an offset with a scale of 0 has no inverse; returning the default offset");
+            return Offset::new_default();
+        }
+
+        let point = cartesian_angle_adjust(-self.x / self.scale, -self.y / self.scale, -self.angle);
+        Offset::new( point.get_x(), point.get_y(), 1.0 / self.scale, -self.angle )
+    }
 }
 impl Offset {
     pub fn clone(&self) -> Offset {
@@ -0,0 +1,208 @@
+use std::fmt;
+
+use super::colour::Colour;
+
+
+
+
+
+
+
+
+//colour map control points
+    //evenly spaced samples of each map (as 0xrrggbb), with the in-between colours filled in through OKLab. The
+    //sequential maps are matplotlib's, which are designed to be perceptually uniform and to survive colour blindness
+    //and greyscale printing
+    const VIRIDIS:[u32; 11] = [
+        0x440154, 0x482576, 0x414487, 0x35608D, 0x2A788E, 0x21908C, 0x22A884, 0x43BF71, 0x7AD151, 0xBBDF27, 0xFDE725,
+    ];
+    const MAGMA:[u32; 11] = [
+        0x000004, 0x140E37, 0x3B0F70, 0x641A80, 0x8C2981, 0xB63679, 0xDE4968, 0xF76F5C, 0xFE9F6D, 0xFECF92, 0xFCFDBF,
+    ];
+    const CIVIDIS:[u32; 10] = [
+        0x00204D, 0x00336F, 0x39486B, 0x575C6D, 0x707173, 0x8A8779, 0xA69D75, 0xC4B56C, 0xE4CF5B, 0xFFEA46,
+    ];
+    const COOL_WARM:[u32; 3] = [ 0x3B4CC0, 0xDDDDDD, 0xB40426 ];
+
+    const TABLEAU_10:[u32; 10] = [
+        0x4E79A7, 0xF28E2B, 0xE15759, 0x76B7B2, 0x59A14F, 0xEDC948, 0xB07AA1, 0xFF9DA7, 0x9C755F, 0xBAB0AC,
+    ];
+    const OKABE_ITO:[u32; 8] = [ 0x000000, 0xE69F00, 0x56B4E9, 0x009E73, 0xF0E442, 0x0072B2, 0xD55E00, 0xCC79A7 ];
+    const SET_2:[u32; 8] = [ 0x66C2A5, 0xFC8D62, 0x8DA0CB, 0xE78AC3, 0xA6D854, 0xFFD92F, 0xE5C494, 0xB3B3B3 ];
+
+    fn colour_from_hex_value(value:u32) -> Colour {
+        Colour::new_from_rgba8( (value >> 16) as u8, (value >> 8) as u8, value as u8, 255 )
+    }
+
+
+
+
+
+
+
+
+//colour map
+    //a continuous run of colours that values from 0 to 1 are mapped onto; use a sequential map (viridis, magma, cividis)
+    //for values that simply go from low to high, and a diverging one for values either side of a meaningful middle
+    #[derive(Clone)]
+    pub struct ColourMap {
+        control_points: Vec<(f32, f32, f32, f32)>, //OKLab and alpha, evenly spaced from 0 to 1
+    }
+    //new
+        impl ColourMap {
+            pub fn new(colours:&[Colour]) -> ColourMap {
+                if colours.is_empty() {
+                    println!("WARNING - ColourMap::new : no colours were provided; using black");
+                    return ColourMap::new(&[Colour::new(0.0, 0.0, 0.0, 1.0)]);
+                }
+
+                ColourMap {
+                    control_points: colours.iter().map(|colour| {
+                        let (lightness, a, b) = colour.to_oklab();
+                        (lightness, a, b, colour.a())
+                    }).collect(),
+                }
+            }
+            fn new_from_hex_values(values:&[u32]) -> ColourMap {
+                ColourMap::new( &values.iter().map(|value| colour_from_hex_value(*value)).collect::<Vec<Colour>>() )
+            }
+
+            pub fn new_viridis() -> ColourMap { ColourMap::new_from_hex_values(&VIRIDIS) }
+            pub fn new_magma() -> ColourMap { ColourMap::new_from_hex_values(&MAGMA) }
+            pub fn new_cividis() -> ColourMap { ColourMap::new_from_hex_values(&CIVIDIS) }
+
+            //runs from low, through middle (at 0.5), to high
+            pub fn new_diverging(low:Colour, middle:Colour, high:Colour) -> ColourMap {
+                ColourMap::new(&[low, middle, high])
+            }
+            //blue, through grey, to red
+            pub fn new_cool_warm() -> ColourMap { ColourMap::new_from_hex_values(&COOL_WARM) }
+        }
+    //sampling
+        impl ColourMap {
+            //values outside of 0 to 1 are clamped
+            pub fn sample(&self, value:f32) -> Colour {
+                let last = self.control_points.len() - 1;
+                let position = if value.is_nan() { 0.0 } else { value.clamp(0.0, 1.0) } * last as f32;
+                let index = (position.floor() as usize).min(last);
+                let next = (index + 1).min(last);
+                let t = position - index as f32;
+
+                let from = self.control_points[index];
+                let to = self.control_points[next];
+                Colour::new_from_oklab(
+                    from.0 + (to.0 - from.0) * t,
+                    from.1 + (to.1 - from.1) * t,
+                    from.2 + (to.2 - from.2) * t,
+                    from.3 + (to.3 - from.3) * t,
+                )
+            }
+            //maps the value from within minimum to maximum (in either order) before sampling
+            pub fn sample_within(&self, value:f32, minimum:f32, maximum:f32) -> Colour {
+                if minimum == maximum {
+                    return self.sample(0.5);
+                }
+                self.sample( (value - minimum) / (maximum - minimum) )
+            }
+            //count colours spread evenly across the whole map, both ends included
+            pub fn sample_evenly(&self, count:usize) -> Vec<Colour> {
+                match count {
+                    0 => vec![],
+                    1 => vec![self.sample(0.5)],
+                    _ => (0..count).map(|index| self.sample( index as f32 / (count - 1) as f32 )).collect(),
+                }
+            }
+        }
+    //printing
+        impl fmt::Display for ColourMap {
+            fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result {
+                write!(f, "ColourMap{{control_points:{}}}", self.control_points.len())
+            }
+        }
+        impl fmt::Debug for ColourMap {
+            fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { fmt::Display::fmt(self, f) }
+        }
+
+
+
+
+
+
+
+
+//qualitative palette
+    //sets of colours with no order to them, for telling categories apart
+    #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+    pub enum QualitativePalette {
+        Tableau10,
+        OkabeIto, //8 colours, chosen to stay distinct with any kind of colour blindness
+        Set2, //8 softer colours, from ColorBrewer
+    }
+    impl QualitativePalette {
+        pub fn get_colours(&self) -> Vec<Colour> {
+            let values:&[u32] = match self {
+                QualitativePalette::Tableau10 => &TABLEAU_10,
+                QualitativePalette::OkabeIto => &OKABE_ITO,
+                QualitativePalette::Set2 => &SET_2,
+            };
+            values.iter().map(|value| colour_from_hex_value(*value)).collect()
+        }
+        //wraps around once the palette runs out
+        pub fn get_colour(&self, index:usize) -> Colour {
+            let colours = self.get_colours();
+            colours[index % colours.len()]
+        }
+    }
+    impl fmt::Display for QualitativePalette {
+        fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { fmt::Debug::fmt(self, f) }
+    }
+
+
+
+
+
+
+
+
+//distinguishable colours
+    //count colours, each as far from the others as can be managed. The first ten are Tableau10; after that hues step
+    //around OKLCH by the golden angle (so neighbouring indices are never similar), cycling through three lightnesses,
+    //with the chroma pulled in wherever it would fall outside of sRGB. No set of thousands of colours can be told apart
+    //by eye, but this way series next to each other in a list at least look different
+    pub fn distinguishable_colours(count:usize) -> Vec<Colour> {
+        const GOLDEN_ANGLE:f32 = 137.50776;
+        const LIGHTNESSES:[f32; 3] = [0.72, 0.55, 0.86];
+        const CHROMA:f32 = 0.15;
+
+        let mut output:Vec<Colour> = QualitativePalette::Tableau10.get_colours().into_iter().take(count).collect();
+
+        for index in output.len()..count {
+            let step = index - TABLEAU_10.len();
+            let hue = (step as f32 * GOLDEN_ANGLE + 20.0).rem_euclid(360.0);
+            let lightness = LIGHTNESSES[step % LIGHTNESSES.len()];
+            output.push( Colour::new_from_oklch(lightness, largest_chroma_within_gamut(lightness, hue, CHROMA), hue, 1.0) );
+        }
+
+        output
+    }
+
+    //Colour clamps whatever doesn't fit into sRGB (which shifts the hue), so the chroma is shrunk until the colour fits
+    fn largest_chroma_within_gamut(lightness:f32, hue:f32, chroma:f32) -> f32 {
+        let fits = |chroma:f32| {
+            let (a, b) = (chroma * hue.to_radians().cos(), chroma * hue.to_radians().sin());
+            let (fitted_lightness, fitted_a, fitted_b) = Colour::new_from_oklab(lightness, a, b, 1.0).to_oklab();
+            (fitted_lightness - lightness).abs() + (fitted_a - a).abs() + (fitted_b - b).abs() < 0.002
+        };
+
+        if fits(chroma) {
+            return chroma;
+        }
+
+        let mut low = 0.0;
+        let mut high = chroma;
+        for _ in 0..16 {
+            let middle = (low + high) / 2.0;
+            if fits(middle) { low = middle; } else { high = middle; }
+        }
+        low
+    }
\ No newline at end of file
@@ -0,0 +1,47 @@
+use std::fmt;
+
+
+
+
+
+
+
+
+//the point of a piece of text that's put at its position (so the text sits around it accordingly)
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum PrintingModePosition {
+    TopLeft,
+    TopCentre,
+    TopRight,
+    CentreLeft,
+    Centre,
+    CentreRight,
+    BaselineLeft, //on the first line's baseline
+    BaselineCentre,
+    BaselineRight,
+    BottomLeft,
+    BottomCentre,
+    BottomRight,
+}
+impl PrintingModePosition {
+    //how far across the text's width the point is
+    pub fn get_horizontal_fraction(&self) -> f32 {
+        match self {
+            PrintingModePosition::TopLeft | PrintingModePosition::CentreLeft | PrintingModePosition::BaselineLeft | PrintingModePosition::BottomLeft => 0.0,
+            PrintingModePosition::TopCentre | PrintingModePosition::Centre | PrintingModePosition::BaselineCentre | PrintingModePosition::BottomCentre => 0.5,
+            PrintingModePosition::TopRight | PrintingModePosition::CentreRight | PrintingModePosition::BaselineRight | PrintingModePosition::BottomRight => 1.0,
+        }
+    }
+    //picks the point's height from those of the text's top, first baseline and bottom
+    pub fn get_vertical_position(&self, top:f32, baseline:f32, bottom:f32) -> f32 {
+        match self {
+            PrintingModePosition::TopLeft | PrintingModePosition::TopCentre | PrintingModePosition::TopRight => top,
+            PrintingModePosition::CentreLeft | PrintingModePosition::Centre | PrintingModePosition::CentreRight => (top + bottom) / 2.0,
+            PrintingModePosition::BaselineLeft | PrintingModePosition::BaselineCentre | PrintingModePosition::BaselineRight => baseline,
+            PrintingModePosition::BottomLeft | PrintingModePosition::BottomCentre | PrintingModePosition::BottomRight => bottom,
+        }
+    }
+}
+impl fmt::Display for PrintingModePosition {
+    fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { fmt::Debug::fmt(self, f) }
+}
\ No newline at end of file
@@ -0,0 +1,156 @@
+use std::fmt;
+
+use super::geometry::{
+    BoundingBox,
+    Point,
+    PolySide,
+    SimplePolygon,
+};
+
+
+
+
+
+
+
+
+//render decision
+    //where something sits relative to the view (the world area a Viewbox covers), and so whether it needs drawing
+    #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+    pub enum RenderDecision {
+        FullyVisible, //entirely within the view
+        PartiallyVisible, //crossing the edge of the view
+        Culled, //entirely outside the view (or hidden); nothing to draw
+    }
+    impl fmt::Display for RenderDecision {
+        fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { fmt::Debug::fmt(self, f) }
+    }
+    impl RenderDecision {
+        pub fn should_render(&self) -> bool { *self != RenderDecision::Culled }
+    }
+    //deciding
+        impl RenderDecision {
+            fn is_point_within(view:&SimplePolygon, point:&Point) -> bool {
+                view.intersect_with_point(point) != PolySide::Outside
+            }
+
+            //for anything inside the bounding box; PartiallyVisible here only means the box crosses the edge of the
+            //view, so the thing inside it may still turn out to be fully visible, or culled
+            pub fn decide_for_bounding_box(view:&SimplePolygon, bounding_box:&BoundingBox) -> RenderDecision {
+                if !view.get_bounding_box().intersect_with_bounding_box(bounding_box) {
+                    return RenderDecision::Culled;
+                }
+
+                let top_left = bounding_box.get_top_left();
+                let bottom_right = bounding_box.get_bottom_right();
+                let corners = [
+                    *top_left,
+                    Point::new(bottom_right.get_x(), top_left.get_y()),
+                    *bottom_right,
+                    Point::new(top_left.get_x(), bottom_right.get_y()),
+                ];
+                if corners.iter().all(|corner| RenderDecision::is_point_within(view, corner)) {
+                    return RenderDecision::FullyVisible;
+                }
+
+                //the boxes overlap, but a turned view can still miss the box entirely
+                if view.intersect_with_simple_polygon__intersect_only( &SimplePolygon::new_from_bounding_box(*bounding_box, true) ) {
+                    RenderDecision::PartiallyVisible
+                } else {
+                    RenderDecision::Culled
+                }
+            }
+            //the polygon's own bounding box is checked first, as most things can be decided from that alone
+            pub fn decide_for_simple_polygon(view:&SimplePolygon, polygon:&SimplePolygon) -> RenderDecision {
+                match RenderDecision::decide_for_bounding_box(view, polygon.get_bounding_box()) {
+                    RenderDecision::PartiallyVisible => {},
+                    decision => return decision,
+                }
+
+                if polygon.get_points().iter().all(|point| RenderDecision::is_point_within(view, point)) {
+                    RenderDecision::FullyVisible
+                } else if view.intersect_with_simple_polygon__intersect_only(polygon) {
+                    RenderDecision::PartiallyVisible
+                } else {
+                    RenderDecision::Culled
+                }
+            }
+        }
+
+
+
+
+
+
+
+
+//render decision statistics
+    //counts of elements from one pass of decisions
+    #[derive(Copy, Clone, PartialEq, Eq, Default)]
+    pub struct RenderDecisionStatistics {
+        fully_visible: usize,
+        partially_visible: usize,
+        culled: usize, //including those in culled groups and hidden ones
+        hidden: usize,
+        culled_untested: usize, //decided by a group they're in, rather than by themselves
+        culled_groups: usize,
+    }
+    //new
+        impl RenderDecisionStatistics {
+            pub fn new() -> RenderDecisionStatistics {
+                RenderDecisionStatistics::default()
+            }
+        }
+    //getters
+        impl RenderDecisionStatistics {
+            pub fn get_fully_visible(&self) -> usize { self.fully_visible }
+            pub fn get_partially_visible(&self) -> usize { self.partially_visible }
+            pub fn get_culled(&self) -> usize { self.culled }
+            pub fn get_hidden(&self) -> usize { self.hidden }
+            pub fn get_culled_untested(&self) -> usize { self.culled_untested }
+            pub fn get_culled_groups(&self) -> usize { self.culled_groups }
+
+            pub fn get_rendered(&self) -> usize { self.fully_visible + self.partially_visible }
+            pub fn get_total(&self) -> usize { self.fully_visible + self.partially_visible + self.culled }
+        }
+    //recording
+        impl RenderDecisionStatistics {
+            //tested is whether the element was looked at itself, or decided along with the group it's in
+            pub fn record_element(&mut self, decision:RenderDecision, tested:bool) {
+                match decision {
+                    RenderDecision::FullyVisible => self.fully_visible += 1,
+                    RenderDecision::PartiallyVisible => self.partially_visible += 1,
+                    RenderDecision::Culled => {
+                        self.culled += 1;
+                        if !tested { self.culled_untested += 1; }
+                    },
+                }
+            }
+            pub fn record_hidden_element(&mut self) {
+                self.culled += 1;
+                self.hidden += 1;
+            }
+            pub fn record_culled_group(&mut self) {
+                self.culled_groups += 1;
+            }
+        }
+    //printing
+        impl RenderDecisionStatistics {
+            fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result {
+                write!(
+                    f, "{{fully_visible:{}, partially_visible:{}, culled:{} (hidden:{}, untested:{}), culled_groups:{}}}",
+                    self.fully_visible,
+                    self.partially_visible,
+                    self.culled,
+                    self.hidden,
+                    self.culled_untested,
+                    self.culled_groups,
+                )
+            }
+        }
+        impl fmt::Display for RenderDecisionStatistics {
+            fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { self.fmt(f) }
+        }
+        impl fmt::Debug for RenderDecisionStatistics {
+            fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { self.fmt(f) }
+        }
\ No newline at end of file
@@ -3,7 +3,10 @@ use std::fmt;
 use super::super::data_type::{
     Offset,
     BoundingBox,
+    ComplexPolygon,
+    Point,
     SimplePolygon,
+    ViewboxConstraints,
 };
 use super::super::math::cartesian_angle_adjust;
 
@@ -28,10 +31,17 @@ pub struct Viewbox {
     static_polygon: SimplePolygon,
 
     cached_offset: Offset,
+
+    constraints: ViewboxConstraints,
+
+    //the width and height are logical pixels; the screen (window events, the surface) counts device pixels
+    device_pixel_ratio: f32,
+    cached_world_to_screen_offset: Offset,
+    cached_screen_to_world_offset: Offset,
 }
 impl Viewbox {
     pub fn new(x:f32, y:f32, scale:f32, angle:f32, anchor_x:f32, anchor_y:f32, width:u32, height:u32) -> Viewbox {
-        Viewbox { 
+        let mut viewbox = Viewbox { 
             x, 
             y, 
             scale, 
@@ -70,7 +80,13 @@ impl Viewbox {
                 width, 
                 height,
             ),
-        }
+            constraints: ViewboxConstraints::new_default(),
+            device_pixel_ratio: 1.0,
+            cached_world_to_screen_offset: Offset::new_default(),
+            cached_screen_to_world_offset: Offset::new_default(),
+        };
+        viewbox.update_conversion_offsets();
+        viewbox
     }
     pub fn new_default() -> Viewbox {
         Viewbox::new(
@@ -86,40 +102,19 @@ impl Viewbox {
     }
 
     fn generate_viewbox(x:f32, y:f32, scale:f32, angle:f32, anchor_x:f32, anchor_y:f32, width:u32, height:u32) -> SimplePolygon {
-        let frame = 0.0/scale;
-
         let width = (width as f32)/scale;
         let height = (height as f32)/scale;
 
-        let angle = -angle;
-
-        let top_left_point = cartesian_angle_adjust(
-            -width*anchor_x + frame,     
-            -height*anchor_y + frame,
-            angle
-        );
-        let top_right_point = cartesian_angle_adjust(
-            width*(1.0-anchor_x) - frame,
-            -height*anchor_y + frame,
-            angle
-        );
-        let bottom_right_point = cartesian_angle_adjust(
-            width*(1.0-anchor_x) - frame,
-            height*(1.0-anchor_y) - frame, 
-            angle
-        );
-        let bottom_left_point = cartesian_angle_adjust(
-            -width*anchor_x + frame,     
-            height*(1.0-anchor_y) - frame, 
-            angle
-        );
-
-        SimplePolygon::new_from_flat_array(vec![
-            x + top_left_point.get_x(),     y + top_left_point.get_y(),
-            x + top_right_point.get_x(),    y + top_right_point.get_y(),
-            x + bottom_right_point.get_x(), y + bottom_right_point.get_y(),
-            x + bottom_left_point.get_x(),  y + bottom_left_point.get_y(),
-        ])
+        //lay the frame out around the anchor, spin it around the anchor, then move the anchor into place
+        let mut polygon = SimplePolygon::new_from_flat_array(vec![
+            -width*anchor_x,       -height*anchor_y,
+            width*(1.0-anchor_x),  -height*anchor_y,
+            width*(1.0-anchor_x),  height*(1.0-anchor_y),
+            -width*anchor_x,       height*(1.0-anchor_y),
+        ]);
+        polygon.rotate(-angle, &Point::new(0.0, 0.0));
+        polygon.translate(x, y);
+        polygon
     }
     fn generate_offset(x:f32, y:f32, scale:f32, angle:f32, anchor_x:f32, anchor_y:f32, width:u32, height:u32) -> Offset {
         let p = cartesian_angle_adjust(-x*scale, -y*scale, angle);
@@ -140,6 +135,8 @@ impl Viewbox {
         // )
     }
     fn update(&mut self) {
+        self.constrain();
+
         self.polygon = Viewbox::generate_viewbox(
             self.x, 
             self.y, 
@@ -170,6 +167,11 @@ impl Viewbox {
             self.width, 
             self.height
         );
+        self.update_conversion_offsets();
+    }
+    fn update_conversion_offsets(&mut self) {
+        self.cached_world_to_screen_offset = Offset::combine( &self.cached_offset, &Offset::new(0.0, 0.0, self.device_pixel_ratio, 0.0) );
+        self.cached_screen_to_world_offset = self.cached_world_to_screen_offset.get_inverse();
     }
 
     pub fn get_x(&self) -> f32 { self.x }
@@ -189,6 +191,9 @@ impl Viewbox {
     pub fn get_height(&self) -> u32 { self.height }
     pub fn set_height(&mut self, new:u32) { self.height = new; self.update(); }
 
+    pub fn get_constraints(&self) -> &ViewboxConstraints { &self.constraints }
+    pub fn set_constraints(&mut self, new:ViewboxConstraints) { self.constraints = new; self.update(); }
+
     pub fn get_offset(&self) -> &Offset {
         &self.cached_offset
     }
@@ -202,6 +207,135 @@ impl Viewbox {
         &self.static_polygon
     }
 }
+impl Viewbox {
+    pub fn clone(&self) -> Viewbox {
+        let mut output = Viewbox::new(self.x, self.y, self.scale, self.angle, self.anchor_x, self.anchor_y, self.width, self.height);
+        output.constraints = self.constraints.clone();
+        output.device_pixel_ratio = self.device_pixel_ratio;
+        output.update_conversion_offsets();
+        output
+    }
+}
+//constraints
+impl Viewbox {
+    //the scale and angle are settled first, as the area the view covers (which the bounds are checked against) depends on them
+    fn constrain(&mut self) {
+        if self.constraints.is_unconstrained() {
+            return;
+        }
+
+        self.scale = self.constraints.constrain_scale(self.scale);
+        self.angle = self.constraints.constrain_angle(self.angle);
+
+        if self.constraints.get_bounds().is_some() {
+            let view = Viewbox::generate_viewbox(self.x, self.y, self.scale, self.angle, self.anchor_x, self.anchor_y, self.width, self.height);
+            let (x, y) = self.constraints.get_bounds_correction(view.get_bounding_box());
+            self.x += x;
+            self.y += y;
+        }
+    }
+}
+//conversion
+//screen values are in device pixels, which is what winit reports the cursor in; on a HiDPI display there are several to
+//every logical pixel of the viewbox's width and height. Keep the ratio up to date with the window's scale factor
+impl Viewbox {
+    pub fn get_device_pixel_ratio(&self) -> f32 { self.device_pixel_ratio }
+    pub fn set_device_pixel_ratio(&mut self, new:f32) {
+        if new.is_nan() || new <= 0.0 {
+            println!("WARNING - Viewbox::set_device_pixel_ratio : the ratio must be above 0 (was given {}); left as {}", new, self.device_pixel_ratio);
+            return;
+        }
+        self.device_pixel_ratio = new;
+        self.update_conversion_offsets();
+    }
+
+    pub fn get_world_to_screen_offset(&self) -> &Offset { &self.cached_world_to_screen_offset }
+    pub fn get_screen_to_world_offset(&self) -> &Offset { &self.cached_screen_to_world_offset }
+
+    pub fn convert_point_to_world(&self, point:&Point) -> Point {
+        point.get_with_offset_applied(&self.cached_screen_to_world_offset)
+    }
+    pub fn convert_point_to_screen(&self, point:&Point) -> Point {
+        point.get_with_offset_applied(&self.cached_world_to_screen_offset)
+    }
+    //when the view is at an angle, these give the box around the converted corners
+    pub fn convert_bounding_box_to_world(&self, bounding_box:&BoundingBox) -> BoundingBox {
+        bounding_box.get_with_offset_applied(&self.cached_screen_to_world_offset)
+    }
+    pub fn convert_bounding_box_to_screen(&self, bounding_box:&BoundingBox) -> BoundingBox {
+        bounding_box.get_with_offset_applied(&self.cached_world_to_screen_offset)
+    }
+    pub fn convert_simple_polygon_to_world(&self, polygon:&SimplePolygon) -> SimplePolygon {
+        polygon.get_with_offset_applied(&self.cached_screen_to_world_offset)
+    }
+    pub fn convert_simple_polygon_to_screen(&self, polygon:&SimplePolygon) -> SimplePolygon {
+        polygon.get_with_offset_applied(&self.cached_world_to_screen_offset)
+    }
+    pub fn convert_complex_polygon_to_world(&self, polygon:&ComplexPolygon) -> ComplexPolygon {
+        polygon.get_with_offset_applied(&self.cached_screen_to_world_offset)
+    }
+    pub fn convert_complex_polygon_to_screen(&self, polygon:&ComplexPolygon) -> ComplexPolygon {
+        polygon.get_with_offset_applied(&self.cached_world_to_screen_offset)
+    }
+}
+//framing
+//padding is in screen pixels, left clear on every side
+impl Viewbox {
+    //the x and y that put the world point at the middle of the view, given the view's scale and angle
+    fn get_position_centred_on(&self, point:&Point) -> (f32, f32) {
+        let offset = cartesian_angle_adjust(
+            (self.anchor_x - 0.5) * self.width as f32 / self.scale,
+            (self.anchor_y - 0.5) * self.height as f32 / self.scale,
+            -self.angle,
+        );
+        (point.get_x() + offset.get_x(), point.get_y() + offset.get_y())
+    }
+
+    pub fn centre_on_point(&mut self, point:&Point) {
+        let (x, y) = self.get_position_centred_on(point);
+        self.x = x;
+        self.y = y;
+        self.update();
+    }
+    //keeps the angle, and picks the largest scale at which all of the bounding box fits (if the constraints allow it)
+    pub fn fit_bounding_box(&mut self, bounding_box:&BoundingBox, padding:f32) {
+        let centre = Point::new(
+            (bounding_box.get_top_left().get_x() + bounding_box.get_bottom_right().get_x()) / 2.0,
+            (bounding_box.get_top_left().get_y() + bounding_box.get_bottom_right().get_y()) / 2.0,
+        );
+
+        //the box's extent as it will appear on screen, at a scale of 1
+        let turned = bounding_box.get_rotated(self.angle, &centre);
+        let width = turned.get_bottom_right().get_x() - turned.get_top_left().get_x();
+        let height = turned.get_bottom_right().get_y() - turned.get_top_left().get_y();
+
+        let available_width = (self.width as f32 - padding * 2.0).max(1.0);
+        let available_height = (self.height as f32 - padding * 2.0).max(1.0);
+        let scale = match (width > 0.0, height > 0.0) {
+            (true, true) => (available_width / width).min(available_height / height),
+            (true, false) => available_width / width,
+            (false, true) => available_height / height,
+            (false, false) => self.scale, //a single point; just centre on it
+        };
+
+        self.scale = self.constraints.constrain_scale(scale);
+        let (x, y) = self.get_position_centred_on(&centre);
+        self.x = x;
+        self.y = y;
+        self.update();
+    }
+
+    pub fn get_centred_on_point(&self, point:&Point) -> Viewbox {
+        let mut output = self.clone();
+        output.centre_on_point(point);
+        output
+    }
+    pub fn get_fitted_to_bounding_box(&self, bounding_box:&BoundingBox, padding:f32) -> Viewbox {
+        let mut output = self.clone();
+        output.fit_bounding_box(bounding_box, padding);
+        output
+    }
+}
 impl Viewbox {
     fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result {
         write!(
@@ -0,0 +1,144 @@
+use std::fmt;
+
+use super::geometry::BoundingBox;
+
+
+
+
+
+
+
+
+//limits that a Viewbox holds itself to whenever it changes; every part is optional, and new_default has none of them
+pub struct ViewboxConstraints {
+    minimum_scale: Option<f32>,
+    maximum_scale: Option<f32>,
+    bounds: Option<BoundingBox>, //the world area the view is kept within (or centred on, where the view is bigger)
+    angle_snap_increment: Option<f32>, //radians; angles close to a multiple of this are pulled onto it
+    angle_snap_threshold: f32, //radians; how close counts as close
+}
+impl ViewboxConstraints {
+    pub fn new(minimum_scale:Option<f32>, maximum_scale:Option<f32>, bounds:Option<BoundingBox>, angle_snap_increment:Option<f32>, angle_snap_threshold:f32) -> ViewboxConstraints {
+        let mut constraints = ViewboxConstraints::new_default();
+        constraints.set_scale_limits(minimum_scale, maximum_scale);
+        constraints.set_bounds(bounds);
+        constraints.set_angle_snap(angle_snap_increment, angle_snap_threshold);
+        constraints
+    }
+    pub fn new_default() -> ViewboxConstraints {
+        ViewboxConstraints {
+            minimum_scale: None,
+            maximum_scale: None,
+            bounds: None,
+            angle_snap_increment: None,
+            angle_snap_threshold: 0.0,
+        }
+    }
+    pub fn clone(&self) -> ViewboxConstraints {
+        ViewboxConstraints {
+            minimum_scale: self.minimum_scale,
+            maximum_scale: self.maximum_scale,
+            bounds: self.bounds,
+            angle_snap_increment: self.angle_snap_increment,
+            angle_snap_threshold: self.angle_snap_threshold,
+        }
+    }
+}
+impl ViewboxConstraints {
+    pub fn get_minimum_scale(&self) -> Option<f32> { self.minimum_scale }
+    pub fn get_maximum_scale(&self) -> Option<f32> { self.maximum_scale }
+    pub fn get_bounds(&self) -> Option<&BoundingBox> { self.bounds.as_ref() }
+    pub fn get_angle_snap_increment(&self) -> Option<f32> { self.angle_snap_increment }
+    pub fn get_angle_snap_threshold(&self) -> f32 { self.angle_snap_threshold }
+
+    pub fn set_scale_limits(&mut self, minimum:Option<f32>, maximum:Option<f32>) {
+        let minimum = match minimum {
+            Some(minimum) if minimum <= 0.0 => {
+                println!("WARNING - ViewboxConstraints::set_scale_limits : the minimum scale must be above 0 (was given {}); ignoring it", minimum);
+                None
+            },
+            _ => minimum,
+        };
+        if let (Some(minimum), Some(maximum)) = (minimum, maximum) {
+            if minimum > maximum {
+                println!("WARNING - ViewboxConstraints::set_scale_limits : the minimum scale ({}) is above the maximum ({}); swapping them", minimum, maximum);
+                self.minimum_scale = Some(maximum);
+                self.maximum_scale = Some(minimum);
+                return;
+            }
+        }
+        self.minimum_scale = minimum;
+        self.maximum_scale = maximum;
+    }
+    pub fn set_bounds(&mut self, new:Option<BoundingBox>) { self.bounds = new; }
+    pub fn set_angle_snap(&mut self, increment:Option<f32>, threshold:f32) {
+        self.angle_snap_increment = match increment {
+            Some(increment) if increment <= 0.0 => {
+                println!("WARNING - ViewboxConstraints::set_angle_snap : the increment must be above 0 (was given {}); snapping turned off", increment);
+                None
+            },
+            _ => increment,
+        };
+        self.angle_snap_threshold = threshold.abs();
+    }
+
+    pub fn is_unconstrained(&self) -> bool {
+        self.minimum_scale.is_none() && self.maximum_scale.is_none() && self.bounds.is_none() && self.angle_snap_increment.is_none()
+    }
+}
+impl ViewboxConstraints {
+    pub fn constrain_scale(&self, scale:f32) -> f32 {
+        let scale = match self.minimum_scale { Some(minimum) if scale < minimum => minimum, _ => scale };
+        match self.maximum_scale { Some(maximum) if scale > maximum => maximum, _ => scale }
+    }
+    pub fn constrain_angle(&self, angle:f32) -> f32 {
+        let increment = match self.angle_snap_increment {
+            Some(increment) => increment,
+            None => return angle,
+        };
+        let nearest = (angle / increment).round() * increment;
+        if (angle - nearest).abs() <= self.angle_snap_threshold { nearest } else { angle }
+    }
+    //how far the view (given as the world area it covers) needs moving to be within the bounds
+    pub fn get_bounds_correction(&self, view:&BoundingBox) -> (f32, f32) {
+        let bounds = match &self.bounds {
+            Some(bounds) => bounds,
+            None => return (0.0, 0.0),
+        };
+
+        let correct = |view_start:f32, view_end:f32, bounds_start:f32, bounds_end:f32| {
+            if view_end - view_start > bounds_end - bounds_start {
+                (bounds_start + bounds_end) / 2.0 - (view_start + view_end) / 2.0
+            } else if view_start < bounds_start {
+                bounds_start - view_start
+            } else if view_end > bounds_end {
+                bounds_end - view_end
+            } else {
+                0.0
+            }
+        };
+
+        (
+            correct(view.get_top_left().get_x(), view.get_bottom_right().get_x(), bounds.get_top_left().get_x(), bounds.get_bottom_right().get_x()),
+            correct(view.get_top_left().get_y(), view.get_bottom_right().get_y(), bounds.get_top_left().get_y(), bounds.get_bottom_right().get_y()),
+        )
+    }
+}
+impl ViewboxConstraints {
+    fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f, "{{minimum_scale:{:?}, maximum_scale:{:?}, bounds:{:?}, angle_snap_increment:{:?}, angle_snap_threshold:{}}}",
+            self.minimum_scale,
+            self.maximum_scale,
+            self.bounds,
+            self.angle_snap_increment,
+            self.angle_snap_threshold,
+        )
+    }
+}
+impl fmt::Display for ViewboxConstraints {
+    fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { self.fmt(f) }
+}
+impl fmt::Debug for ViewboxConstraints {
+    fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { self.fmt(f) }
+}
\ No newline at end of file
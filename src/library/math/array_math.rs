@@ -1,3 +1,10 @@
+use std::collections::{
+    HashMap,
+    HashSet,
+    VecDeque,
+};
+use std::hash::Hash;
+
 pub fn array_difference<T:PartialEq + Copy>(a_array:&Vec<T>, b_array:&Vec<T>) -> (Vec<T>, Vec<T>) {
     let mut a_output:Vec<T> = vec![];
     let mut b_output:Vec<T> = vec![];
@@ -30,4 +37,127 @@ pub fn array_intersect<T:PartialEq + Copy>(a:&Vec<T>, b:&Vec<T>) -> Vec<T> {
     }
 
     output
-}
\ No newline at end of file
+}
+
+pub fn array_difference_hashed<T:Hash + Eq + Copy>(a_array:&[T], b_array:&[T]) -> (Vec<T>, Vec<T>) {
+    let a_set:HashSet<&T> = a_array.iter().collect();
+    let b_set:HashSet<&T> = b_array.iter().collect();
+
+    (
+        a_array.iter().filter(|a_item| !b_set.contains(a_item)).cloned().collect(),
+        b_array.iter().filter(|b_item| !a_set.contains(b_item)).cloned().collect(),
+    )
+}
+
+pub fn array_intersect_hashed<T:Hash + Eq + Copy>(a:&[T], b:&[T]) -> Vec<T> {
+    let b_set:HashSet<&T> = b.iter().collect();
+    a.iter().filter(|a_item| b_set.contains(a_item)).cloned().collect()
+}
+
+
+
+//ordered diff
+    //old_index refers to the position in the old array, new_index to the position in the new array
+    #[derive(Copy, Clone, PartialEq, Debug)]
+    pub enum ArrayEdit<T> {
+        Insert { new_index:usize, item:T },
+        Delete { old_index:usize, item:T },
+        Move { old_index:usize, new_index:usize, item:T },
+    }
+
+    //the edits that turn the old array into the new one, touching as few items as possible; items that keep their order
+    //relative to one another (the longest increasing run of old positions, taken in new order) are left where they are
+    //repeated items are matched up in the order they appear. Runs in O(n log n)
+    //edits come out as deletions (by old_index), then moves and insertions (by new_index)
+    pub fn array_ordered_diff<T:Hash + Eq + Copy>(old:&[T], new:&[T]) -> Vec<ArrayEdit<T>> {
+        //pair each item in the new array with the matching item in the old array, if there is one
+            let mut old_positions:HashMap<&T, VecDeque<usize>> = HashMap::new();
+            for (index, item) in old.iter().enumerate() {
+                old_positions.entry(item).or_default().push_back(index);
+            }
+            let matches:Vec<Option<usize>> = new.iter().map(|item| {
+                old_positions.get_mut(item).and_then(|positions| positions.pop_front())
+            }).collect();
+
+        //the matched items that can stay put
+            let matched_old_indices:Vec<usize> = matches.iter().filter_map(|old_index| *old_index).collect();
+            let staying:HashSet<usize> = longest_increasing_subsequence(&matched_old_indices).into_iter().collect();
+
+        let mut matched:Vec<bool> = vec![false; old.len()];
+        matches.iter().filter_map(|old_index| *old_index).for_each(|old_index| matched[old_index] = true);
+
+        let mut output:Vec<ArrayEdit<T>> = vec![];
+        for (old_index, item) in old.iter().enumerate() {
+            if !matched[old_index] {
+                output.push(ArrayEdit::Delete { old_index, item:*item });
+            }
+        }
+        for (new_index, item) in new.iter().enumerate() {
+            match matches[new_index] {
+                None => output.push(ArrayEdit::Insert { new_index, item:*item }),
+                Some(old_index) if !staying.contains(&old_index) => output.push(ArrayEdit::Move { old_index, new_index, item:*item }),
+                Some(_) => {},
+            }
+        }
+        output
+    }
+
+    //applies the edits from array_ordered_diff; everything deleted or moved is taken out first, and then everything
+    //inserted or moved is put back in at its new_index, in order
+    pub fn array_apply_ordered_diff<T:Copy>(old:&[T], edits:&[ArrayEdit<T>]) -> Vec<T> {
+        let mut removed:HashSet<usize> = HashSet::new();
+        let mut placed:Vec<(usize, T)> = vec![];
+        for edit in edits {
+            match *edit {
+                ArrayEdit::Delete { old_index, .. } => { removed.insert(old_index); },
+                ArrayEdit::Insert { new_index, item } => { placed.push((new_index, item)); },
+                ArrayEdit::Move { old_index, new_index, item } => {
+                    removed.insert(old_index);
+                    placed.push((new_index, item));
+                },
+            }
+        }
+        placed.sort_by_key(|(new_index, _)| *new_index);
+
+        let mut kept = old.iter().enumerate().filter(|(index, _)| !removed.contains(index)).map(|(_, item)| *item);
+        let mut placed = placed.into_iter().peekable();
+        let mut output:Vec<T> = Vec::with_capacity(old.len() - removed.len() + placed.len());
+        loop {
+            match placed.peek() {
+                Some((new_index, _)) if *new_index == output.len() => { output.push(placed.next().unwrap().1); },
+                _ => match kept.next() {
+                    Some(item) => output.push(item),
+                    None => match placed.next() {
+                        Some((_, item)) => output.push(item),
+                        None => break,
+                    },
+                },
+            }
+        }
+        output
+    }
+
+    //patience sorting; returns the values (not positions) making up one of the longest strictly increasing subsequences
+    fn longest_increasing_subsequence(values:&[usize]) -> Vec<usize> {
+        let mut tails:Vec<usize> = vec![]; //index (into values) of the smallest tail of each length of run found so far
+        let mut previous:Vec<Option<usize>> = vec![None; values.len()];
+
+        for (index, value) in values.iter().enumerate() {
+            let length = tails.partition_point(|&tail| values[tail] < *value);
+            previous[index] = if length > 0 { Some(tails[length - 1]) } else { None };
+            if length == tails.len() {
+                tails.push(index);
+            } else {
+                tails[length] = index;
+            }
+        }
+
+        let mut output:Vec<usize> = vec![];
+        let mut current = tails.last().cloned();
+        while let Some(index) = current {
+            output.push(values[index]);
+            current = previous[index];
+        }
+        output.reverse();
+        output
+    }
\ No newline at end of file
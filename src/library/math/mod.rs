@@ -1,7 +1,14 @@
 pub mod array_math;
+pub mod triangulation;
 
 mod cartesian_angle_adjust;
 pub use cartesian_angle_adjust::{*};
 
+mod parametric_line_clip;
+pub use parametric_line_clip::{*};
+
+mod parametric_line_intersect;
+pub use parametric_line_intersect::{*};
+
 // mod path_extrapolation;
 // pub use path_extrapolation::{*};
\ No newline at end of file
@@ -0,0 +1,44 @@
+use super::super::data_type::{
+    BoundingBox,
+    Point,
+};
+
+//Liang-Barsky clipping of the line running through point_a (t = 0) and point_b (t = 1), restricted to the parameter
+//range t_min..t_max (use infinities for unbounded lines). Returns the parameter range that lies within the bounding box
+pub fn parametric_line_clip(point_a:&Point, point_b:&Point, t_min:f32, t_max:f32, bounding_box:&BoundingBox) -> Option<(f32,f32)> {
+    let delta_x = point_b.get_x() - point_a.get_x();
+    let delta_y = point_b.get_y() - point_a.get_y();
+
+    let mut t_min = t_min;
+    let mut t_max = t_max;
+
+    let checks = [
+        (-delta_x, point_a.get_x() - bounding_box.get_top_left().get_x()),
+        ( delta_x, bounding_box.get_bottom_right().get_x() - point_a.get_x()),
+        (-delta_y, point_a.get_y() - bounding_box.get_top_left().get_y()),
+        ( delta_y, bounding_box.get_bottom_right().get_y() - point_a.get_y()),
+    ];
+
+    for (p, q) in checks.iter() {
+        if *p == 0.0 {
+            //parallel to this edge; either wholly inside its limit or wholly outside
+            if *q < 0.0 { return None; }
+        } else {
+            let t = q / p;
+            if *p < 0.0 {
+                if t > t_max { return None; }
+                if t > t_min { t_min = t; }
+            } else {
+                if t < t_min { return None; }
+                if t < t_max { t_max = t; }
+            }
+        }
+    }
+
+    if t_min.is_infinite() || t_max.is_infinite() {
+        //a degenerate line (point_a == point_b) has no direction to clip along
+        return None;
+    }
+
+    Some((t_min, t_max))
+}
\ No newline at end of file
@@ -0,0 +1,23 @@
+use super::super::data_type::Point;
+
+//for the two lines running through a_point_a (t = 0) and a_point_b (t = 1), and b_point_a (u = 0) and b_point_b (u = 1)
+//find the parameters (t,u) at which they cross. Parallel lines don't cross, so return None
+pub fn parametric_line_intersect(a_point_a:&Point, a_point_b:&Point, b_point_a:&Point, b_point_b:&Point) -> Option<(f32,f32)> {
+    let a_delta_x = a_point_b.get_x() - a_point_a.get_x();
+    let a_delta_y = a_point_b.get_y() - a_point_a.get_y();
+    let b_delta_x = b_point_b.get_x() - b_point_a.get_x();
+    let b_delta_y = b_point_b.get_y() - b_point_a.get_y();
+
+    let denominator = a_delta_x * b_delta_y - a_delta_y * b_delta_x;
+    if denominator == 0.0 {
+        return None;
+    }
+
+    let between_x = b_point_a.get_x() - a_point_a.get_x();
+    let between_y = b_point_a.get_y() - a_point_a.get_y();
+
+    Some((
+        (between_x * b_delta_y - between_y * b_delta_x) / denominator,
+        (between_x * a_delta_y - between_y * a_delta_x) / denominator,
+    ))
+}
\ No newline at end of file
@@ -0,0 +1,997 @@
+use std::cmp::Ordering;
+use std::collections::{
+    BinaryHeap,
+    HashMap,
+    HashSet,
+    VecDeque,
+};
+
+use super::super::data_type::{
+    BoundingBox,
+    Point,
+    SimplePolygon,
+};
+
+
+
+
+
+
+
+
+//refinement
+    //limits that triangles produced by constrained_delaunay should meet; Steiner points are added until they do, or until
+    //maximum_steiner_points have been added (some inputs, such as very sharp corners, can never fully satisfy a minimum angle)
+    pub struct TriangulationRefinement {
+        maximum_area: Option<f32>,
+        minimum_angle: Option<f32>, //radians
+        maximum_steiner_points: usize,
+    }
+    impl TriangulationRefinement {
+        pub fn new(maximum_area:Option<f32>, minimum_angle:Option<f32>, maximum_steiner_points:usize) -> TriangulationRefinement {
+            TriangulationRefinement {
+                maximum_area,
+                minimum_angle,
+                maximum_steiner_points,
+            }
+        }
+        pub fn new_default() -> TriangulationRefinement {
+            TriangulationRefinement::new(None, None, 0)
+        }
+    }
+    impl TriangulationRefinement {
+        pub fn get_maximum_area(&self) -> Option<f32> { self.maximum_area }
+        pub fn get_minimum_angle(&self) -> Option<f32> { self.minimum_angle }
+        pub fn get_maximum_steiner_points(&self) -> usize { self.maximum_steiner_points }
+        fn is_active(&self) -> bool {
+            self.maximum_steiner_points > 0 && (self.maximum_area.is_some() || self.minimum_angle.is_some())
+        }
+    }
+
+
+
+
+
+
+
+
+//utilities
+    type Coordinate = (f64, f64);
+
+    //positive when a, b and c wind clockwise on screen (where y increases downwards)
+    fn orientation(a:Coordinate, b:Coordinate, c:Coordinate) -> f64 {
+        (b.0 - a.0) * (c.1 - a.1) - (b.1 - a.1) * (c.0 - a.0)
+    }
+    //positive when d is within the circumcircle of a, b and c (which must have a positive orientation)
+    fn in_circle(a:Coordinate, b:Coordinate, c:Coordinate, d:Coordinate) -> f64 {
+        let (adx, ady) = (a.0 - d.0, a.1 - d.1);
+        let (bdx, bdy) = (b.0 - d.0, b.1 - d.1);
+        let (cdx, cdy) = (c.0 - d.0, c.1 - d.1);
+        (adx * adx + ady * ady) * (bdx * cdy - cdx * bdy) -
+        (bdx * bdx + bdy * bdy) * (adx * cdy - cdx * ady) +
+        (cdx * cdx + cdy * cdy) * (adx * bdy - bdx * ady)
+    }
+    fn segments_cross(a:Coordinate, b:Coordinate, c:Coordinate, d:Coordinate) -> bool {
+        let o1 = orientation(a, b, c);
+        let o2 = orientation(a, b, d);
+        let o3 = orientation(c, d, a);
+        let o4 = orientation(c, d, b);
+        ((o1 > 0.0 && o2 < 0.0) || (o1 < 0.0 && o2 > 0.0)) && ((o3 > 0.0 && o4 < 0.0) || (o3 < 0.0 && o4 > 0.0))
+    }
+    fn circumcentre(a:Coordinate, b:Coordinate, c:Coordinate) -> Option<Coordinate> {
+        let d = 2.0 * (a.0 * (b.1 - c.1) + b.0 * (c.1 - a.1) + c.0 * (a.1 - b.1));
+        if d == 0.0 {
+            return None;
+        }
+        let a_squared = a.0 * a.0 + a.1 * a.1;
+        let b_squared = b.0 * b.0 + b.1 * b.1;
+        let c_squared = c.0 * c.0 + c.1 * c.1;
+        Some((
+            (a_squared * (b.1 - c.1) + b_squared * (c.1 - a.1) + c_squared * (a.1 - b.1)) / d,
+            (a_squared * (c.0 - b.0) + b_squared * (a.0 - c.0) + c_squared * (b.0 - a.0)) / d,
+        ))
+    }
+    fn distance_squared(a:Coordinate, b:Coordinate) -> f64 {
+        (a.0 - b.0) * (a.0 - b.0) + (a.1 - b.1) * (a.1 - b.1)
+    }
+    fn angle_at(vertex:Coordinate, a:Coordinate, b:Coordinate) -> f64 {
+        let (ax, ay) = (a.0 - vertex.0, a.1 - vertex.1);
+        let (bx, by) = (b.0 - vertex.0, b.1 - vertex.1);
+        (ax * by - ay * bx).abs().atan2(ax * bx + ay * by)
+    }
+    fn undirected(a:usize, b:usize) -> (usize, usize) {
+        if a < b { (a, b) } else { (b, a) }
+    }
+    fn to_coordinate(point:&Point) -> Coordinate {
+        (point.get_x() as f64, point.get_y() as f64)
+    }
+    fn to_point(coordinate:Coordinate) -> Point {
+        Point::new(coordinate.0 as f32, coordinate.1 as f32)
+    }
+    //the index of the point in the vector, adding it if it isn't already there (points within Point's comparison tolerance are the same point)
+    fn find_or_add_point(points:&mut Vec<Point>, point:Point) -> usize {
+        match points.iter().position(|existing| *existing == point) {
+            Some(index) => index,
+            None => {
+                points.push(point);
+                points.len() - 1
+            },
+        }
+    }
+
+
+
+
+
+
+
+
+//mesh
+    //the three "super" points, forming a triangle around all the others, come first in a mesh's coordinates
+    const SUPER_POINT_COUNT:usize = 3;
+
+    //where a point falls within the mesh
+    #[derive(Copy, Clone)]
+    enum Location {
+        Triangle(usize),
+        Edge(usize, usize, usize), //on the edge a-b of the triangle
+        Vertex(usize, usize), //on a point of the triangle
+        Constraint(usize, usize), //the walk there was stopped by this constraint edge
+        Outside,
+    }
+    //what lies along the way of a constraint edge that's yet to be inserted
+    enum ConstraintPath {
+        Crossing(Vec<(usize,usize)>), //the edges that cross it, in order from its start
+        Through(usize), //it runs straight through another point
+    }
+
+    //triangles are stored with a positive orientation, and every directed edge maps to the triangle it belongs to.
+    //Triangles are only ever split or replaced where they are, so their indices stay put and none go to waste
+    struct Mesh {
+        coordinates: Vec<Coordinate>,
+        triangles: Vec<[usize;3]>,
+        inside: Vec<bool>, //whether each triangle is within the polygon, for constrained meshes
+        edges: HashMap<(usize,usize), usize>,
+        constraints: HashSet<(usize,usize)>, //undirected
+        last_triangle: usize, //where walks start from
+        changed: Vec<usize>, //the triangles made or replaced, since this was last emptied
+    }
+    impl Mesh {
+        fn new(points:&[Point]) -> Mesh {
+            let bounding_box = BoundingBox::new_from_points(points);
+            let (left, top) = to_coordinate(bounding_box.get_top_left());
+            let (right, bottom) = to_coordinate(bounding_box.get_bottom_right());
+            let size = (right - left).max(bottom - top).max(1.0) * 1000.0;
+            let (centre_x, centre_y) = ((left + right) / 2.0, (top + bottom) / 2.0);
+
+            let mut coordinates:Vec<Coordinate> = vec![
+                (centre_x - size, centre_y - size),
+                (centre_x + size, centre_y - size),
+                (centre_x, centre_y + size),
+            ];
+            coordinates.extend(points.iter().map(to_coordinate));
+
+            let mut mesh = Mesh {
+                coordinates,
+                triangles: vec![],
+                inside: vec![],
+                edges: HashMap::new(),
+                constraints: HashSet::new(),
+                last_triangle: 0,
+                changed: vec![],
+            };
+            mesh.add_triangle(0, 1, 2, false);
+            mesh
+        }
+        fn is_real(index:usize) -> bool {
+            index >= SUPER_POINT_COUNT
+        }
+
+        fn add_triangle(&mut self, a:usize, b:usize, c:usize, inside:bool) -> usize {
+            self.triangles.push([a, b, c]);
+            self.inside.push(inside);
+            let index = self.triangles.len() - 1;
+            self.set_triangle(index, a, b, c);
+            index
+        }
+        fn set_triangle(&mut self, index:usize, a:usize, b:usize, c:usize) {
+            let old = self.triangles[index];
+            for edge in &[(old[0], old[1]), (old[1], old[2]), (old[2], old[0])] {
+                if self.edges.get(edge) == Some(&index) {
+                    self.edges.remove(edge);
+                }
+            }
+
+            let (a, b, c) = if orientation(self.coordinates[a], self.coordinates[b], self.coordinates[c]) < 0.0 { (a, c, b) } else { (a, b, c) };
+            self.triangles[index] = [a, b, c];
+            self.edges.insert((a, b), index);
+            self.edges.insert((b, c), index);
+            self.edges.insert((c, a), index);
+            self.changed.push(index);
+            self.last_triangle = index;
+        }
+        fn third_point(&self, triangle_index:usize, a:usize, b:usize) -> usize {
+            *self.triangles[triangle_index].iter().find(|&&index| index != a && index != b).unwrap()
+        }
+        fn centroid(&self, triangle_index:usize) -> Coordinate {
+            let [a, b, c] = self.triangles[triangle_index];
+            let (a, b, c) = (self.coordinates[a], self.coordinates[b], self.coordinates[c]);
+            ((a.0 + b.0 + c.0) / 3.0, (a.1 + b.1 + c.1) / 3.0)
+        }
+
+        //walk from the given triangle towards the point, crossing whichever edge the point is beyond (trying a different
+        //edge first each step, so as not to go round in circles). Should the walk not arrive, every triangle is looked
+        //through instead
+        fn locate(&self, point:Coordinate, from:usize, stop_at_constraints:bool) -> Location {
+            let mut current = from;
+            for step in 0..self.triangles.len() {
+                let triangle = self.triangles[current];
+                let beyond = (0..3)
+                    .map(|offset| (triangle[(step + offset) % 3], triangle[(step + offset + 1) % 3]))
+                    .find(|&(a, b)| orientation(self.coordinates[a], self.coordinates[b], point) < 0.0);
+
+                match beyond {
+                    None => { return self.locate_within(current, point); },
+                    Some((a, b)) => {
+                        if stop_at_constraints && self.constraints.contains(&undirected(a, b)) {
+                            return Location::Constraint(a, b);
+                        }
+                        match self.edges.get(&(b, a)) {
+                            Some(&neighbour) => { current = neighbour; },
+                            None => { return Location::Outside; },
+                        }
+                    },
+                }
+            }
+
+            let found = (0..self.triangles.len()).find(|&index| {
+                let triangle = self.triangles[index];
+                (0..3).all(|offset| orientation(self.coordinates[triangle[offset]], self.coordinates[triangle[(offset + 1) % 3]], point) >= 0.0)
+            });
+            match found {
+                Some(index) => self.locate_within(index, point),
+                None => Location::Outside,
+            }
+        }
+        //the point is known to be within (or on) the triangle
+        fn locate_within(&self, triangle_index:usize, point:Coordinate) -> Location {
+            let triangle = self.triangles[triangle_index];
+            if let Some(&vertex) = triangle.iter().find(|&&index| to_point(self.coordinates[index]) == to_point(point)) {
+                return Location::Vertex(triangle_index, vertex);
+            }
+            for offset in 0..3 {
+                let (a, b) = (triangle[offset], triangle[(offset + 1) % 3]);
+                let (p, q) = (self.coordinates[a], self.coordinates[b]);
+                if orientation(p, q, point).abs() <= 1e-12 * distance_squared(p, q) {
+                    return Location::Edge(triangle_index, a, b);
+                }
+            }
+            Location::Triangle(triangle_index)
+        }
+
+        //add the point (already in the coordinates) to the mesh, returning false if it wasn't (it's a duplicate, or
+        //outside the super triangle)
+        fn insert_point(&mut self, point_index:usize) -> bool {
+            let location = self.locate(self.coordinates[point_index], self.last_triangle, false);
+            self.insert_point_at(point_index, location)
+        }
+        fn insert_point_at(&mut self, point_index:usize, location:Location) -> bool {
+            let outer_edges = match location {
+                Location::Triangle(triangle_index) => self.split_triangle(triangle_index, point_index),
+                Location::Edge(triangle_index, a, b) => self.split_edge(triangle_index, a, b, point_index),
+                _ => { return false; },
+            };
+            self.legalise(point_index, outer_edges);
+            true
+        }
+        //a new point at the coordinate, returning false if there's already one there
+        fn add_point(&mut self, coordinate:Coordinate, location:Location) -> bool {
+            self.coordinates.push(coordinate);
+            let added = self.insert_point_at(self.coordinates.len() - 1, location);
+            if !added {
+                self.coordinates.pop();
+            }
+            added
+        }
+        //the triangle becomes three, fanning out from the point; returns the edges around them
+        fn split_triangle(&mut self, triangle_index:usize, point_index:usize) -> Vec<(usize,usize)> {
+            let [a, b, c] = self.triangles[triangle_index];
+            let inside = self.inside[triangle_index];
+            self.set_triangle(triangle_index, a, b, point_index);
+            self.add_triangle(b, c, point_index, inside);
+            self.add_triangle(c, a, point_index, inside);
+            vec![(a, b), (b, c), (c, a)]
+        }
+        //the point is on the edge a-b, so the triangles either side of it each become two; returns the edges around them
+        fn split_edge(&mut self, triangle_index:usize, a:usize, b:usize, point_index:usize) -> Vec<(usize,usize)> {
+            let other_index = self.edges.get(&(b, a)).cloned();
+            let mut outer_edges:Vec<(usize,usize)> = vec![];
+
+            let c = self.third_point(triangle_index, a, b);
+            let inside = self.inside[triangle_index];
+            self.set_triangle(triangle_index, c, a, point_index);
+            self.add_triangle(c, point_index, b, inside);
+            outer_edges.push((c, a));
+            outer_edges.push((b, c));
+
+            if let Some(other_index) = other_index {
+                let d = self.third_point(other_index, a, b);
+                let inside = self.inside[other_index];
+                self.set_triangle(other_index, d, b, point_index);
+                self.add_triangle(d, point_index, a, inside);
+                outer_edges.push((d, b));
+                outer_edges.push((a, d));
+            }
+
+            if self.constraints.remove(&undirected(a, b)) {
+                self.constraints.insert(undirected(a, point_index));
+                self.constraints.insert(undirected(point_index, b));
+            }
+            outer_edges
+        }
+        //a new point half way along the constraint edge, returning false if it's too short to be split
+        fn split_constraint(&mut self, a:usize, b:usize) -> bool {
+            let (p, q) = (self.coordinates[a], self.coordinates[b]);
+            let middle = ((p.0 + q.0) / 2.0, (p.1 + q.1) / 2.0);
+            if to_point(middle) == to_point(p) || to_point(middle) == to_point(q) {
+                return false;
+            }
+            let location = match (self.edges.get(&(a, b)), self.edges.get(&(b, a))) {
+                (Some(&triangle_index), _) => Location::Edge(triangle_index, a, b),
+                (None, Some(&triangle_index)) => Location::Edge(triangle_index, b, a),
+                (None, None) => { return false; },
+            };
+            self.add_point(middle, location)
+        }
+
+        //replace the diagonal a-b of the quadrilateral formed by its two triangles with the other diagonal
+        //returns the new diagonal, or None if the quadrilateral isn't convex (or the edge is on the edge of the mesh)
+        fn flip(&mut self, a:usize, b:usize) -> Option<(usize,usize)> {
+            let triangle_a = *self.edges.get(&(a, b))?;
+            let triangle_b = *self.edges.get(&(b, a))?;
+            let c = self.third_point(triangle_a, a, b);
+            let d = self.third_point(triangle_b, a, b);
+
+            if !segments_cross(self.coordinates[a], self.coordinates[b], self.coordinates[c], self.coordinates[d]) {
+                return None;
+            }
+
+            self.set_triangle(triangle_a, a, d, c);
+            self.set_triangle(triangle_b, b, c, d);
+            Some((c, d))
+        }
+        fn is_locally_delaunay(&self, a:usize, b:usize) -> bool {
+            let (triangle_a, triangle_b) = match (self.edges.get(&(a, b)), self.edges.get(&(b, a))) {
+                (Some(triangle_a), Some(triangle_b)) => (*triangle_a, *triangle_b),
+                _ => return true,
+            };
+            let triangle = self.triangles[triangle_a];
+            let d = self.third_point(triangle_b, a, b);
+            in_circle(self.coordinates[triangle[0]], self.coordinates[triangle[1]], self.coordinates[triangle[2]], self.coordinates[d]) <= 0.0
+        }
+        //Lawson flipping, outwards from a new point; only the edges around it can have stopped being Delaunay
+        fn legalise(&mut self, point_index:usize, mut edges:Vec<(usize,usize)>) {
+            while let Some((a, b)) = edges.pop() {
+                if self.constraints.contains(&undirected(a, b)) || self.is_locally_delaunay(a, b) {
+                    continue;
+                }
+                if let Some((c, d)) = self.flip(a, b) {
+                    let far = if c == point_index { d } else { c };
+                    edges.push((a, far));
+                    edges.push((far, b));
+                }
+            }
+        }
+        //Lawson flipping everywhere, to bring back the Delaunay property (other than along constraint edges) after
+        //constraints have been forced in
+        fn restore_delaunay(&mut self) {
+            let mut edges:Vec<(usize,usize)> = self.edges.keys().filter(|&&(u, v)| u < v).cloned().collect();
+            let mut flips = 0;
+            let flip_limit = (edges.len() + 1) * 100;
+            while let Some((a, b)) = edges.pop() {
+                if self.constraints.contains(&undirected(a, b)) || self.is_locally_delaunay(a, b) {
+                    continue;
+                }
+                if let Some((c, d)) = self.flip(a, b) {
+                    flips += 1;
+                    if flips > flip_limit {
+                        println!("WARNING - triangulation - unable to restore the Delaunay property");
+                        return;
+                    }
+                    edges.extend_from_slice(&[(a, c), (c, b), (b, d), (d, a)]);
+                }
+            }
+        }
+
+        //force the edge a-b into the mesh, by repeatedly flipping the edges that cross it (Sloan's method)
+        fn insert_constraint(&mut self, a:usize, b:usize) {
+            if a == b {
+                return;
+            }
+            if self.edges.contains_key(&(a, b)) || self.edges.contains_key(&(b, a)) {
+                self.constraints.insert(undirected(a, b));
+                return;
+            }
+
+            let mut crossing:VecDeque<(usize,usize)> = match self.find_constraint_path(a, b) {
+                ConstraintPath::Through(other) => {
+                    //split at the point in the way
+                    self.insert_constraint(a, other);
+                    self.insert_constraint(other, b);
+                    return;
+                },
+                ConstraintPath::Crossing(crossing) => crossing.into_iter().collect(),
+            };
+
+            let mut attempts = 0;
+            let attempt_limit = (crossing.len() + 1) * 100;
+            while let Some((u, v)) = crossing.pop_back() {
+                attempts += 1;
+                if attempts > attempt_limit {
+                    println!("WARNING - triangulation - unable to insert constraint edge {}-{}", a, b);
+                    return;
+                }
+
+                match self.flip(u, v) {
+                    None => crossing.push_front((u, v)), //not convex yet, come back to it once its neighbours have been flipped
+                    Some((c, d)) => {
+                        if c != a && c != b && d != a && d != b && segments_cross(self.coordinates[a], self.coordinates[b], self.coordinates[c], self.coordinates[d]) {
+                            crossing.push_front((c, d));
+                        }
+                    },
+                }
+            }
+            self.constraints.insert(undirected(a, b));
+        }
+        //turn around a to the triangle the edge a-b leaves through, then walk along the edge to b
+        fn find_constraint_path(&self, a:usize, b:usize) -> ConstraintPath {
+            let (p, q) = (self.coordinates[a], self.coordinates[b]);
+            let length_squared = distance_squared(p, q);
+            let on_path = |index:usize| -> bool {
+                let r = self.coordinates[index];
+                let t = ((r.0 - p.0) * (q.0 - p.0) + (r.1 - p.1) * (q.1 - p.1)) / length_squared;
+                t > 0.0 && t < 1.0 && orientation(p, q, r).abs() <= 1e-9 * length_squared
+            };
+
+            let start = match self.locate(p, self.last_triangle, false) {
+                Location::Vertex(triangle_index, vertex) if vertex == a => triangle_index,
+                _ => { return self.search_constraint_path(a, b); },
+            };
+
+            //around a
+                let mut leaving:Option<(usize,usize)> = None;
+                let mut current = start;
+                for _ in 0..self.triangles.len() {
+                    let triangle = self.triangles[current];
+                    let offset = triangle.iter().position(|&index| index == a).unwrap();
+                    let (x, y) = (triangle[(offset + 1) % 3], triangle[(offset + 2) % 3]);
+                    if on_path(x) { return ConstraintPath::Through(x); }
+                    if on_path(y) { return ConstraintPath::Through(y); }
+                    if segments_cross(p, q, self.coordinates[x], self.coordinates[y]) {
+                        leaving = Some((x, y));
+                        break;
+                    }
+                    match self.edges.get(&(a, y)) {
+                        Some(&next) if next != start => { current = next; },
+                        _ => { break; },
+                    }
+                }
+                let (mut x, mut y) = match leaving {
+                    Some(edge) => edge,
+                    None => { return self.search_constraint_path(a, b); },
+                };
+
+            //along to b
+                let mut crossing:Vec<(usize,usize)> = vec![];
+                for _ in 0..self.triangles.len() {
+                    crossing.push((x, y));
+                    let next = match self.edges.get(&(y, x)) {
+                        Some(&next) => next,
+                        None => { break; },
+                    };
+                    let z = self.third_point(next, x, y);
+                    if z == b { return ConstraintPath::Crossing(crossing); }
+                    if on_path(z) { return ConstraintPath::Through(z); }
+                    if segments_cross(p, q, self.coordinates[x], self.coordinates[z]) { y = z; } else { x = z; }
+                }
+                self.search_constraint_path(a, b)
+        }
+        //the slow way, looking through every point and edge
+        fn search_constraint_path(&self, a:usize, b:usize) -> ConstraintPath {
+            let (p, q) = (self.coordinates[a], self.coordinates[b]);
+            let length_squared = distance_squared(p, q);
+            let through = (SUPER_POINT_COUNT..self.coordinates.len()).find(|&other| {
+                if other == a || other == b { return false; }
+                let r = self.coordinates[other];
+                let t = ((r.0 - p.0) * (q.0 - p.0) + (r.1 - p.1) * (q.1 - p.1)) / length_squared;
+                t > 0.0 && t < 1.0 && orientation(p, q, r).abs() <= 1e-9 * length_squared
+            });
+            if let Some(other) = through {
+                return ConstraintPath::Through(other);
+            }
+
+            ConstraintPath::Crossing(
+                self.edges.keys()
+                    .filter(|&&(u, v)| u < v || !self.edges.contains_key(&(v, u)))
+                    .filter(|&&(u, v)| u != a && u != b && v != a && v != b)
+                    .filter(|&&(u, v)| segments_cross(p, q, self.coordinates[u], self.coordinates[v]))
+                    .cloned()
+                    .collect()
+            )
+        }
+
+        //the edges around the outside of the real points (keeping any points along them). A finite super triangle can
+        //take the place of hull edges beside a sliver, whose circumcircle reaches out past it, so these are made
+        //constraints once all the points are in
+        fn hull_edges(&self) -> Vec<(usize,usize)> {
+            let mut order:Vec<usize> = (SUPER_POINT_COUNT..self.coordinates.len()).collect();
+            if order.len() < 3 {
+                return vec![];
+            }
+            order.sort_by(|&a, &b| {
+                let (a, b) = (self.coordinates[a], self.coordinates[b]);
+                a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal).then(a.1.partial_cmp(&b.1).unwrap_or(Ordering::Equal))
+            });
+
+            //Andrew's monotone chain, along the bottom and back along the top
+            let mut hull:Vec<usize> = vec![];
+            for pass in 0..2 {
+                let start = hull.len();
+                let chain:Vec<usize> = if pass == 0 { order.clone() } else { order.iter().rev().cloned().collect() };
+                for index in chain {
+                    while hull.len() >= start + 2 && orientation(self.coordinates[hull[hull.len() - 2]], self.coordinates[hull[hull.len() - 1]], self.coordinates[index]) < 0.0 {
+                        hull.pop();
+                    }
+                    hull.push(index);
+                }
+                hull.pop(); //each chain ends where the other starts
+            }
+
+            (0..hull.len()).map(|index| (hull[index], hull[(index + 1) % hull.len()])).collect()
+        }
+
+        //flood out from each triangle not yet reached, without crossing constraint edges, and decide whether the whole
+        //region is inside from its largest triangle (whose centroid is the least likely to sit on a ring)
+        fn mark_inside(&mut self, is_inside:&dyn Fn(&Point) -> bool) {
+            let mut inside:Vec<Option<bool>> = vec![None; self.triangles.len()];
+            let mut region:Vec<usize> = vec![];
+            for start in 0..self.triangles.len() {
+                if inside[start].is_some() {
+                    continue;
+                }
+
+                region.clear();
+                region.push(start);
+                inside[start] = Some(false);
+                let mut index = 0;
+                while index < region.len() {
+                    let triangle = self.triangles[region[index]];
+                    for offset in 0..3 {
+                        let (a, b) = (triangle[offset], triangle[(offset + 1) % 3]);
+                        if self.constraints.contains(&undirected(a, b)) { continue; }
+                        if let Some(&neighbour) = self.edges.get(&(b, a)) {
+                            if inside[neighbour].is_none() {
+                                inside[neighbour] = Some(false);
+                                region.push(neighbour);
+                            }
+                        }
+                    }
+                    index += 1;
+                }
+
+                let area = |index:&usize| {
+                    let [a, b, c] = self.triangles[*index];
+                    orientation(self.coordinates[a], self.coordinates[b], self.coordinates[c])
+                };
+                let largest = *region.iter().max_by(|a, b| area(a).partial_cmp(&area(b)).unwrap_or(Ordering::Equal)).unwrap();
+                let region_inside = self.triangles[largest].iter().all(|&index| Mesh::is_real(index)) && is_inside(&to_point(self.centroid(largest)));
+                for &index in &region {
+                    inside[index] = Some(region_inside);
+                }
+            }
+            self.inside = inside.into_iter().map(|inside| inside.unwrap_or(false)).collect();
+        }
+
+        fn real_triangles(&self) -> Vec<[usize;3]> {
+            self.triangles.iter().filter(|triangle| triangle.iter().all(|&index| Mesh::is_real(index))).cloned().collect()
+        }
+        fn real_edges(&self) -> Vec<(usize,usize)> {
+            self.edges.keys().filter(|&&(u, v)| u < v && Mesh::is_real(u)).cloned().collect()
+        }
+    }
+    //the points, their hull forced in, and the Delaunay property brought back
+    fn build_delaunay_mesh(points:&[Point]) -> Mesh {
+        let mut mesh = Mesh::new(points);
+        for index in SUPER_POINT_COUNT..mesh.coordinates.len() {
+            mesh.insert_point(index);
+        }
+        for (a, b) in mesh.hull_edges() {
+            mesh.insert_constraint(a, b);
+        }
+        mesh.restore_delaunay();
+        mesh
+    }
+
+
+
+
+
+
+
+
+//delaunay
+    //triangles of indices into the points (duplicate points share the index of the first), wound clockwise on screen
+    pub fn delaunay(points:&[Point]) -> Vec<[usize;3]> {
+        let mut unique:Vec<Point> = vec![];
+        let lookup:Vec<usize> = points.iter().map(|point| find_or_add_point(&mut unique, *point)).collect();
+        let first_occurrence:Vec<usize> = (0..unique.len()).map(|index| lookup.iter().position(|&unique_index| unique_index == index).unwrap()).collect();
+
+        let mesh = build_delaunay_mesh(&unique);
+        mesh.real_triangles().iter().map(|triangle| {
+            [
+                first_occurrence[triangle[0] - SUPER_POINT_COUNT],
+                first_occurrence[triangle[1] - SUPER_POINT_COUNT],
+                first_occurrence[triangle[2] - SUPER_POINT_COUNT],
+            ]
+        }).collect()
+    }
+    pub fn delaunay_triangles(points:&[Point]) -> Vec<SimplePolygon> {
+        delaunay(points).iter().map(|triangle| {
+            SimplePolygon::new_from_point_vector(vec![points[triangle[0]], points[triangle[1]], points[triangle[2]]])
+        }).collect()
+    }
+
+
+
+
+
+
+
+
+//voronoi
+    //one cell per point (in the same order), clipped to the bounding box. Each cell is the area of the bounding box closer to its point than any other
+    pub fn voronoi(points:&[Point], bounding_box:&BoundingBox) -> Vec<SimplePolygon> {
+        let mut unique:Vec<Point> = vec![];
+        let lookup:Vec<usize> = points.iter().map(|point| find_or_add_point(&mut unique, *point)).collect();
+
+        let mesh = build_delaunay_mesh(&unique);
+
+        //delaunay neighbours are exactly the points whose cells border one another
+        let mut neighbours:Vec<Vec<usize>> = vec![vec![]; unique.len()];
+        for (u, v) in mesh.real_edges() {
+            neighbours[u - SUPER_POINT_COUNT].push(v);
+            neighbours[v - SUPER_POINT_COUNT].push(u);
+        }
+
+        let frame:Vec<Coordinate> = SimplePolygon::new_from_bounding_box(*bounding_box, true).get_points().iter().map(to_coordinate).collect();
+        let cells:Vec<SimplePolygon> = (0..unique.len()).map(|index| {
+            let site = mesh.coordinates[index + SUPER_POINT_COUNT];
+            let mut cell = frame.clone();
+            for &neighbour in &neighbours[index] {
+                let other = mesh.coordinates[neighbour];
+                //keep the side of the bisector nearest the site: x.(other-site) <= (|other|^2 - |site|^2)/2
+                let normal = (other.0 - site.0, other.1 - site.1);
+                let limit = ((other.0 * other.0 + other.1 * other.1) - (site.0 * site.0 + site.1 * site.1)) / 2.0;
+                cell = clip_to_half_plane(&cell, normal, limit);
+                if cell.is_empty() { break; }
+            }
+            SimplePolygon::new_from_point_vector(cell.into_iter().map(to_point).collect())
+        }).collect();
+
+        lookup.iter().map(|&index| cells[index].clone()).collect()
+    }
+    //Sutherland-Hodgman, against the half plane of points x where x.normal <= limit
+    fn clip_to_half_plane(polygon:&[Coordinate], normal:Coordinate, limit:f64) -> Vec<Coordinate> {
+        let value = |point:Coordinate| point.0 * normal.0 + point.1 * normal.1 - limit;
+        let mut output:Vec<Coordinate> = vec![];
+
+        for index in 0..polygon.len() {
+            let current = polygon[index];
+            let next = polygon[(index + 1) % polygon.len()];
+            let current_value = value(current);
+            let next_value = value(next);
+
+            if current_value <= 0.0 {
+                output.push(current);
+            }
+            if (current_value < 0.0 && next_value > 0.0) || (current_value > 0.0 && next_value < 0.0) {
+                let t = current_value / (current_value - next_value);
+                output.push((current.0 + (next.0 - current.0) * t, current.1 + (next.1 - current.1) * t));
+            }
+        }
+
+        output
+    }
+
+
+
+
+
+
+
+
+//constrained delaunay
+    //triangulate the area within the body and outside the holes, keeping every ring's edges as triangle edges
+    //triangles are wound clockwise on screen
+    pub fn constrained_delaunay(body:&SimplePolygon, holes:&[SimplePolygon], refinement:&TriangulationRefinement) -> Vec<SimplePolygon> {
+        let is_inside = |point:&Point| -> bool {
+            body.intersect_with_point(point) == super::super::data_type::PolySide::Inside &&
+            !holes.iter().any(|hole| hole.intersect_with_point(point) != super::super::data_type::PolySide::Outside)
+        };
+
+        //gather the points and the edges that must be kept
+            let mut points:Vec<Point> = vec![];
+            let mut segments:Vec<(usize,usize)> = vec![];
+            for ring in std::iter::once(body).chain(holes.iter()) {
+                let indices:Vec<usize> = ring.get_points().iter().map(|point| find_or_add_point(&mut points, *point)).collect();
+                for index in 0..indices.len() {
+                    let (a, b) = (indices[index], indices[(index + 1) % indices.len()]);
+                    if a != b && !segments.contains(&undirected(a, b)) {
+                        segments.push(undirected(a, b));
+                    }
+                }
+            }
+            if points.len() < 3 {
+                return vec![];
+            }
+
+        //triangulate, force the rings in, and work out which triangles are within them
+            let mut mesh = Mesh::new(&points);
+            for index in SUPER_POINT_COUNT..mesh.coordinates.len() {
+                mesh.insert_point(index);
+            }
+            for &(a, b) in &segments {
+                mesh.insert_constraint(a + SUPER_POINT_COUNT, b + SUPER_POINT_COUNT);
+            }
+            mesh.restore_delaunay();
+            mesh.mark_inside(&is_inside);
+
+        if refinement.is_active() {
+            refine(&mut mesh, refinement);
+        }
+
+        (0..mesh.triangles.len()).filter(|&index| mesh.inside[index]).map(|index| {
+            SimplePolygon::new_from_point_vector(mesh.triangles[index].iter().map(|&point| to_point(mesh.coordinates[point])).collect())
+        }).collect()
+    }
+
+    //a triangle waiting to be refined, worst first
+    struct RefinementCandidate {
+        badness: f64,
+        triangle_index: usize,
+        triangle: [usize;3], //as it was, so that candidates for triangles that have since been replaced can be told apart
+    }
+    impl PartialEq for RefinementCandidate {
+        fn eq(&self, other:&Self) -> bool { self.badness == other.badness }
+    }
+    impl Eq for RefinementCandidate {}
+    impl PartialOrd for RefinementCandidate {
+        fn partial_cmp(&self, other:&Self) -> Option<Ordering> { Some(self.cmp(other)) }
+    }
+    impl Ord for RefinementCandidate {
+        fn cmp(&self, other:&Self) -> Ordering { self.badness.partial_cmp(&other.badness).unwrap_or(Ordering::Equal) }
+    }
+
+    //Ruppert's refinement; the worst triangle gets a Steiner point at its circumcentre, unless that's beyond a
+    //constraint edge or within its diametral circle, in which case the edge is split in half instead. Points go into
+    //the mesh as it is, so only the triangles they change need looking at again
+    fn refine(mesh:&mut Mesh, refinement:&TriangulationRefinement) {
+        let mut queue:BinaryHeap<RefinementCandidate> = BinaryHeap::new();
+        let enqueue = |mesh:&Mesh, queue:&mut BinaryHeap<RefinementCandidate>, triangle_index:usize| {
+            if !mesh.inside[triangle_index] {
+                return;
+            }
+            let triangle = mesh.triangles[triangle_index];
+            if let Some(badness) = triangle_badness(mesh, &triangle, refinement) {
+                queue.push(RefinementCandidate { badness, triangle_index, triangle });
+            }
+        };
+        for triangle_index in 0..mesh.triangles.len() {
+            enqueue(mesh, &mut queue, triangle_index);
+        }
+
+        let mut steiner_points = 0;
+        while steiner_points < refinement.maximum_steiner_points {
+            let candidate = match queue.pop() {
+                None => { break; },
+                Some(candidate) => candidate,
+            };
+            if mesh.triangles[candidate.triangle_index] != candidate.triangle {
+                continue;
+            }
+
+            let [a, b, c] = candidate.triangle;
+            let (a, b, c) = (mesh.coordinates[a], mesh.coordinates[b], mesh.coordinates[c]);
+            let centroid = mesh.centroid(candidate.triangle_index);
+            let centre = circumcentre(a, b, c).unwrap_or(centroid);
+
+            mesh.changed.clear();
+            let location = mesh.locate(centre, candidate.triangle_index, true);
+            let added = match location {
+                Location::Constraint(u, v) => mesh.split_constraint(u, v),
+                Location::Triangle(triangle_index) | Location::Edge(triangle_index, _, _) => {
+                    let triangle = mesh.triangles[triangle_index];
+                    let encroached = (0..3).map(|offset| (triangle[offset], triangle[(offset + 1) % 3])).find(|&(u, v)| {
+                        let (p, q) = (mesh.coordinates[u], mesh.coordinates[v]);
+                        let middle = ((p.0 + q.0) / 2.0, (p.1 + q.1) / 2.0);
+                        mesh.constraints.contains(&undirected(u, v)) && distance_squared(centre, middle) < distance_squared(p, q) / 4.0
+                    });
+                    match encroached {
+                        Some((u, v)) => mesh.split_constraint(u, v),
+                        None if mesh.inside[triangle_index] => mesh.add_point(centre, location),
+                        None => mesh.add_point(centroid, Location::Triangle(candidate.triangle_index)),
+                    }
+                },
+                Location::Vertex(_, _) | Location::Outside => mesh.add_point(centroid, Location::Triangle(candidate.triangle_index)),
+            };
+            if !added {
+                //nothing new could be added for this triangle; it's as good as it gets
+                continue;
+            }
+            steiner_points += 1;
+
+            let changed:Vec<usize> = mesh.changed.drain(..).collect();
+            for triangle_index in changed {
+                enqueue(mesh, &mut queue, triangle_index);
+            }
+            if mesh.triangles[candidate.triangle_index] == candidate.triangle {
+                queue.push(candidate);
+            }
+        }
+    }
+    //how far the triangle is from meeting the refinement's limits (None if it does)
+    fn triangle_badness(mesh:&Mesh, triangle:&[usize;3], refinement:&TriangulationRefinement) -> Option<f64> {
+        let (a, b, c) = (mesh.coordinates[triangle[0]], mesh.coordinates[triangle[1]], mesh.coordinates[triangle[2]]);
+
+        if let Some(maximum_area) = refinement.maximum_area {
+            let area = orientation(a, b, c).abs() / 2.0;
+            if area > maximum_area as f64 {
+                return Some(area / maximum_area as f64);
+            }
+        }
+
+        if let Some(minimum_angle) = refinement.minimum_angle {
+            let angles = [angle_at(a, b, c), angle_at(b, c, a), angle_at(c, a, b)];
+            let (smallest_index, smallest) = angles.iter().enumerate().fold((0, f64::MAX), |best, (index, &angle)| if angle < best.1 { (index, angle) } else { best });
+            if smallest < minimum_angle as f64 {
+                //a sharp corner between two constraint edges can't be improved upon, so leave it be
+                let vertex = triangle[smallest_index];
+                let others = [triangle[(smallest_index + 1) % 3], triangle[(smallest_index + 2) % 3]];
+                if mesh.constraints.contains(&undirected(vertex, others[0])) && mesh.constraints.contains(&undirected(vertex, others[1])) {
+                    return None;
+                }
+                return Some(1.0 + (minimum_angle as f64 - smallest));
+            }
+        }
+
+        None
+    }
+
+
+
+
+
+
+
+
+//tests
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use super::super::super::data_type::{
+            ComplexPolygon,
+            PolySide,
+        };
+
+        fn area_of(polygons:&[SimplePolygon]) -> f32 {
+            polygons.iter().map(|polygon| polygon.get_area()).sum()
+        }
+        fn has_edge(triangles:&[[usize;3]], a:usize, b:usize) -> bool {
+            triangles.iter().any(|triangle| triangle.contains(&a) && triangle.contains(&b))
+        }
+        //a point just inside the long hull edge makes a sliver whose circumcircle is far larger than any finite super
+        //triangle, which used to cost the mesh that hull edge
+        fn sliver() -> Vec<Point> {
+            vec![Point::new(0.0, 0.0), Point::new(20.0, 0.0), Point::new(10.0, 0.001), Point::new(10.0, 10.0)]
+        }
+        //a very shallow arc around one far point
+        fn shallow_arc() -> Vec<Point> {
+            let mut points:Vec<Point> = (0..=20).map(|x| Point::new(x as f32, ((x - 10) * (x - 10)) as f32 * 0.000_001)).collect();
+            points.push(Point::new(10.0, 10.0));
+            points
+        }
+
+        #[test]
+        fn delaunay_keeps_hull_edges() {
+            let points = sliver();
+            let triangles = delaunay(&points);
+            assert!(has_edge(&triangles, 0, 1), "hull edge missing from {:?}", triangles);
+            assert_eq!(triangles.len(), 3);
+            assert!((area_of(&delaunay_triangles(&points)) - 100.0).abs() < 0.01);
+
+            let points = shallow_arc();
+            let triangles = delaunay(&points);
+            for index in 0..20 {
+                assert!(has_edge(&triangles, index, index + 1), "hull edge {}-{} missing", index, index + 1);
+            }
+            assert!(has_edge(&triangles, 0, 21) && has_edge(&triangles, 20, 21));
+            let hull = SimplePolygon::new_from_point_vector(points.clone());
+            assert!((area_of(&delaunay_triangles(&points)) - hull.get_area()).abs() < 0.01);
+        }
+
+        #[test]
+        fn delaunay_of_a_grid() {
+            let points:Vec<Point> = (0..16).map(|index| Point::new((index % 4) as f32, (index / 4) as f32)).collect();
+            let triangles = delaunay(&points);
+            assert_eq!(triangles.len(), 18);
+            assert!((area_of(&delaunay_triangles(&points)) - 9.0).abs() < 0.001);
+        }
+
+        #[test]
+        fn constrained_delaunay_leaves_holes_empty() {
+            let body = SimplePolygon::new_from_point_vector(vec![Point::new(0.0,0.0), Point::new(10.0,0.0), Point::new(10.0,10.0), Point::new(0.0,10.0)]);
+            let hole = SimplePolygon::new_from_point_vector(vec![Point::new(3.0,3.0), Point::new(7.0,3.0), Point::new(7.0,7.0), Point::new(3.0,7.0)]);
+            let polygon = ComplexPolygon::new_from_simple_polygons(body.clone(), vec![hole.clone()]);
+
+            let refinements = [
+                TriangulationRefinement::new_default(),
+                TriangulationRefinement::new(Some(2.0), Some(0.35), 500),
+            ];
+            for refinement in refinements.iter() {
+                let triangles = constrained_delaunay(&body, &[hole.clone()], refinement);
+                assert!((area_of(&triangles) - 84.0).abs() < 0.01, "{} covered", area_of(&triangles));
+                for triangle in &triangles {
+                    let points = triangle.get_points();
+                    let centre = Point::new(
+                        (points[0].get_x() + points[1].get_x() + points[2].get_x()) / 3.0,
+                        (points[0].get_y() + points[1].get_y() + points[2].get_y()) / 3.0,
+                    );
+                    assert!(polygon.intersect_with_point(&centre) == PolySide::Inside, "triangle {} outside the polygon", triangle);
+                    if let Some(maximum_area) = refinement.get_maximum_area() {
+                        assert!(triangle.get_area() <= maximum_area + 0.001, "triangle {} too large", triangle);
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn constrained_delaunay_of_a_concave_polygon() {
+            //an L shape, whose inner corner is off the hull
+            let body = SimplePolygon::new_from_point_vector(vec![
+                Point::new(0.0,0.0), Point::new(10.0,0.0), Point::new(10.0,4.0), Point::new(4.0,4.0), Point::new(4.0,10.0), Point::new(0.0,10.0),
+            ]);
+            let triangles = constrained_delaunay(&body, &[], &TriangulationRefinement::new(Some(1.0), None, 1000));
+            assert!((area_of(&triangles) - 64.0).abs() < 0.01, "{} covered", area_of(&triangles));
+            assert!(triangles.iter().all(|triangle| triangle.get_area() <= 1.001));
+        }
+
+        #[test]
+        fn voronoi_cells_tile_the_bounding_box() {
+            //cells missing a neighbour overlap one another, and so cover more than the box
+            let bounding_box = BoundingBox::new(-5.0, -5.0, 25.0, 15.0);
+            for points in [sliver(), shallow_arc()].iter() {
+                let cells = voronoi(points, &bounding_box);
+                assert_eq!(cells.len(), points.len());
+                assert!((area_of(&cells) - 600.0).abs() < 0.1, "{} covered", area_of(&cells));
+            }
+
+        }
+
+        #[test]
+        fn voronoi_finds_far_neighbours() {
+            //the sliver's hull edge makes the points at its ends neighbours, though their cells only meet far beyond it;
+            //without that, the two cells overlap there
+            let cells = voronoi(&sliver(), &BoundingBox::new(-100_000.0, -100_000.0, 100_000.0, 100_000.0));
+            let covered = cells.iter().map(|cell| cell.get_area() as f64).sum::<f64>();
+            assert!((covered - 4e10).abs() < 4e4, "{} covered", covered);
+        }
+
+        #[test]
+        fn voronoi_of_a_grid() {
+            let points:Vec<Point> = (0..9).map(|index| Point::new((index % 3) as f32, (index / 3) as f32)).collect();
+            let cells = voronoi(&points, &BoundingBox::new(-0.5, -0.5, 2.5, 2.5));
+            for cell in &cells {
+                assert!((cell.get_area() - 1.0).abs() < 0.001, "cell {} isn't a unit square", cell);
+            }
+        }
+    }
@@ -1,5 +1,12 @@
+pub mod animation;
+pub mod atlas_packer;
+pub mod camera_controller;
 pub mod data_type;
 pub mod math;
+pub mod scene_generation;
+pub mod scene_graph;
+pub mod text;
+pub mod viewport;
 
 mod communique;
 pub use communique::Communique;
@@ -0,0 +1,213 @@
+use std::fmt;
+
+use rand::{
+    Rng,
+    SeedableRng,
+};
+use rand_chacha::ChaCha8Rng;
+
+use super::data_type::{
+    Colour,
+    Point,
+    SimplePolygon,
+};
+
+
+
+
+
+
+
+
+//shape kind
+    #[derive(Copy, Clone, PartialEq)]
+    pub enum ShapeKind {
+        Rectangle,
+        Ellipse,
+        Triangle,
+        RegularPolygon(usize), //number of sides
+    }
+    impl fmt::Display for ShapeKind {
+        fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result {
+            match self {
+                ShapeKind::Rectangle => write!(f, "Rectangle"),
+                ShapeKind::Ellipse => write!(f, "Ellipse"),
+                ShapeKind::Triangle => write!(f, "Triangle"),
+                ShapeKind::RegularPolygon(sides) => write!(f, "RegularPolygon({})", sides),
+            }
+        }
+    }
+    impl fmt::Debug for ShapeKind {
+        fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { fmt::Display::fmt(self, f) }
+    }
+
+
+
+
+
+
+
+
+//generated shape
+    //positioned by its centre, in the same terms as the renderer's uniform data (with an anchor of 0.5,0.5)
+    #[derive(Copy, Clone)]
+    pub struct GeneratedShape {
+        pub kind: ShapeKind,
+        pub x: f32,
+        pub y: f32,
+        pub width: f32,
+        pub height: f32,
+        pub angle: f32,
+        pub colour: Colour,
+    }
+    impl GeneratedShape {
+        //the outline in world space, with ellipses approximated by the given number of points
+        pub fn get_simple_polygon(&self, ellipse_point_count:usize) -> SimplePolygon {
+            let unit_points:Vec<(f32,f32)> = match self.kind {
+                ShapeKind::Rectangle => vec![ (-0.5,-0.5), (0.5,-0.5), (0.5,0.5), (-0.5,0.5) ],
+                ShapeKind::Triangle => vec![ (0.0,-0.5), (0.5,0.5), (-0.5,0.5) ],
+                ShapeKind::Ellipse => regular_polygon_points(ellipse_point_count.max(3)),
+                ShapeKind::RegularPolygon(sides) => regular_polygon_points(sides.max(3)),
+            };
+
+            let mut polygon = SimplePolygon::new_from_point_vector(
+                unit_points.into_iter().map(|(x, y)| Point::new(x * self.width, y * self.height)).collect()
+            );
+            polygon.rotate(self.angle, &Point::new(0.0, 0.0));
+            polygon.translate(self.x, self.y);
+            polygon
+        }
+    }
+    fn regular_polygon_points(count:usize) -> Vec<(f32,f32)> {
+        (0..count).map(|index| {
+            let angle = 2.0 * std::f32::consts::PI * (index as f32) / (count as f32);
+            (0.5 * angle.cos(), 0.5 * angle.sin())
+        }).collect()
+    }
+
+
+
+
+
+
+
+
+//scene description
+    //everything needed to produce the same list of shapes on every run. Each shape draws the same numbers from the
+    //random number generator in the same order, so changing (say) the palette doesn't move the shapes around. The
+    //generator is a named algorithm (unlike rand's StdRng, which may change between versions and platforms), so a seed
+    //means the same scene everywhere
+    pub struct SceneDescription {
+        seed: u64,
+        count: usize,
+        area_width: f32,
+        area_height: f32,
+        size_range: (f32, f32), //applied to width and height separately
+        rotation_range: (f32, f32), //radians
+        palette: Vec<Colour>, //empty for any opaque colour
+        shape_kinds: Vec<ShapeKind>, //empty for rectangles
+    }
+//new
+    impl SceneDescription {
+        pub fn new(
+            seed:u64, count:usize,
+            (area_width, area_height):(f32, f32),
+            size_range:(f32, f32), rotation_range:(f32, f32),
+            palette:Vec<Colour>, shape_kinds:Vec<ShapeKind>,
+        ) -> SceneDescription {
+            SceneDescription {
+                seed,
+                count,
+                area_width,
+                area_height,
+                size_range,
+                rotation_range,
+                palette,
+                shape_kinds,
+            }
+        }
+        //30 by 30 squares at any angle, in any colour, scattered across 500 by 400
+        pub fn new_default(seed:u64, count:usize) -> SceneDescription {
+            SceneDescription::new(
+                seed, count,
+                (500.0, 400.0),
+                (30.0, 30.0), (0.0, 2.0 * std::f32::consts::PI),
+                vec![], vec![ShapeKind::Rectangle],
+            )
+        }
+    }
+//getters
+    impl SceneDescription {
+        pub fn get_seed(&self) -> u64 { self.seed }
+        pub fn get_count(&self) -> usize { self.count }
+        pub fn get_area_width(&self) -> f32 { self.area_width }
+        pub fn get_area_height(&self) -> f32 { self.area_height }
+        pub fn get_size_range(&self) -> (f32, f32) { self.size_range }
+        pub fn get_rotation_range(&self) -> (f32, f32) { self.rotation_range }
+        pub fn get_palette(&self) -> &Vec<Colour> { &self.palette }
+        pub fn get_shape_kinds(&self) -> &Vec<ShapeKind> { &self.shape_kinds }
+    }
+//setters
+    impl SceneDescription {
+        pub fn set_seed(&mut self, new:u64) { self.seed = new; }
+        pub fn set_count(&mut self, new:usize) { self.count = new; }
+        pub fn set_palette(&mut self, new:Vec<Colour>) { self.palette = new; }
+    }
+//generation
+    impl SceneDescription {
+        //shapes are produced one at a time, so very large scenes needn't be held in memory all at once; iterating twice
+        //gives the same shapes both times
+        pub fn iter(&self) -> SceneGenerator<'_> {
+            SceneGenerator {
+                description: self,
+                rng: ChaCha8Rng::seed_from_u64(self.seed),
+                produced: 0,
+            }
+        }
+        pub fn generate(&self) -> Vec<GeneratedShape> {
+            self.iter().collect()
+        }
+    }
+
+    pub struct SceneGenerator<'a> {
+        description: &'a SceneDescription,
+        rng: ChaCha8Rng,
+        produced: usize,
+    }
+    impl<'a> Iterator for SceneGenerator<'a> {
+        type Item = GeneratedShape;
+
+        fn next(&mut self) -> Option<GeneratedShape> {
+            if self.produced >= self.description.count {
+                return None;
+            }
+            self.produced += 1;
+
+            let description = self.description;
+            let rng = &mut self.rng;
+            let mut within = |(low, high):(f32, f32)| low + (high - low) * rng.gen::<f32>();
+
+            let kind_choice = within((0.0, 1.0));
+            let x = within((0.0, description.area_width));
+            let y = within((0.0, description.area_height));
+            let width = within(description.size_range);
+            let height = within(description.size_range);
+            let angle = within(description.rotation_range);
+            let colour_choice = (within((0.0, 1.0)), within((0.0, 1.0)), within((0.0, 1.0)));
+
+            let kind = match description.shape_kinds.len() {
+                0 => ShapeKind::Rectangle,
+                length => description.shape_kinds[ ((kind_choice * length as f32) as usize).min(length - 1) ],
+            };
+            let colour = match description.palette.len() {
+                0 => Colour::new(colour_choice.0, colour_choice.1, colour_choice.2, 1.0),
+                length => description.palette[ ((colour_choice.0 * length as f32) as usize).min(length - 1) ],
+            };
+
+            Some(GeneratedShape { kind, x, y, width, height, angle, colour })
+        }
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            let remaining = self.description.count - self.produced;
+            (remaining, Some(remaining))
+        }
+    }
\ No newline at end of file
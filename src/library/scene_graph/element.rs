@@ -0,0 +1,100 @@
+use std::fmt;
+
+use super::super::data_type::{
+    BoundingBox,
+    Colour,
+    Image,
+    ImageSampling,
+    Point,
+    SimplePolygon,
+};
+
+
+
+
+
+
+
+
+//kind
+    //what an element draws, in its own space (before its node's offset, and those of the groups above it, are applied)
+    #[derive(Clone)]
+    pub enum ElementKind {
+        Rectangle { width:f32, height:f32, anchor:Point, colour:Colour }, //the anchor is a fraction of the size, and sits on the origin
+        Image { image:Image, source:BoundingBox, width:f32, height:f32, anchor:Point, sampling:ImageSampling }, //the source is the part of the image drawn, in its pixels, stretched over the width and height
+    }
+    impl fmt::Display for ElementKind {
+        fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result {
+            match self {
+                ElementKind::Rectangle { width, height, anchor, colour } => write!(f, "Rectangle{{width:{}, height:{}, anchor:{}, colour:{:?}}}", width, height, anchor, colour),
+                ElementKind::Image { image, source, width, height, anchor, sampling } => write!(f, "Image{{image:{}, source:{}, width:{}, height:{}, anchor:{}, sampling:{}}}", image, source, width, height, anchor, sampling),
+            }
+        }
+    }
+    impl fmt::Debug for ElementKind {
+        fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { fmt::Display::fmt(self, f) }
+    }
+
+
+
+
+
+
+
+
+//element
+    #[derive(Clone)]
+    pub struct Element {
+        kind: ElementKind,
+        layer: usize, //see viewport::LayerFilter
+    }
+    //new
+        impl Element {
+            pub fn new(kind:ElementKind, layer:usize) -> Element {
+                Element { kind, layer }
+            }
+            pub fn new_rectangle(width:f32, height:f32, anchor:Point, colour:Colour) -> Element {
+                Element::new( ElementKind::Rectangle { width, height, anchor, colour }, 0 )
+            }
+            //without a source, the whole image is drawn (see Image::get_clamped_source for sources that go beyond it)
+            pub fn new_image(image:Image, source:Option<BoundingBox>, width:f32, height:f32, anchor:Point, sampling:ImageSampling) -> Element {
+                let source = match source {
+                    Some(source) => image.get_clamped_source(&source),
+                    None => image.get_bounding_box(),
+                };
+                Element::new( ElementKind::Image { image, source, width, height, anchor, sampling }, 0 )
+            }
+        }
+    //getters
+        impl Element {
+            pub fn get_kind(&self) -> &ElementKind { &self.kind }
+            pub fn get_kind_mut(&mut self) -> &mut ElementKind { &mut self.kind }
+            pub fn get_layer(&self) -> usize { self.layer }
+        }
+    //setters
+        impl Element {
+            pub fn set_kind(&mut self, new:ElementKind) { self.kind = new; }
+            pub fn set_layer(&mut self, new:usize) { self.layer = new; }
+        }
+    //outline
+        impl Element {
+            //the area the element covers, in its own space
+            pub fn get_local_polygon(&self) -> SimplePolygon {
+                match &self.kind {
+                    ElementKind::Rectangle { width, height, anchor, .. } | ElementKind::Image { width, height, anchor, .. } => {
+                        let left = -width * anchor.get_x();
+                        let top = -height * anchor.get_y();
+                        SimplePolygon::new_from_boundings(left, top, left + width, top + height, true)
+                    },
+                }
+            }
+        }
+    //printing
+        impl fmt::Display for Element {
+            fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result {
+                write!(f, "{{kind:{}, layer:{}}}", self.kind, self.layer)
+            }
+        }
+        impl fmt::Debug for Element {
+            fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { fmt::Display::fmt(self, f) }
+        }
\ No newline at end of file
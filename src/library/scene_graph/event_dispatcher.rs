@@ -0,0 +1,255 @@
+use super::graph::SceneGraph;
+use super::super::data_type::{
+    CallbackActivationMode,
+    CallbackType,
+    Event,
+    EventPhase,
+    Point,
+};
+use super::super::viewport::LayerFilter;
+
+
+
+
+
+
+
+
+//callback
+    struct Callback {
+        id: usize,
+        node: usize,
+        callback_type: CallbackType,
+        activation_mode: CallbackActivationMode,
+        capture: bool, //called on the way down (Capture) rather than the way back up (Bubble); both are called at the target
+        function: Box<dyn FnMut(&mut Event)>,
+    }
+    impl Callback {
+        fn answers(&self, node:usize, callback_type:CallbackType, phase:EventPhase) -> bool {
+            self.node == node && self.callback_type == callback_type && match phase {
+                EventPhase::Capture => self.capture,
+                EventPhase::Target => true,
+                EventPhase::Bubble => !self.capture,
+            }
+        }
+    }
+
+
+
+
+
+
+
+
+//event dispatcher
+    //works out which scene graph elements are under the pointer, and calls the callbacks added for them (and for the
+    //groups they're in). Points are in world space; the dispatcher has no idea about viewports. Each event goes to the
+    //elements hit in turn, top-most first, and for each of them runs from the root down to the element and back up
+    //again. Callbacks with the FirstHit activation mode only hear about the top-most element
+    pub struct EventDispatcher {
+        callbacks: Vec<Callback>, //in the order they were added, which is the order they're called in
+        next_callback_id: usize,
+
+        hits: Vec<usize>, //under the pointer when it was last seen, top-most first
+        hovered_first_hit: Vec<usize>, //the first hit and the groups above it
+        hovered_all_hits: Vec<usize>, //every hit and the groups above them
+        pressed_hits: Vec<usize>, //under the pointer when it went down, for working out clicks
+        last_click_target: Option<usize>, //for working out double clicks
+    }
+    //new
+        impl EventDispatcher {
+            pub fn new() -> EventDispatcher {
+                EventDispatcher {
+                    callbacks: vec![],
+                    next_callback_id: 0,
+                    hits: vec![],
+                    hovered_first_hit: vec![],
+                    hovered_all_hits: vec![],
+                    pressed_hits: vec![],
+                    last_click_target: None,
+                }
+            }
+        }
+    //callbacks
+        impl EventDispatcher {
+            //returns an id for removing the callback later. capture is ignored for entering and leaving, which only
+            //ever go to the node itself
+            pub fn add_callback<F:FnMut(&mut Event) + 'static>(&mut self, node:usize, callback_type:CallbackType, activation_mode:CallbackActivationMode, capture:bool, function:F) -> usize {
+                let id = self.next_callback_id;
+                self.next_callback_id += 1;
+                self.callbacks.push(Callback {
+                    id,
+                    node,
+                    callback_type,
+                    activation_mode,
+                    capture,
+                    function: Box::new(function),
+                });
+                id
+            }
+            pub fn remove_callback(&mut self, id:usize) -> bool {
+                let count = self.callbacks.len();
+                self.callbacks.retain(|callback| callback.id != id);
+                self.callbacks.len() != count
+            }
+            //for when the node is removed from the scene graph
+            pub fn remove_callbacks_for_node(&mut self, node:usize) {
+                self.callbacks.retain(|callback| callback.node != node);
+            }
+            //for after nodes have been removed from the scene graph (along with everything in them); their callbacks go,
+            //and they're forgotten as hit or hovered, so that no leave event is sent for them later
+            pub fn remove_callbacks_for_removed_nodes(&mut self, scene_graph:&SceneGraph) {
+                self.callbacks.retain(|callback| scene_graph.contains(callback.node));
+                self.hits.retain(|node| scene_graph.contains(*node));
+                self.hovered_first_hit.retain(|node| scene_graph.contains(*node));
+                self.hovered_all_hits.retain(|node| scene_graph.contains(*node));
+                self.pressed_hits.retain(|node| scene_graph.contains(*node));
+                if let Some(node) = self.last_click_target {
+                    if !scene_graph.contains(node) { self.last_click_target = None; }
+                }
+            }
+            pub fn get_callback_count(&self) -> usize { self.callbacks.len() }
+
+            //top-most first
+            pub fn get_hits(&self) -> &Vec<usize> { &self.hits }
+        }
+    //input
+        impl EventDispatcher {
+            pub fn pointer_move(&mut self, scene_graph:&SceneGraph, point:&Point, layer_filter:&LayerFilter) {
+                self.hits = scene_graph.hit_test(point, layer_filter);
+                self.update_hovered(scene_graph, point);
+                self.dispatch(scene_graph, CallbackType::PointerMove, &self.hits.clone(), point, &Point::new(0.0, 0.0));
+            }
+            //for when the pointer goes somewhere there's nothing to hit; out of the window, or out of every viewport
+            pub fn pointer_leave(&mut self, scene_graph:&SceneGraph, point:&Point) {
+                self.hits.clear();
+                self.pressed_hits.clear();
+                self.update_hovered(scene_graph, point);
+            }
+            pub fn pointer_down(&mut self, scene_graph:&SceneGraph, point:&Point, layer_filter:&LayerFilter) {
+                self.hits = scene_graph.hit_test(point, layer_filter);
+                self.update_hovered(scene_graph, point);
+                self.pressed_hits = self.hits.clone();
+                self.dispatch(scene_graph, CallbackType::PointerDown, &self.hits.clone(), point, &Point::new(0.0, 0.0));
+            }
+            //a click goes to those elements that were hit both when the pointer went down and now it's come up. The
+            //timing of double clicks is up to the caller (the window system has its own ideas about it), which says
+            //whether this is soon enough after the last click; if so, and the click is on the same element, a double
+            //click follows it. Returns which of the two happened, if either
+            pub fn pointer_up(&mut self, scene_graph:&SceneGraph, point:&Point, layer_filter:&LayerFilter, within_double_click_interval:bool) -> Option<CallbackType> {
+                self.hits = scene_graph.hit_test(point, layer_filter);
+                self.update_hovered(scene_graph, point);
+                self.dispatch(scene_graph, CallbackType::PointerUp, &self.hits.clone(), point, &Point::new(0.0, 0.0));
+
+                let pressed_hits = std::mem::take(&mut self.pressed_hits);
+                let clicked:Vec<usize> = self.hits.iter().filter(|hit| pressed_hits.contains(hit)).copied().collect();
+                if clicked.is_empty() {
+                    self.last_click_target = None;
+                    return None;
+                }
+                self.dispatch(scene_graph, CallbackType::Click, &clicked, point, &Point::new(0.0, 0.0));
+
+                if within_double_click_interval && self.last_click_target == Some(clicked[0]) {
+                    //so that a third click starts afresh
+                    self.last_click_target = None;
+                    self.dispatch(scene_graph, CallbackType::DoubleClick, &clicked, point, &Point::new(0.0, 0.0));
+                    Some(CallbackType::DoubleClick)
+                } else {
+                    self.last_click_target = Some(clicked[0]);
+                    Some(CallbackType::Click)
+                }
+            }
+            pub fn wheel(&mut self, scene_graph:&SceneGraph, delta:&winit::event::MouseScrollDelta, point:&Point, layer_filter:&LayerFilter) {
+                //in lines, as the camera controller has it
+                let delta = match delta {
+                    winit::event::MouseScrollDelta::LineDelta(x, y) => Point::new(*x, *y),
+                    winit::event::MouseScrollDelta::PixelDelta(position) => Point::new(position.x as f32 / 100.0, position.y as f32 / 100.0),
+                };
+
+                self.hits = scene_graph.hit_test(point, layer_filter);
+                self.update_hovered(scene_graph, point);
+                self.dispatch(scene_graph, CallbackType::Wheel, &self.hits.clone(), point, &delta);
+            }
+        }
+    //dispatch
+        impl EventDispatcher {
+            fn call(&mut self, event:&mut Event, node:usize, activation_modes:&[CallbackActivationMode]) {
+                event.set_current_target(node);
+                let (callback_type, phase) = (event.get_callback_type(), event.get_phase());
+                for callback in self.callbacks.iter_mut() {
+                    if callback.answers(node, callback_type, phase) && activation_modes.contains(&callback.activation_mode) {
+                        (callback.function)(event);
+                    }
+                }
+            }
+
+            fn dispatch(&mut self, scene_graph:&SceneGraph, callback_type:CallbackType, targets:&[usize], point:&Point, wheel_delta:&Point) {
+                if self.callbacks.is_empty() {
+                    return;
+                }
+
+                for (hit_index, target) in targets.iter().enumerate() {
+                    let activation_modes:&[CallbackActivationMode] = if hit_index == 0 {
+                        &[CallbackActivationMode::FirstHit, CallbackActivationMode::AllHits]
+                    } else {
+                        &[CallbackActivationMode::AllHits]
+                    };
+                    let path = scene_graph.get_path(*target);
+                    let (target, groups) = match path.split_last() {
+                        Some((target, groups)) if callback_type.propagates() => (*target, groups),
+                        Some((target, _)) => (*target, &[][..]),
+                        None => continue,
+                    };
+
+                    let mut event = Event::new(callback_type, target, hit_index, *point);
+                    event.set_wheel_delta(*wheel_delta);
+
+                    //capture
+                        event.set_phase(EventPhase::Capture);
+                        for node in groups {
+                            self.call(&mut event, *node, activation_modes);
+                            if event.is_propagation_stopped() { return; }
+                        }
+                    //target
+                        event.set_phase(EventPhase::Target);
+                        self.call(&mut event, target, activation_modes);
+                        if event.is_propagation_stopped() { return; }
+                    //bubble
+                        event.set_phase(EventPhase::Bubble);
+                        for node in groups.iter().rev() {
+                            self.call(&mut event, *node, activation_modes);
+                            if event.is_propagation_stopped() { return; }
+                        }
+                }
+            }
+
+            //sends leave events to the nodes the pointer's gone off (deepest first), then enter events to those it's
+            //come onto (root first), for each activation mode
+            fn update_hovered(&mut self, scene_graph:&SceneGraph, point:&Point) {
+                let hovered_first_hit = match self.hits.first() {
+                    Some(hit) => scene_graph.get_path(*hit),
+                    None => vec![],
+                };
+                let mut hovered_all_hits:Vec<usize> = vec![];
+                for hit in &self.hits {
+                    for node in scene_graph.get_path(*hit) {
+                        if !hovered_all_hits.contains(&node) {
+                            hovered_all_hits.push(node);
+                        }
+                    }
+                }
+
+                let previous_first_hit = std::mem::replace(&mut self.hovered_first_hit, hovered_first_hit.clone());
+                let previous_all_hits = std::mem::replace(&mut self.hovered_all_hits, hovered_all_hits.clone());
+                self.dispatch_hover_changes(&previous_first_hit, &hovered_first_hit, CallbackActivationMode::FirstHit, point);
+                self.dispatch_hover_changes(&previous_all_hits, &hovered_all_hits, CallbackActivationMode::AllHits, point);
+            }
+            fn dispatch_hover_changes(&mut self, previous:&[usize], current:&[usize], activation_mode:CallbackActivationMode, point:&Point) {
+                for node in previous.iter().rev().filter(|node| !current.contains(node)) {
+                    self.call(&mut Event::new(CallbackType::PointerLeave, *node, 0, *point), *node, &[activation_mode]);
+                }
+                for node in current.iter().filter(|node| !previous.contains(node)) {
+                    self.call(&mut Event::new(CallbackType::PointerEnter, *node, 0, *point), *node, &[activation_mode]);
+                }
+            }
+        }
\ No newline at end of file
@@ -0,0 +1,560 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use super::element::Element;
+use super::super::data_type::{
+    BoundingBox,
+    Offset,
+    Point,
+    PolySide,
+    RenderDecision,
+    RenderDecisionStatistics,
+    SimplePolygon,
+    Viewbox,
+};
+use super::super::viewport::LayerFilter;
+
+
+
+
+
+
+
+
+//node
+    pub enum NodeContent {
+        Group(Vec<usize>), //children, in drawing order (so the last is on top)
+        Element(Element),
+    }
+    struct Node {
+        parent: Option<usize>,
+        offset: Offset, //relative to the parent
+        visible: bool,
+        opacity: f32,
+        content: NodeContent,
+        //as of the last pass of decide_rendering (or update_world_bounding_boxes). A group's box covers everything in
+        //it (and is None if there's nothing in it). Whenever a node's box goes stale, so do those of the groups above it
+        world_bounding_box: Option<BoundingBox>,
+        world_bounding_box_stale: bool,
+        render_decision: RenderDecision,
+    }
+    impl Node {
+        fn new(parent:Option<usize>, offset:Offset, content:NodeContent) -> Node {
+            Node {
+                parent,
+                offset,
+                visible: true,
+                opacity: 1.0,
+                content,
+                world_bounding_box: None,
+                world_bounding_box_stale: true,
+                render_decision: RenderDecision::PartiallyVisible, //undecided, so drawn to be safe
+            }
+        }
+    }
+
+
+
+
+
+
+
+
+//flattened element
+    //an element as the renderer wants it; everything above it in the tree has been applied
+    pub struct FlattenedElement<'a> {
+        pub id: usize,
+        pub offset: Offset, //from the element's own space to the world
+        pub opacity: f32,
+        pub render_decision: RenderDecision, //as of the last pass of decide_rendering
+        pub element: &'a Element,
+    }
+
+
+
+
+
+
+
+
+//scene graph
+    //a tree of groups and elements. Every node has an offset relative to its parent (combined down the tree, so moving
+    //a group moves everything in it), a visibility (hiding a group hides all of it) and an opacity (multiplied down the
+    //tree, and into each element's colour; overlapping elements in a faded group show through one another). Ids stay
+    //with their nodes through moves and reordering, and aren't reused once a node is removed. The root is a group
+    pub struct SceneGraph {
+        nodes: HashMap<usize, Node>,
+        root: usize,
+        next_id: usize,
+        render_decision_statistics: RenderDecisionStatistics,
+    }
+    //new
+        impl SceneGraph {
+            pub fn new() -> SceneGraph {
+                let mut nodes = HashMap::new();
+                nodes.insert(0, Node::new(None, Offset::new_default(), NodeContent::Group(vec![])));
+
+                SceneGraph {
+                    nodes,
+                    root: 0,
+                    next_id: 1,
+                    render_decision_statistics: RenderDecisionStatistics::new(),
+                }
+            }
+        }
+    //node creation and removal
+        impl SceneGraph {
+            fn add_node(&mut self, parent:usize, offset:Offset, content:NodeContent, caller:&str) -> Option<usize> {
+                match self.nodes.get(&parent).map(|node| &node.content) {
+                    Some(NodeContent::Group(_)) => {},
+                    Some(NodeContent::Element(_)) => {
+                        println!("WARNING - SceneGraph::{} : node {} is an element, and only groups can have children; nothing added", caller, parent);
+                        return None;
+                    },
+                    None => {
+                        println!("WARNING - SceneGraph::{} : there is no node {}; nothing added", caller, parent);
+                        return None;
+                    },
+                }
+
+                let id = self.next_id;
+                self.next_id += 1;
+                self.nodes.insert(id, Node::new(Some(parent), offset, content));
+                self.get_children_mut(parent).unwrap().push(id);
+                self.mark_stale(parent);
+                Some(id)
+            }
+            //returns the new group's id, or None if the parent isn't a group
+            pub fn add_group(&mut self, parent:usize, offset:Offset) -> Option<usize> {
+                self.add_node(parent, offset, NodeContent::Group(vec![]), "add_group")
+            }
+            //returns the new element's id, or None if the parent isn't a group
+            pub fn add_element(&mut self, parent:usize, offset:Offset, element:Element) -> Option<usize> {
+                self.add_node(parent, offset, NodeContent::Element(element), "add_element")
+            }
+
+            //removes the node and (for a group) everything in it; the root can't be removed, only emptied. Callbacks added
+            //for them need removing from the EventDispatcher too (Orchestrator::remove_scene_node does both)
+            pub fn remove(&mut self, id:usize) -> bool {
+                if id == self.root {
+                    println!("WARNING - SceneGraph::remove : the root can't be removed");
+                    return false;
+                }
+                let parent = match self.nodes.get(&id) {
+                    Some(node) => node.parent.unwrap(),
+                    None => return false,
+                };
+                self.get_children_mut(parent).unwrap().retain(|child| *child != id);
+                self.mark_stale(parent);
+
+                let mut to_remove = vec![id];
+                while let Some(id) = to_remove.pop() {
+                    if let Some(Node { content: NodeContent::Group(children), .. }) = self.nodes.remove(&id) {
+                        to_remove.extend(children);
+                    }
+                }
+                true
+            }
+            pub fn clear(&mut self) {
+                let children = self.get_children(self.root).unwrap().clone();
+                for child in children {
+                    self.remove(child);
+                }
+            }
+        }
+    //structure
+        impl SceneGraph {
+            pub fn get_root(&self) -> usize { self.root }
+            pub fn contains(&self, id:usize) -> bool { self.nodes.contains_key(&id) }
+            //every node (groups and elements) other than the root
+            pub fn len(&self) -> usize { self.nodes.len() - 1 }
+            pub fn is_empty(&self) -> bool { self.nodes.len() == 1 }
+
+            pub fn get_parent(&self, id:usize) -> Option<usize> {
+                self.nodes.get(&id)?.parent
+            }
+            //None for elements (and missing nodes)
+            pub fn get_children(&self, id:usize) -> Option<&Vec<usize>> {
+                match &self.nodes.get(&id)?.content {
+                    NodeContent::Group(children) => Some(children),
+                    NodeContent::Element(_) => None,
+                }
+            }
+            fn get_children_mut(&mut self, id:usize) -> Option<&mut Vec<usize>> {
+                match &mut self.nodes.get_mut(&id)?.content {
+                    NodeContent::Group(children) => Some(children),
+                    NodeContent::Element(_) => None,
+                }
+            }
+            pub fn is_group(&self, id:usize) -> bool {
+                matches!(self.nodes.get(&id).map(|node| &node.content), Some(NodeContent::Group(_)))
+            }
+            //whether ancestor is the node itself, its parent, its parent's parent, and so on
+            pub fn is_ancestor_of(&self, ancestor:usize, id:usize) -> bool {
+                let mut current = Some(id);
+                while let Some(node) = current {
+                    if node == ancestor {
+                        return true;
+                    }
+                    current = self.get_parent(node);
+                }
+                false
+            }
+
+            //moves the node into another group, at the index among its new siblings (or on top, with None). Its
+            //local offset is kept, so it moves along with its new parent
+            pub fn reparent(&mut self, id:usize, new_parent:usize, index:Option<usize>) -> bool {
+                if id == self.root || !self.contains(id) {
+                    println!("WARNING - SceneGraph::reparent : node {} can't be moved", id);
+                    return false;
+                }
+                if !self.is_group(new_parent) {
+                    println!("WARNING - SceneGraph::reparent : node {} isn't a group", new_parent);
+                    return false;
+                }
+                if self.is_ancestor_of(id, new_parent) {
+                    println!("WARNING - SceneGraph::reparent : node {} can't be moved inside itself", id);
+                    return false;
+                }
+
+                let old_parent = self.get_parent(id).unwrap();
+                self.get_children_mut(old_parent).unwrap().retain(|child| *child != id);
+                self.mark_stale(old_parent);
+
+                let siblings = self.get_children_mut(new_parent).unwrap();
+                let index = index.unwrap_or(siblings.len()).min(siblings.len());
+                siblings.insert(index, id);
+                self.nodes.get_mut(&id).unwrap().parent = Some(new_parent);
+                self.mark_subtree_stale(id);
+                true
+            }
+            //moves the node to the index among its siblings
+            pub fn reorder(&mut self, id:usize, index:usize) -> bool {
+                let parent = match self.get_parent(id) {
+                    Some(parent) => parent,
+                    None => return false,
+                };
+                let siblings = self.get_children_mut(parent).unwrap();
+                siblings.retain(|child| *child != id);
+                let index = index.min(siblings.len());
+                siblings.insert(index, id);
+                true
+            }
+            pub fn bring_to_front(&mut self, id:usize) -> bool {
+                self.reorder(id, usize::MAX)
+            }
+            pub fn send_to_back(&mut self, id:usize) -> bool {
+                self.reorder(id, 0)
+            }
+        }
+    //node properties
+        impl SceneGraph {
+            pub fn get_offset(&self, id:usize) -> Option<&Offset> { self.nodes.get(&id).map(|node| &node.offset) }
+            pub fn get_visible(&self, id:usize) -> Option<bool> { self.nodes.get(&id).map(|node| node.visible) }
+            pub fn get_opacity(&self, id:usize) -> Option<f32> { self.nodes.get(&id).map(|node| node.opacity) }
+            pub fn get_element(&self, id:usize) -> Option<&Element> {
+                match &self.nodes.get(&id)?.content {
+                    NodeContent::Element(element) => Some(element),
+                    NodeContent::Group(_) => None,
+                }
+            }
+            //the element may well be changed, so its bounding box is taken to be stale
+            pub fn get_element_mut(&mut self, id:usize) -> Option<&mut Element> {
+                if self.get_element(id).is_some() { self.mark_stale(id); }
+                match &mut self.nodes.get_mut(&id)?.content {
+                    NodeContent::Element(element) => Some(element),
+                    NodeContent::Group(_) => None,
+                }
+            }
+
+            pub fn set_offset(&mut self, id:usize, new:Offset) {
+                if let Some(node) = self.nodes.get_mut(&id) {
+                    node.offset = new;
+                    self.mark_subtree_stale(id);
+                }
+            }
+            pub fn set_visible(&mut self, id:usize, new:bool) {
+                if let Some(node) = self.nodes.get_mut(&id) { node.visible = new; }
+            }
+            pub fn set_opacity(&mut self, id:usize, new:f32) {
+                if let Some(node) = self.nodes.get_mut(&id) { node.opacity = new.clamp(0.0, 1.0); }
+            }
+
+            //the node's offset combined with those of every group above it
+            pub fn get_world_offset(&self, id:usize) -> Option<Offset> {
+                let node = self.nodes.get(&id)?;
+                Some(match node.parent {
+                    Some(parent) => Offset::combine( &node.offset, &self.get_world_offset(parent)? ),
+                    None => node.offset.clone(),
+                })
+            }
+            //the node's opacity multiplied by that of every group above it; 0 if it, or anything above it, is hidden
+            pub fn get_world_opacity(&self, id:usize) -> Option<f32> {
+                let node = self.nodes.get(&id)?;
+                let own = if node.visible { node.opacity } else { 0.0 };
+                Some(match node.parent {
+                    Some(parent) => own * self.get_world_opacity(parent)?,
+                    None => own,
+                })
+            }
+        }
+    //world bounding boxes
+        impl SceneGraph {
+            //the node's box, and those of the groups above it, need working out again
+            fn mark_stale(&mut self, id:usize) {
+                let node = self.nodes.get_mut(&id).unwrap();
+                node.world_bounding_box_stale = true;
+
+                //a stale group's own groups are already stale, so there's no need to go further up
+                let mut current = node.parent;
+                while let Some(id) = current {
+                    let node = self.nodes.get_mut(&id).unwrap();
+                    if node.world_bounding_box_stale { break; }
+                    node.world_bounding_box_stale = true;
+                    current = node.parent;
+                }
+            }
+            //for when a node's world offset changes, which moves everything in it too
+            fn mark_subtree_stale(&mut self, id:usize) {
+                let mut to_mark = vec![id];
+                while let Some(id) = to_mark.pop() {
+                    let node = self.nodes.get_mut(&id).unwrap();
+                    node.world_bounding_box_stale = true;
+                    if let NodeContent::Group(children) = &node.content {
+                        to_mark.extend(children);
+                    }
+                }
+                self.mark_stale(id);
+            }
+
+            //as of the last update; None for empty groups (and missing nodes)
+            pub fn get_world_bounding_box(&self, id:usize) -> Option<&BoundingBox> {
+                self.nodes.get(&id)?.world_bounding_box.as_ref()
+            }
+            //works out again any boxes that have gone stale since the last update (decide_rendering does this itself)
+            pub fn update_world_bounding_boxes(&mut self) {
+                self.update_world_bounding_box(self.root, &Offset::new_default());
+            }
+            fn update_world_bounding_box(&mut self, id:usize, parent_offset:&Offset) -> Option<BoundingBox> {
+                let node = &self.nodes[&id];
+                if !node.world_bounding_box_stale {
+                    return node.world_bounding_box;
+                }
+                let offset = Offset::combine(&node.offset, parent_offset);
+
+                let world_bounding_box = match &node.content {
+                    NodeContent::Element(element) => {
+                        Some( *element.get_local_polygon().get_with_offset_applied(&offset).get_bounding_box() )
+                    },
+                    NodeContent::Group(children) => {
+                        let mut corners = vec![];
+                        for child in children.clone() {
+                            if let Some(bounding_box) = self.update_world_bounding_box(child, &offset) {
+                                corners.push(*bounding_box.get_top_left());
+                                corners.push(*bounding_box.get_bottom_right());
+                            }
+                        }
+                        if corners.is_empty() { None } else { Some(BoundingBox::new_from_points(&corners)) }
+                    },
+                };
+
+                let node = self.nodes.get_mut(&id).unwrap();
+                node.world_bounding_box = world_bounding_box;
+                node.world_bounding_box_stale = false;
+                world_bounding_box
+            }
+        }
+    //render decisions
+        impl SceneGraph {
+            //as of the last pass of decide_rendering; nodes added since are PartiallyVisible
+            pub fn get_render_decision(&self, id:usize) -> Option<RenderDecision> {
+                self.nodes.get(&id).map(|node| node.render_decision)
+            }
+            pub fn get_render_decision_statistics(&self) -> &RenderDecisionStatistics {
+                &self.render_decision_statistics
+            }
+
+            //decides, for every node, whether it's fully visible, partially visible or culled from the view. Groups are
+            //decided first from their bounding boxes; everything in a culled group is culled (and everything in a fully
+            //visible one is fully visible) without being looked at. Hidden and fully transparent nodes are culled
+            pub fn decide_rendering(&mut self, viewbox:&Viewbox) -> &RenderDecisionStatistics {
+                self.update_world_bounding_boxes();
+
+                let mut statistics = RenderDecisionStatistics::new();
+                self.decide_node_rendering(self.root, viewbox.get_polygon(), &Offset::new_default(), None, false, &mut statistics);
+                self.render_decision_statistics = statistics;
+                &self.render_decision_statistics
+            }
+            //decided is Some when a group above has already decided for everything in it
+            fn decide_node_rendering(&mut self, id:usize, view:&SimplePolygon, parent_offset:&Offset, decided:Option<RenderDecision>, hidden_above:bool, statistics:&mut RenderDecisionStatistics) {
+                let node = &self.nodes[&id];
+                let hidden = hidden_above || !node.visible || node.opacity <= 0.0;
+                let offset = Offset::combine(&node.offset, parent_offset);
+
+                let decision = match &node.content {
+                    NodeContent::Group(children) => {
+                        let decision = match decided {
+                            _ if hidden => RenderDecision::Culled,
+                            Some(decision) => decision,
+                            None => {
+                                let decision = match &node.world_bounding_box {
+                                    Some(bounding_box) => RenderDecision::decide_for_bounding_box(view, bounding_box),
+                                    None => RenderDecision::Culled,
+                                };
+                                if decision == RenderDecision::Culled && !children.is_empty() {
+                                    statistics.record_culled_group();
+                                }
+                                decision
+                            },
+                        };
+
+                        let children_decided = match decision {
+                            RenderDecision::PartiallyVisible => None,
+                            decision => Some(decision),
+                        };
+                        for child in children.clone() {
+                            self.decide_node_rendering(child, view, &offset, children_decided, hidden, statistics);
+                        }
+                        decision
+                    },
+                    NodeContent::Element(element) => {
+                        match decided {
+                            _ if hidden => {
+                                statistics.record_hidden_element();
+                                RenderDecision::Culled
+                            },
+                            Some(decision) => {
+                                statistics.record_element(decision, false);
+                                decision
+                            },
+                            None => {
+                                let decision = match RenderDecision::decide_for_bounding_box(view, node.world_bounding_box.as_ref().unwrap()) {
+                                    RenderDecision::PartiallyVisible => {
+                                        RenderDecision::decide_for_simple_polygon(view, &element.get_local_polygon().get_with_offset_applied(&offset))
+                                    },
+                                    decision => decision,
+                                };
+                                statistics.record_element(decision, true);
+                                decision
+                            },
+                        }
+                    },
+                };
+
+                self.nodes.get_mut(&id).unwrap().render_decision = decision;
+            }
+        }
+    //flattening
+        impl SceneGraph {
+            //every visible element in drawing order (depth first, each group's children in order), with its offset and
+            //opacity worked out. Hidden and fully transparent groups are skipped without looking inside them
+            pub fn flatten(&self) -> Vec<FlattenedElement<'_>> {
+                let mut output = vec![];
+                self.flatten_node(self.root, &Offset::new_default(), 1.0, false, &mut output);
+                output
+            }
+            //as flatten, but also skipping whatever the last pass of decide_rendering culled (whole groups at a time)
+            pub fn flatten_rendered(&self) -> Vec<FlattenedElement<'_>> {
+                let mut output = vec![];
+                self.flatten_node(self.root, &Offset::new_default(), 1.0, true, &mut output);
+                output
+            }
+            fn flatten_node<'a>(&'a self, id:usize, parent_offset:&Offset, parent_opacity:f32, skip_culled:bool, output:&mut Vec<FlattenedElement<'a>>) {
+                let node = &self.nodes[&id];
+                let opacity = parent_opacity * node.opacity;
+                if !node.visible || opacity <= 0.0 || (skip_culled && !node.render_decision.should_render()) {
+                    return;
+                }
+                let offset = Offset::combine(&node.offset, parent_offset);
+
+                match &node.content {
+                    NodeContent::Group(children) => {
+                        for child in children {
+                            self.flatten_node(*child, &offset, opacity, skip_culled, output);
+                        }
+                    },
+                    NodeContent::Element(element) => {
+                        output.push(FlattenedElement { id, offset, opacity, render_decision: node.render_decision, element });
+                    },
+                }
+            }
+        }
+    //hit testing
+        impl SceneGraph {
+            //every visible element that the world point is on (within its shape, or on the edge), top-most first,
+            //leaving out those on layers the filter doesn't include. Subtrees whose world bounding box (as of the last
+            //update) doesn't contain the point are skipped without looking inside them; stale boxes can't be trusted,
+            //so those subtrees are looked through as usual
+            pub fn hit_test(&self, point:&Point, layer_filter:&LayerFilter) -> Vec<usize> {
+                let mut output = vec![];
+                self.hit_test_node(self.root, point, layer_filter, &Offset::new_default(), 1.0, &mut output);
+                output.reverse();
+                output
+            }
+            //in drawing order, as flatten_node
+            fn hit_test_node(&self, id:usize, point:&Point, layer_filter:&LayerFilter, parent_offset:&Offset, parent_opacity:f32, output:&mut Vec<usize>) {
+                let node = &self.nodes[&id];
+                let opacity = parent_opacity * node.opacity;
+                if !node.visible || opacity <= 0.0 {
+                    return;
+                }
+                if !node.world_bounding_box_stale {
+                    match &node.world_bounding_box {
+                        Some(bounding_box) if bounding_box.intersect_with_point(point) => {},
+                        _ => return,
+                    }
+                }
+                let offset = Offset::combine(&node.offset, parent_offset);
+
+                match &node.content {
+                    NodeContent::Group(children) => {
+                        for child in children {
+                            self.hit_test_node(*child, point, layer_filter, &offset, opacity, output);
+                        }
+                    },
+                    NodeContent::Element(element) => {
+                        if !layer_filter.includes(element.get_layer()) {
+                            return;
+                        }
+                        let polygon = element.get_local_polygon().get_with_offset_applied(&offset);
+                        if point.intersect_with_simple_polygon(&polygon) != PolySide::Outside {
+                            output.push(id);
+                        }
+                    },
+                }
+            }
+            //the node, and every group above it, from the root down
+            pub fn get_path(&self, id:usize) -> Vec<usize> {
+                let mut output = vec![];
+                let mut current = if self.contains(id) { Some(id) } else { None };
+                while let Some(node) = current {
+                    output.push(node);
+                    current = self.get_parent(node);
+                }
+                output.reverse();
+                output
+            }
+        }
+    //printing
+        impl SceneGraph {
+            fn fmt_node(&self, f:&mut fmt::Formatter, id:usize, depth:usize) -> fmt::Result {
+                let node = &self.nodes[&id];
+                write!(f, "{}{} offset:{} visible:{} opacity:{} render_decision:{}", "  ".repeat(depth), id, node.offset, node.visible, node.opacity, node.render_decision)?;
+                match &node.content {
+                    NodeContent::Group(children) => {
+                        writeln!(f, " group")?;
+                        for child in children {
+                            self.fmt_node(f, *child, depth + 1)?;
+                        }
+                        Ok(())
+                    },
+                    NodeContent::Element(element) => writeln!(f, " element:{}", element),
+                }
+            }
+        }
+        impl fmt::Display for SceneGraph {
+            fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { self.fmt_node(f, self.root, 0) }
+        }
+        impl fmt::Debug for SceneGraph {
+            fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { fmt::Display::fmt(self, f) }
+        }
\ No newline at end of file
@@ -0,0 +1,6 @@
+mod element;
+pub use element::{*};
+mod event_dispatcher;
+pub use event_dispatcher::{*};
+mod graph;
+pub use graph::{*};
\ No newline at end of file
@@ -0,0 +1,99 @@
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use ab_glyph::{Font as _, ScaleFont as _};
+
+//every font loaded gets its own id, so that glyph atlases can tell them apart
+static NEXT_FONT_ID:AtomicUsize = AtomicUsize::new(0);
+
+
+
+
+
+
+
+
+//a TrueType or OpenType font. Sizes are em sizes, as with CSS, in whatever units the text is laid out in; the
+//metrics below are all scaled to the size given. Cloning is cheap (the font's data is shared), and clones keep the id
+pub struct Font {
+    id: usize,
+    font: ab_glyph::FontArc,
+}
+//new
+    impl Font {
+        pub fn new_from_bytes(bytes:Vec<u8>) -> Option<Font> {
+            match ab_glyph::FontArc::try_from_vec(bytes) {
+                Ok(font) => Some(Font {
+                    id: NEXT_FONT_ID.fetch_add(1, Ordering::Relaxed),
+                    font,
+                }),
+                Err(error) => {
+                    println!("WARNING - Font::new_from_bytes : the font couldn't be read ({})", error);
+                    None
+                },
+            }
+        }
+        pub fn new_from_file(path:&str) -> Option<Font> {
+            match std::fs::read(path) {
+                Ok(bytes) => Font::new_from_bytes(bytes),
+                Err(error) => {
+                    println!("WARNING - Font::new_from_file : couldn't read \"{}\" ({})", path, error);
+                    None
+                },
+            }
+        }
+        pub fn clone(&self) -> Font {
+            Font {
+                id: self.id,
+                font: self.font.clone(),
+            }
+        }
+
+        //a tiny font for tests, with 1000 units to the em: an ascent of 800 and a descent of 200, no line gap and no
+        //kerning. Every printable ASCII character advances 500; the space is empty and the rest are all the same box,
+        //from 50 to 450 across and 0 to 700 up
+        #[cfg(test)]
+        pub(super) fn new_for_tests() -> Font {
+            Font::new_from_bytes( include_bytes!("test_font.ttf").to_vec() ).unwrap()
+        }
+    }
+//getters
+    impl Font {
+        pub fn get_id(&self) -> usize { self.id }
+        pub(super) fn get_ab_glyph_font(&self) -> &ab_glyph::FontArc { &self.font }
+
+        //ab_glyph scales by the height from descent to ascent, rather than by the em
+        pub(super) fn get_scale(&self, size:f32) -> ab_glyph::PxScale {
+            let units_per_em = self.font.units_per_em().unwrap_or_else(|| self.font.height_unscaled());
+            ab_glyph::PxScale::from( size * self.font.height_unscaled() / units_per_em )
+        }
+    }
+//metrics
+    impl Font {
+        //above the baseline
+        pub fn get_ascent(&self, size:f32) -> f32 { self.font.as_scaled(self.get_scale(size)).ascent() }
+        //below the baseline, so usually negative
+        pub fn get_descent(&self, size:f32) -> f32 { self.font.as_scaled(self.get_scale(size)).descent() }
+        //the extra space the font asks for between one line's descent and the next one's ascent
+        pub fn get_line_gap(&self, size:f32) -> f32 { self.font.as_scaled(self.get_scale(size)).line_gap() }
+
+        //characters the font has nothing for give 0, which is drawn as the font's "missing" glyph
+        pub fn get_glyph_id(&self, character:char) -> u16 { self.font.glyph_id(character).0 }
+        pub fn has_glyph(&self, character:char) -> bool { self.get_glyph_id(character) != 0 }
+        pub fn get_advance(&self, glyph_id:u16, size:f32) -> f32 {
+            self.font.as_scaled(self.get_scale(size)).h_advance(ab_glyph::GlyphId(glyph_id))
+        }
+        //the adjustment to the advance between the two glyphs (usually negative, pulling them together)
+        pub fn get_kerning(&self, first_glyph_id:u16, second_glyph_id:u16, size:f32) -> f32 {
+            self.font.as_scaled(self.get_scale(size)).kern(ab_glyph::GlyphId(first_glyph_id), ab_glyph::GlyphId(second_glyph_id))
+        }
+    }
+//printing
+    impl fmt::Display for Font {
+        fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result {
+            write!(f, "{{id:{}, glyph_count:{}}}", self.id, self.font.glyph_count())
+        }
+    }
+    impl fmt::Debug for Font {
+        fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { fmt::Display::fmt(self, f) }
+    }
\ No newline at end of file
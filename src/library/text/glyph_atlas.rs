@@ -0,0 +1,239 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use ab_glyph::Font as _;
+
+use super::font::Font;
+use super::super::atlas_packer::AtlasPacker;
+use super::super::data_type::{
+    BoundingBox,
+    Point,
+};
+
+
+
+
+
+
+
+
+//atlas glyph
+    //where a rasterised glyph is in the atlas, and how to place it. offset is from the glyph's origin (on the
+    //baseline) to the top left of its image, in pixels
+    #[derive(Copy, Clone, Debug)]
+    pub struct AtlasGlyph {
+        pub uv: BoundingBox, //from 0 to 1 across the atlas
+        pub width: u32,
+        pub height: u32,
+        pub offset: Point,
+    }
+
+
+
+
+
+
+
+
+//rasterised glyph
+    //what rasterising came to. Glyphs with nothing to draw are remembered as such, but those that didn't fit aren't, so
+    //that they're tried again once there's room (after a clear)
+    enum RasterisedGlyph {
+        Drawn(AtlasGlyph),
+        Empty,
+        DidNotFit,
+    }
+
+
+
+
+
+
+
+
+//glyph key
+    #[derive(Copy, Clone, PartialEq, Eq, Hash)]
+    struct GlyphKey {
+        font_id: usize,
+        glyph_id: u16,
+        size: u32, //in quarter pixels, so that near enough sizes share
+    }
+
+
+
+
+
+
+
+
+//glyph atlas
+    //a single channel image holding rasterised glyphs, packed in (a pixel apart) as they're asked for. Sizes are in
+    //pixels, so text drawn larger or smaller on screen needs its glyphs at that size. Once full, glyphs that don't fit
+    //are left out (with a warning), and tried again the next time they're asked for; clearing the atlas and starting
+    //again is up to the user. The revision changes whenever the pixels do, for knowing when to upload them again
+    pub struct GlyphAtlas {
+        width: u32,
+        height: u32,
+        pixels: Vec<u8>,
+        glyphs: HashMap<GlyphKey, Option<AtlasGlyph>>, //None for glyphs with nothing to draw (spaces)
+        packer: AtlasPacker, //a single page that doesn't grow, as the pixels don't
+        revision: usize,
+    }
+    //new
+        impl GlyphAtlas {
+            pub fn new(width:u32, height:u32) -> GlyphAtlas {
+                GlyphAtlas {
+                    width,
+                    height,
+                    pixels: vec![0; (width * height) as usize],
+                    glyphs: HashMap::new(),
+                    packer: AtlasPacker::new(width, height, width, height, 1, 1, false),
+                    revision: 0,
+                }
+            }
+        }
+    //getters
+        impl GlyphAtlas {
+            pub fn get_width(&self) -> u32 { self.width }
+            pub fn get_height(&self) -> u32 { self.height }
+            //a byte per pixel, row by row from the top
+            pub fn get_pixels(&self) -> &Vec<u8> { &self.pixels }
+            pub fn get_glyph_count(&self) -> usize { self.glyphs.len() }
+            pub fn get_revision(&self) -> usize { self.revision }
+        }
+    //glyphs
+        impl GlyphAtlas {
+            //rasterises the glyph if it's not already in the atlas
+            pub fn get_glyph(&mut self, font:&Font, glyph_id:u16, size:f32) -> Option<AtlasGlyph> {
+                let key = GlyphKey {
+                    font_id: font.get_id(),
+                    glyph_id,
+                    size: (size * 4.0).round().max(0.0) as u32,
+                };
+                if let Some(glyph) = self.glyphs.get(&key) {
+                    return *glyph;
+                }
+
+                match self.rasterise(font, glyph_id, key.size as f32 / 4.0) {
+                    RasterisedGlyph::Drawn(glyph) => {
+                        self.glyphs.insert(key, Some(glyph));
+                        Some(glyph)
+                    },
+                    RasterisedGlyph::Empty => {
+                        self.glyphs.insert(key, None);
+                        None
+                    },
+                    RasterisedGlyph::DidNotFit => None,
+                }
+            }
+
+            fn rasterise(&mut self, font:&Font, glyph_id:u16, size:f32) -> RasterisedGlyph {
+                let outlined = match font.get_ab_glyph_font().outline_glyph(
+                    ab_glyph::GlyphId(glyph_id).with_scale_and_position(font.get_scale(size), ab_glyph::point(0.0, 0.0))
+                ) {
+                    Some(outlined) => outlined,
+                    None => return RasterisedGlyph::Empty,
+                };
+                let bounds = outlined.px_bounds();
+                let width = bounds.width() as u32;
+                let height = bounds.height() as u32;
+                if width == 0 || height == 0 {
+                    return RasterisedGlyph::Empty;
+                }
+
+                //linear sampling would pick up neighbouring glyphs, were they not kept apart. (The packer warns when
+                //it's full)
+                let (x, y) = match self.packer.insert(width, height).and_then(|id| self.packer.get_rectangle(id)) {
+                    Some(rectangle) => (rectangle.x, rectangle.y),
+                    None => return RasterisedGlyph::DidNotFit,
+                };
+
+                let atlas_width = self.width;
+                let pixels = &mut self.pixels;
+                outlined.draw(|glyph_x, glyph_y, coverage| {
+                    let index = ((y + glyph_y) * atlas_width + x + glyph_x) as usize;
+                    pixels[index] = (coverage.clamp(0.0, 1.0) * 255.0).round() as u8;
+                });
+                self.revision += 1;
+
+                RasterisedGlyph::Drawn(AtlasGlyph {
+                    uv: BoundingBox::new(
+                        x as f32 / self.width as f32,
+                        y as f32 / self.height as f32,
+                        (x + width) as f32 / self.width as f32,
+                        (y + height) as f32 / self.height as f32,
+                    ),
+                    width,
+                    height,
+                    offset: Point::new(bounds.min.x, bounds.min.y),
+                })
+            }
+
+            pub fn clear(&mut self) {
+                self.pixels.iter_mut().for_each(|pixel| *pixel = 0);
+                self.glyphs.clear();
+                self.packer.clear();
+                self.revision += 1;
+            }
+        }
+    //printing
+        impl fmt::Display for GlyphAtlas {
+            fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result {
+                write!(f, "{{width:{}, height:{}, glyph_count:{}, revision:{}}}", self.width, self.height, self.glyphs.len(), self.revision)
+            }
+        }
+        impl fmt::Debug for GlyphAtlas {
+            fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { fmt::Display::fmt(self, f) }
+        }
+
+
+
+
+
+
+
+//tests
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn remembers_glyphs_with_nothing_to_draw() {
+            let font = Font::new_for_tests();
+            let mut atlas = GlyphAtlas::new(16, 16);
+
+            assert!(atlas.get_glyph(&font, font.get_glyph_id(' '), 10.0).is_none());
+            assert_eq!(atlas.get_glyph_count(), 1);
+            assert_eq!(atlas.get_revision(), 0);
+        }
+
+        #[test]
+        fn reuses_rasterised_glyphs() {
+            let font = Font::new_for_tests();
+            let mut atlas = GlyphAtlas::new(16, 16);
+
+            let first = atlas.get_glyph(&font, font.get_glyph_id('a'), 10.0).unwrap();
+            let revision = atlas.get_revision();
+            let second = atlas.get_glyph(&font, font.get_glyph_id('b'), 10.0).unwrap(); //the same box glyph
+            assert_eq!(atlas.get_glyph_count(), 1);
+            assert_eq!(atlas.get_revision(), revision);
+            assert_eq!((first.width, first.height, first.uv.get_top_left().get_x()), (second.width, second.height, second.uv.get_top_left().get_x()));
+            assert!(atlas.get_pixels().contains(&255));
+        }
+
+        #[test]
+        fn tries_glyphs_that_did_not_fit_again() {
+            let font = Font::new_for_tests();
+            let glyph_id = font.get_glyph_id('a');
+            //room for the glyph at 10 (5 by 7) or at 12 (6 by 9), but not for both
+            let mut atlas = GlyphAtlas::new(12, 12);
+
+            assert!(atlas.get_glyph(&font, glyph_id, 10.0).is_some());
+            assert!(atlas.get_glyph(&font, glyph_id, 12.0).is_none());
+            assert_eq!(atlas.get_glyph_count(), 1);
+
+            atlas.clear();
+            assert!(atlas.get_glyph(&font, glyph_id, 12.0).is_some());
+            assert_eq!(atlas.get_glyph_count(), 1);
+        }
+    }
@@ -0,0 +1,425 @@
+use std::fmt;
+
+use super::font::Font;
+use super::super::data_type::{
+    BoundingBox,
+    Point,
+    PrintingModePosition,
+};
+
+
+
+
+
+
+
+
+//alignment
+    //how lines shorter than the text's width sit within it
+    #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+    pub enum TextAlignment {
+        Left,
+        Centre,
+        Right,
+    }
+    impl TextAlignment {
+        fn get_fraction(&self) -> f32 {
+            match self {
+                TextAlignment::Left => 0.0,
+                TextAlignment::Centre => 0.5,
+                TextAlignment::Right => 1.0,
+            }
+        }
+    }
+    impl fmt::Display for TextAlignment {
+        fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { fmt::Debug::fmt(self, f) }
+    }
+
+
+
+
+
+
+
+
+//style
+    #[derive(Copy, Clone, Debug)]
+    pub struct TextStyle {
+        size: f32, //the em size
+        line_height: f32, //as a multiple of the font's own line spacing
+        alignment: TextAlignment,
+        wrap_width: Option<f32>, //lines are broken (between words where possible) to fit within this
+        position: PrintingModePosition, //which point of the text is put at the layout's origin
+    }
+    //new
+        impl TextStyle {
+            pub fn new(size:f32, line_height:f32, alignment:TextAlignment, wrap_width:Option<f32>, position:PrintingModePosition) -> TextStyle {
+                let mut style = TextStyle::new_default();
+                style.set_size(size);
+                style.set_line_height(line_height);
+                style.set_alignment(alignment);
+                style.set_wrap_width(wrap_width);
+                style.set_position(position);
+                style
+            }
+            pub fn new_default() -> TextStyle {
+                TextStyle {
+                    size: 16.0,
+                    line_height: 1.0,
+                    alignment: TextAlignment::Left,
+                    wrap_width: None,
+                    position: PrintingModePosition::TopLeft,
+                }
+            }
+        }
+    //getters
+        impl TextStyle {
+            pub fn get_size(&self) -> f32 { self.size }
+            pub fn get_line_height(&self) -> f32 { self.line_height }
+            pub fn get_alignment(&self) -> TextAlignment { self.alignment }
+            pub fn get_wrap_width(&self) -> Option<f32> { self.wrap_width }
+            pub fn get_position(&self) -> PrintingModePosition { self.position }
+        }
+    //setters
+        impl TextStyle {
+            pub fn set_size(&mut self, new:f32) {
+                if new.is_nan() || new <= 0.0 {
+                    println!("WARNING - TextStyle::set_size : the size must be above 0 (was given {}); ignoring it", new);
+                    return;
+                }
+                self.size = new;
+            }
+            pub fn set_line_height(&mut self, new:f32) { self.line_height = new; }
+            pub fn set_alignment(&mut self, new:TextAlignment) { self.alignment = new; }
+            pub fn set_wrap_width(&mut self, new:Option<f32>) {
+                self.wrap_width = match new {
+                    Some(width) if width.is_nan() || width <= 0.0 => {
+                        println!("WARNING - TextStyle::set_wrap_width : the width must be above 0 (was given {}); not wrapping", width);
+                        None
+                    },
+                    _ => new,
+                };
+            }
+            pub fn set_position(&mut self, new:PrintingModePosition) { self.position = new; }
+        }
+
+
+
+
+
+
+
+
+//positioned glyph
+    pub struct PositionedGlyph {
+        pub character: char,
+        pub glyph_id: u16,
+        pub position: Point, //the glyph's origin, on the baseline
+        pub advance: f32,
+        pub line: usize,
+    }
+
+
+
+
+
+
+
+
+//line
+    pub struct TextLine {
+        pub first_glyph: usize,
+        pub glyph_count: usize,
+        pub left: f32,
+        pub width: f32, //not counting any spaces at the end
+        pub baseline: f32,
+    }
+
+
+
+
+
+
+
+
+//text layout
+    //the text broken into lines and placed glyph by glyph, with the style's position (say, the centre of the first
+    //baseline) at the origin and y going down, in the same units as the style's size. Line breaks ("\n") are kept;
+    //with a wrap width, lines are also broken after spaces, or within a word where the word alone is too long.
+    //Shaping is simple: each character is its own glyph, with kerning between neighbours, which is fine for Latin
+    //text but not for scripts that join letters or run right to left
+    pub struct TextLayout {
+        glyphs: Vec<PositionedGlyph>,
+        lines: Vec<TextLine>,
+        bounding_box: BoundingBox,
+        font_id: usize,
+        size: f32,
+    }
+    //new
+        impl TextLayout {
+            pub fn new(font:&Font, text:&str, style:&TextStyle) -> TextLayout {
+                let size = style.get_size();
+                let characters:Vec<char> = text.chars()
+                    .filter(|character| *character != '\r')
+                    .map(|character| if character == '\t' { ' ' } else { character })
+                    .collect();
+
+                //break into lines
+                    let mut line_ranges = vec![];
+                    let mut paragraph_start = 0;
+                    for (index, character) in characters.iter().enumerate().chain(std::iter::once((characters.len(), &'\n'))) {
+                        if *character == '\n' {
+                            TextLayout::break_paragraph(font, size, &characters, paragraph_start, index, style.get_wrap_width(), &mut line_ranges);
+                            paragraph_start = index + 1;
+                        }
+                    }
+
+                //lay out each line from the left, with the first baseline at the ascent
+                    let ascent = font.get_ascent(size);
+                    let descent = font.get_descent(size);
+                    let line_advance = (ascent - descent + font.get_line_gap(size)) * style.get_line_height();
+
+                    let mut glyphs = vec![];
+                    let mut lines = vec![];
+                    for (line_index, (start, end)) in line_ranges.iter().enumerate() {
+                        let baseline = ascent + line_advance * line_index as f32;
+                        let first_glyph = glyphs.len();
+                        let mut pen = 0.0;
+                        let mut width = 0.0;
+                        let mut previous:Option<u16> = None;
+                        for character in &characters[*start..*end] {
+                            let glyph_id = font.get_glyph_id(*character);
+                            if let Some(previous) = previous {
+                                pen += font.get_kerning(previous, glyph_id, size);
+                            }
+                            let advance = font.get_advance(glyph_id, size);
+                            glyphs.push(PositionedGlyph { character: *character, glyph_id, position: Point::new(pen, baseline), advance, line: line_index });
+                            pen += advance;
+                            if !character.is_whitespace() { width = pen; }
+                            previous = Some(glyph_id);
+                        }
+                        lines.push(TextLine { first_glyph, glyph_count: glyphs.len() - first_glyph, left: 0.0, width, baseline });
+                    }
+
+                //align the lines within the wrap width (or the widest line, without one)
+                    let alignment_width = style.get_wrap_width().unwrap_or_else(|| lines.iter().fold(0.0, |widest, line| line.width.max(widest)));
+                    for line in &mut lines {
+                        line.left = (alignment_width - line.width) * style.get_alignment().get_fraction();
+                        for glyph in &mut glyphs[line.first_glyph..line.first_glyph + line.glyph_count] {
+                            glyph.position.translate(line.left, 0.0);
+                        }
+                    }
+
+                //move the style's position onto the origin
+                    let height = ascent - descent + line_advance * (lines.len() - 1) as f32;
+                    let origin = Point::new(
+                        alignment_width * style.get_position().get_horizontal_fraction(),
+                        style.get_position().get_vertical_position(0.0, ascent, height),
+                    );
+                    for glyph in &mut glyphs {
+                        glyph.position.translate(-origin.get_x(), -origin.get_y());
+                    }
+                    for line in &mut lines {
+                        line.left -= origin.get_x();
+                        line.baseline -= origin.get_y();
+                    }
+
+                    let left = lines.iter().fold(f32::INFINITY, |left, line| line.left.min(left));
+                    let right = lines.iter().fold(f32::NEG_INFINITY, |right, line| (line.left + line.width).max(right));
+                    let bounding_box = BoundingBox::new(left, -origin.get_y(), right, height - origin.get_y());
+
+                TextLayout {
+                    glyphs,
+                    lines,
+                    bounding_box,
+                    font_id: font.get_id(),
+                    size,
+                }
+            }
+
+            //adds the lines (as ranges of characters) that the paragraph between start and end breaks into
+            fn break_paragraph(font:&Font, size:f32, characters:&[char], start:usize, end:usize, wrap_width:Option<f32>, line_ranges:&mut Vec<(usize, usize)>) {
+                let wrap_width = match wrap_width {
+                    Some(wrap_width) => wrap_width,
+                    None => {
+                        line_ranges.push((start, end));
+                        return;
+                    },
+                };
+                let measure = |start:usize, end:usize| -> f32 {
+                    let mut width = 0.0;
+                    let mut previous:Option<u16> = None;
+                    for character in &characters[start..end] {
+                        let glyph_id = font.get_glyph_id(*character);
+                        if let Some(previous) = previous { width += font.get_kerning(previous, glyph_id, size); }
+                        width += font.get_advance(glyph_id, size);
+                        previous = Some(glyph_id);
+                    }
+                    width
+                };
+
+                let mut line_start = start;
+                let mut last_break:Option<usize> = None; //just after the most recent space
+                let mut width = 0.0;
+                let mut previous:Option<u16> = None;
+                for index in start..end {
+                    let character = characters[index];
+                    let glyph_id = font.get_glyph_id(character);
+                    let mut advance = previous.map_or(0.0, |previous| font.get_kerning(previous, glyph_id, size)) + font.get_advance(glyph_id, size);
+
+                    //spaces can always hang off the end of a line
+                    if !character.is_whitespace() && index > line_start && width + advance > wrap_width {
+                        let break_at = match last_break {
+                            Some(last_break) if last_break > line_start => last_break,
+                            _ => index,
+                        };
+                        line_ranges.push((line_start, break_at));
+                        line_start = break_at;
+                        last_break = None;
+
+                        width = measure(line_start, index);
+                        previous = if index > line_start { Some(font.get_glyph_id(characters[index - 1])) } else { None };
+                        advance = previous.map_or(0.0, |previous| font.get_kerning(previous, glyph_id, size)) + font.get_advance(glyph_id, size);
+                    }
+
+                    width += advance;
+                    previous = Some(glyph_id);
+                    if character.is_whitespace() {
+                        last_break = Some(index + 1);
+                    }
+                }
+                line_ranges.push((line_start, end));
+            }
+        }
+    //getters
+        impl TextLayout {
+            pub fn get_glyphs(&self) -> &Vec<PositionedGlyph> { &self.glyphs }
+            pub fn get_lines(&self) -> &Vec<TextLine> { &self.lines }
+            //from the top of the first line to the bottom of the last, and across the lines' widths
+            pub fn get_bounding_box(&self) -> &BoundingBox { &self.bounding_box }
+            pub fn get_font_id(&self) -> usize { self.font_id }
+            pub fn get_size(&self) -> f32 { self.size }
+        }
+    //printing
+        impl TextLayout {
+            fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result {
+                write!(f, "{{font_id:{}, size:{}, bounding_box:{}, lines:[", self.font_id, self.size, self.bounding_box)?;
+                for (index, line) in self.lines.iter().enumerate() {
+                    let text:String = self.glyphs[line.first_glyph..line.first_glyph + line.glyph_count].iter().map(|glyph| glyph.character).collect();
+                    write!(f, "{}{{text:{:?}, left:{}, width:{}, baseline:{}}}", if index == 0 { "" } else { ", " }, text, line.left, line.width, line.baseline)?;
+                }
+                write!(f, "]}}")
+            }
+        }
+        impl fmt::Display for TextLayout {
+            fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { self.fmt(f) }
+        }
+        impl fmt::Debug for TextLayout {
+            fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { self.fmt(f) }
+        }
+
+
+
+
+
+
+
+//tests
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        //at a size of 10 the test font's characters all advance 5, with an ascent of 8 and a descent of 2, so lines
+        //are 10 apart
+        fn lay_out(text:&str, alignment:TextAlignment, wrap_width:Option<f32>, position:PrintingModePosition) -> TextLayout {
+            TextLayout::new(&Font::new_for_tests(), text, &TextStyle::new(10.0, 1.0, alignment, wrap_width, position))
+        }
+        fn line_texts(layout:&TextLayout) -> Vec<String> {
+            layout.get_lines().iter().map(|line| {
+                layout.get_glyphs()[line.first_glyph..line.first_glyph + line.glyph_count].iter().map(|glyph| glyph.character).collect()
+            }).collect()
+        }
+
+        #[test]
+        fn places_glyphs_along_the_first_baseline() {
+            let layout = lay_out("ab cd", TextAlignment::Left, None, PrintingModePosition::TopLeft);
+            assert_eq!(line_texts(&layout), vec!["ab cd"]);
+
+            let positions:Vec<(f32, f32)> = layout.get_glyphs().iter().map(|glyph| (glyph.position.get_x(), glyph.position.get_y())).collect();
+            assert_eq!(positions, vec![(0.0, 8.0), (5.0, 8.0), (10.0, 8.0), (15.0, 8.0), (20.0, 8.0)]);
+            assert_eq!(layout.get_lines()[0].width, 25.0);
+        }
+
+        #[test]
+        fn keeps_line_breaks() {
+            let layout = lay_out("ab\r\n\ncd\te", TextAlignment::Left, None, PrintingModePosition::TopLeft);
+            assert_eq!(line_texts(&layout), vec!["ab", "", "cd e"]);
+
+            let baselines:Vec<f32> = layout.get_lines().iter().map(|line| line.baseline).collect();
+            assert_eq!(baselines, vec![8.0, 18.0, 28.0]);
+        }
+
+        #[test]
+        fn wraps_between_words() {
+            let layout = lay_out("ab cd ef", TextAlignment::Left, Some(22.0), PrintingModePosition::TopLeft);
+            assert_eq!(line_texts(&layout), vec!["ab ", "cd ", "ef"]);
+
+            let glyph = &layout.get_glyphs()[3];
+            assert_eq!((glyph.character, glyph.line, glyph.position.get_x(), glyph.position.get_y()), ('c', 1, 0.0, 18.0));
+        }
+
+        #[test]
+        fn breaks_words_too_long_for_a_line() {
+            let layout = lay_out("abcdefgh", TextAlignment::Left, Some(22.0), PrintingModePosition::TopLeft);
+            assert_eq!(line_texts(&layout), vec!["abcd", "efgh"]);
+
+            //a long word after a short one starts a line of its own before being broken
+            let layout = lay_out("ab cdefghij", TextAlignment::Left, Some(22.0), PrintingModePosition::TopLeft);
+            assert_eq!(line_texts(&layout), vec!["ab ", "cdef", "ghij"]);
+        }
+
+        #[test]
+        fn lets_spaces_hang_off_the_end_of_a_line() {
+            let layout = lay_out("ab   cd", TextAlignment::Left, Some(12.0), PrintingModePosition::TopLeft);
+            assert_eq!(line_texts(&layout), vec!["ab   ", "cd"]);
+            assert_eq!(layout.get_lines()[0].width, 10.0);
+
+            //and they aren't counted when aligning
+            let layout = lay_out("ab   cd", TextAlignment::Right, Some(12.0), PrintingModePosition::TopLeft);
+            assert_eq!(layout.get_lines()[0].left, 2.0);
+            assert_eq!(layout.get_glyphs()[0].position.get_x(), 2.0);
+        }
+
+        #[test]
+        fn aligns_lines_within_the_widest() {
+            let lefts = |alignment:TextAlignment| -> Vec<f32> {
+                lay_out("ab\nabcd", alignment, None, PrintingModePosition::TopLeft).get_lines().iter().map(|line| line.left).collect()
+            };
+            assert_eq!(lefts(TextAlignment::Left), vec![0.0, 0.0]);
+            assert_eq!(lefts(TextAlignment::Centre), vec![5.0, 0.0]);
+            assert_eq!(lefts(TextAlignment::Right), vec![10.0, 0.0]);
+
+            //or within the wrap width, where there is one
+            let layout = lay_out("ab", TextAlignment::Centre, Some(30.0), PrintingModePosition::TopLeft);
+            assert_eq!(layout.get_lines()[0].left, 10.0);
+        }
+
+        #[test]
+        fn puts_the_position_at_the_origin() {
+            //two lines, 10 across and 20 down (from the first ascent to the last descent)
+            let placement = |position:PrintingModePosition| -> (f32, (f32, f32, f32, f32)) {
+                let layout = lay_out("ab\ncd", TextAlignment::Left, None, position);
+                let bounding_box = layout.get_bounding_box();
+                (
+                    layout.get_lines()[0].baseline,
+                    (bounding_box.get_top_left().get_x(), bounding_box.get_top_left().get_y(), bounding_box.get_bottom_right().get_x(), bounding_box.get_bottom_right().get_y()),
+                )
+            };
+            assert_eq!(placement(PrintingModePosition::TopLeft), (8.0, (0.0, 0.0, 10.0, 20.0)));
+            assert_eq!(placement(PrintingModePosition::TopRight), (8.0, (-10.0, 0.0, 0.0, 20.0)));
+            assert_eq!(placement(PrintingModePosition::Centre), (-2.0, (-5.0, -10.0, 5.0, 10.0)));
+            assert_eq!(placement(PrintingModePosition::BaselineCentre), (0.0, (-5.0, -8.0, 5.0, 12.0)));
+            assert_eq!(placement(PrintingModePosition::BottomLeft), (-12.0, (0.0, -20.0, 10.0, 0.0)));
+            assert_eq!(placement(PrintingModePosition::BottomRight), (-12.0, (-10.0, -20.0, 0.0, 0.0)));
+        }
+    }
@@ -0,0 +1,6 @@
+mod font;
+pub use font::{*};
+mod glyph_atlas;
+pub use glyph_atlas::{*};
+mod layout;
+pub use layout::{*};
\ No newline at end of file
@@ -0,0 +1,443 @@
+use std::fmt;
+
+use super::camera_controller::CameraController;
+use super::data_type::{
+    BoundingBox,
+    Colour,
+    Point,
+    Viewbox,
+};
+
+
+
+
+
+
+
+
+//layer filter
+    //which layers a viewport draws
+    #[derive(Clone, PartialEq, Debug)]
+    pub enum LayerFilter {
+        All,
+        Only(Vec<usize>),
+        AllExcept(Vec<usize>),
+    }
+    impl LayerFilter {
+        pub fn includes(&self, layer:usize) -> bool {
+            match self {
+                LayerFilter::All => true,
+                LayerFilter::Only(layers) => layers.contains(&layer),
+                LayerFilter::AllExcept(layers) => !layers.contains(&layer),
+            }
+        }
+    }
+    impl fmt::Display for LayerFilter {
+        fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { fmt::Debug::fmt(self, f) }
+    }
+
+
+
+
+
+
+
+
+//viewport
+    //a view of the world, drawn into one rectangle of the surface. The region is given as fractions of the surface
+    //(0 to 1 across and down), so the layout holds as the window is resized; the viewbox is kept the same (logical
+    //pixel) size as the region. A camera controller moves the viewbox about, for viewports that take input. Until the
+    //viewport is given a surface size (which adding it to a ViewportSet does), the viewbox's size is taken as the surface's
+    pub struct Viewport {
+        region: BoundingBox,
+        clear_colour: Option<Colour>, //None leaves whatever is beneath showing through
+        layer_filter: LayerFilter,
+        interactive: bool,
+        camera_controller: CameraController,
+
+        surface_width: u32, //logical pixels
+        surface_height: u32, //logical pixels
+    }
+    //new
+        impl Viewport {
+            pub fn new(region:BoundingBox, clear_colour:Option<Colour>, layer_filter:LayerFilter, interactive:bool, viewbox:Viewbox) -> Viewport {
+                let (surface_width, surface_height) = (viewbox.get_width(), viewbox.get_height());
+                let mut viewport = Viewport {
+                    region: Viewport::clamp_region(&region),
+                    clear_colour,
+                    layer_filter,
+                    interactive,
+                    camera_controller: CameraController::new(viewbox),
+                    surface_width,
+                    surface_height,
+                };
+                viewport.fit_viewbox_to_region();
+                viewport
+            }
+            //the whole surface, cleared to white, showing every layer
+            pub fn new_default() -> Viewport {
+                Viewport::new(
+                    BoundingBox::new(0.0, 0.0, 1.0, 1.0),
+                    Some(Colour::new(1.0, 1.0, 1.0, 1.0)),
+                    LayerFilter::All,
+                    true,
+                    Viewbox::new_default(),
+                )
+            }
+
+            fn clamp_region(region:&BoundingBox) -> BoundingBox {
+                BoundingBox::new(
+                    region.get_top_left().get_x().clamp(0.0, 1.0),
+                    region.get_top_left().get_y().clamp(0.0, 1.0),
+                    region.get_bottom_right().get_x().clamp(0.0, 1.0),
+                    region.get_bottom_right().get_y().clamp(0.0, 1.0),
+                )
+            }
+            fn fit_viewbox_to_region(&mut self) {
+                let region = self.get_pixel_region();
+                let width = (region.get_bottom_right().get_x() - region.get_top_left().get_x()).round().max(1.0) as u32;
+                let height = (region.get_bottom_right().get_y() - region.get_top_left().get_y()).round().max(1.0) as u32;
+                self.camera_controller.set_size(width, height);
+            }
+        }
+    //getters
+        impl Viewport {
+            pub fn get_region(&self) -> &BoundingBox { &self.region }
+            pub fn get_clear_colour(&self) -> Option<Colour> { self.clear_colour }
+            pub fn get_layer_filter(&self) -> &LayerFilter { &self.layer_filter }
+            pub fn is_interactive(&self) -> bool { self.interactive }
+            pub fn get_viewbox(&self) -> &Viewbox { self.camera_controller.get_viewbox() }
+            pub fn get_camera_controller(&self) -> &CameraController { &self.camera_controller }
+            pub fn get_camera_controller_mut(&mut self) -> &mut CameraController { &mut self.camera_controller }
+
+            //the region in logical pixels
+            pub fn get_pixel_region(&self) -> BoundingBox {
+                BoundingBox::new(
+                    self.region.get_top_left().get_x() * self.surface_width as f32,
+                    self.region.get_top_left().get_y() * self.surface_height as f32,
+                    self.region.get_bottom_right().get_x() * self.surface_width as f32,
+                    self.region.get_bottom_right().get_y() * self.surface_height as f32,
+                )
+            }
+            //the region in device pixels, as (x, y, width, height); what the renderer's viewport and scissor rectangle want
+            pub fn get_device_pixel_region(&self) -> (u32, u32, u32, u32) {
+                let region = self.get_pixel_region();
+                let ratio = self.get_viewbox().get_device_pixel_ratio();
+                let left = (region.get_top_left().get_x() * ratio).round() as u32;
+                let top = (region.get_top_left().get_y() * ratio).round() as u32;
+                let right = (region.get_bottom_right().get_x() * ratio).round() as u32;
+                let bottom = (region.get_bottom_right().get_y() * ratio).round() as u32;
+                (left, top, right.saturating_sub(left), bottom.saturating_sub(top))
+            }
+        }
+    //setters
+        impl Viewport {
+            pub fn set_region(&mut self, new:BoundingBox) {
+                self.region = Viewport::clamp_region(&new);
+                self.fit_viewbox_to_region();
+            }
+            pub fn set_clear_colour(&mut self, new:Option<Colour>) { self.clear_colour = new; }
+            pub fn set_layer_filter(&mut self, new:LayerFilter) { self.layer_filter = new; }
+            pub fn set_interactive(&mut self, new:bool) { self.interactive = new; }
+
+            //in logical pixels
+            pub fn set_surface_size(&mut self, width:u32, height:u32) {
+                self.surface_width = width;
+                self.surface_height = height;
+                self.fit_viewbox_to_region();
+            }
+            pub fn set_device_pixel_ratio(&mut self, new:f32) {
+                self.camera_controller.set_device_pixel_ratio(new);
+            }
+        }
+    //screen
+        //screen points here are in device pixels across the whole surface, as winit reports them
+        impl Viewport {
+            pub fn contains_screen_point(&self, point:&Point) -> bool {
+                let ratio = self.get_viewbox().get_device_pixel_ratio();
+                self.get_pixel_region().intersect_with_point( &Point::new(point.get_x() / ratio, point.get_y() / ratio) )
+            }
+            //the point relative to the region's top left, which is what the viewbox and camera controller work with
+            pub fn get_local_point(&self, point:&Point) -> Point {
+                let ratio = self.get_viewbox().get_device_pixel_ratio();
+                let region = self.get_pixel_region();
+                Point::new(
+                    point.get_x() - region.get_top_left().get_x() * ratio,
+                    point.get_y() - region.get_top_left().get_y() * ratio,
+                )
+            }
+            pub fn convert_point_to_world(&self, point:&Point) -> Point {
+                self.get_viewbox().convert_point_to_world( &self.get_local_point(point) )
+            }
+        }
+    //culling
+        impl Viewport {
+            //whether something on the layer, covering the world space bounding box, would show up in this viewport
+            pub fn is_visible(&self, layer:usize, bounding_box:&BoundingBox) -> bool {
+                self.layer_filter.includes(layer) && self.get_viewbox().get_bounding_box().intersect_with_bounding_box(bounding_box)
+            }
+        }
+    //printing
+        impl Viewport {
+            fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result {
+                write!(
+                    f, "{{region:{}, clear_colour:{:?}, layer_filter:{}, interactive:{}, viewbox:{}}}",
+                    self.region,
+                    self.clear_colour,
+                    self.layer_filter,
+                    self.interactive,
+                    self.get_viewbox(),
+                )
+            }
+        }
+        impl fmt::Display for Viewport {
+            fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { self.fmt(f) }
+        }
+        impl fmt::Debug for Viewport {
+            fn fmt(&self, f:&mut fmt::Formatter) -> fmt::Result { self.fmt(f) }
+        }
+
+
+
+
+
+
+
+
+//viewport snapshot
+    //what drawing a viewport needs, copied out of it. The viewports themselves (with their camera controllers and the
+    //input routing) stay with the orchestrator, so the renderer is sent these instead whenever they change
+    #[derive(Debug)]
+    pub struct ViewportSnapshot {
+        id: usize,
+        device_pixel_region: (u32, u32, u32, u32), //see Viewport::get_device_pixel_region
+        clear_colour: Option<Colour>,
+        layer_filter: LayerFilter,
+        viewbox: Viewbox,
+    }
+    //new
+        impl Viewport {
+            pub fn get_snapshot(&self, id:usize) -> ViewportSnapshot {
+                ViewportSnapshot {
+                    id,
+                    device_pixel_region: self.get_device_pixel_region(),
+                    clear_colour: self.clear_colour,
+                    layer_filter: self.layer_filter.clone(),
+                    viewbox: self.get_viewbox().clone(),
+                }
+            }
+        }
+    //getters
+        impl ViewportSnapshot {
+            pub fn get_id(&self) -> usize { self.id }
+            pub fn get_device_pixel_region(&self) -> (u32, u32, u32, u32) { self.device_pixel_region }
+            pub fn get_clear_colour(&self) -> Option<Colour> { self.clear_colour }
+            pub fn get_layer_filter(&self) -> &LayerFilter { &self.layer_filter }
+            pub fn get_viewbox(&self) -> &Viewbox { &self.viewbox }
+        }
+    //cloning
+        impl Clone for ViewportSnapshot {
+            fn clone(&self) -> ViewportSnapshot {
+                ViewportSnapshot {
+                    id: self.id,
+                    device_pixel_region: self.device_pixel_region,
+                    clear_colour: self.clear_colour,
+                    layer_filter: self.layer_filter.clone(),
+                    viewbox: self.viewbox.clone(),
+                }
+            }
+        }
+    //culling
+        impl ViewportSnapshot {
+            //as Viewport::is_visible
+            pub fn is_visible(&self, layer:usize, bounding_box:&BoundingBox) -> bool {
+                self.layer_filter.includes(layer) && self.viewbox.get_bounding_box().intersect_with_bounding_box(bounding_box)
+            }
+        }
+
+
+
+
+
+
+
+
+//viewport set
+    //the viewports on a surface, drawn in the order they were added (so later ones sit on top), and the routing of
+    //input to them. Pointer input goes to the top-most interactive viewport under the pointer; a press keeps the input
+    //going to its viewport until release (so a drag can leave the region), and keys go to whichever was pressed last
+    pub struct ViewportSet {
+        viewports: Vec<(usize, Viewport)>,
+        next_id: usize,
+
+        surface_width: u32, //logical pixels
+        surface_height: u32, //logical pixels
+        device_pixel_ratio: f32,
+
+        captured: Option<usize>,
+        focused: Option<usize>,
+        touch_owners: Vec<(u64, usize)>,
+    }
+    //new
+        impl ViewportSet {
+            pub fn new(surface_width:u32, surface_height:u32, device_pixel_ratio:f32) -> ViewportSet {
+                ViewportSet {
+                    viewports: vec![],
+                    next_id: 0,
+                    surface_width,
+                    surface_height,
+                    device_pixel_ratio,
+                    captured: None,
+                    focused: None,
+                    touch_owners: vec![],
+                }
+            }
+        }
+    //viewports
+        impl ViewportSet {
+            //returns the viewport's id
+            pub fn add(&mut self, mut viewport:Viewport) -> usize {
+                viewport.set_device_pixel_ratio(self.device_pixel_ratio);
+                viewport.set_surface_size(self.surface_width, self.surface_height);
+
+                let id = self.next_id;
+                self.next_id += 1;
+                self.viewports.push((id, viewport));
+                if self.focused.is_none() {
+                    self.focused = Some(id);
+                }
+                id
+            }
+            pub fn remove(&mut self, id:usize) -> Option<Viewport> {
+                let index = self.viewports.iter().position(|(viewport_id, _)| *viewport_id == id)?;
+                if self.captured == Some(id) { self.captured = None; }
+                if self.focused == Some(id) { self.focused = None; }
+                self.touch_owners.retain(|(_, owner)| *owner != id);
+                Some(self.viewports.remove(index).1)
+            }
+            pub fn get(&self, id:usize) -> Option<&Viewport> {
+                self.viewports.iter().find(|(viewport_id, _)| *viewport_id == id).map(|(_, viewport)| viewport)
+            }
+            pub fn get_mut(&mut self, id:usize) -> Option<&mut Viewport> {
+                self.viewports.iter_mut().find(|(viewport_id, _)| *viewport_id == id).map(|(_, viewport)| viewport)
+            }
+            //bottom to top, with their ids
+            pub fn iter(&self) -> impl Iterator<Item = &(usize, Viewport)> {
+                self.viewports.iter()
+            }
+            pub fn len(&self) -> usize { self.viewports.len() }
+            pub fn is_empty(&self) -> bool { self.viewports.is_empty() }
+            pub fn bring_to_front(&mut self, id:usize) {
+                if let Some(index) = self.viewports.iter().position(|(viewport_id, _)| *viewport_id == id) {
+                    let viewport = self.viewports.remove(index);
+                    self.viewports.push(viewport);
+                }
+            }
+
+            //bottom to top, for sending to the renderer
+            pub fn get_snapshots(&self) -> Vec<ViewportSnapshot> {
+                self.viewports.iter().map(|(id, viewport)| viewport.get_snapshot(*id)).collect()
+            }
+            //whether a press has a viewport's camera being dragged about
+            pub fn is_dragging(&self) -> bool {
+                match self.captured.and_then(|id| self.get(id)) {
+                    Some(viewport) => viewport.get_camera_controller().is_dragging(),
+                    None => false,
+                }
+            }
+            //whether any camera still has inertia or a transition to work through (see CameraController::is_moving)
+            pub fn is_moving(&self) -> bool {
+                self.viewports.iter().any(|(_, viewport)| viewport.get_camera_controller().is_moving())
+            }
+
+            //the top-most interactive viewport under the screen point (in device pixels)
+            pub fn get_viewport_id_at(&self, point:&Point) -> Option<usize> {
+                self.viewports.iter().rev()
+                    .find(|(_, viewport)| viewport.is_interactive() && viewport.contains_screen_point(point))
+                    .map(|(id, _)| *id)
+            }
+        }
+    //surface
+        impl ViewportSet {
+            pub fn get_surface_width(&self) -> u32 { self.surface_width }
+            pub fn get_surface_height(&self) -> u32 { self.surface_height }
+            pub fn get_device_pixel_ratio(&self) -> f32 { self.device_pixel_ratio }
+
+            //in logical pixels
+            pub fn set_surface_size(&mut self, width:u32, height:u32) {
+                self.surface_width = width;
+                self.surface_height = height;
+                for (_, viewport) in &mut self.viewports {
+                    viewport.set_surface_size(width, height);
+                }
+            }
+            pub fn set_device_pixel_ratio(&mut self, new:f32) {
+                self.device_pixel_ratio = new;
+                for (_, viewport) in &mut self.viewports {
+                    viewport.set_device_pixel_ratio(new);
+                }
+            }
+        }
+    //input
+        impl ViewportSet {
+            pub fn press(&mut self, point:&Point, modifiers:&winit::event::ModifiersState) {
+                self.captured = self.get_viewport_id_at(point);
+                self.focused = self.captured;
+                if let Some(viewport) = self.captured.and_then(|id| self.get_mut(id)) {
+                    let local_point = viewport.get_local_point(point);
+                    viewport.get_camera_controller_mut().press(&local_point, modifiers);
+                }
+            }
+            pub fn pointer_move(&mut self, point:&Point) {
+                if let Some(viewport) = self.captured.and_then(|id| self.get_mut(id)) {
+                    let local_point = viewport.get_local_point(point);
+                    viewport.get_camera_controller_mut().pointer_move(&local_point);
+                }
+            }
+            pub fn release(&mut self) {
+                if let Some(viewport) = self.captured.take().and_then(|id| self.get_mut(id)) {
+                    viewport.get_camera_controller_mut().release();
+                }
+            }
+            pub fn wheel(&mut self, delta:&winit::event::MouseScrollDelta, point:&Point) {
+                if let Some(viewport) = self.get_viewport_id_at(point).and_then(|id| self.get_mut(id)) {
+                    let local_point = viewport.get_local_point(point);
+                    viewport.get_camera_controller_mut().wheel(delta, &local_point);
+                }
+            }
+            //each finger stays with the viewport it first touched
+            pub fn touch(&mut self, id:u64, phase:winit::event::TouchPhase, point:&Point) {
+                let owner = match phase {
+                    winit::event::TouchPhase::Started => {
+                        let owner = self.get_viewport_id_at(point);
+                        self.touch_owners.retain(|(touch_id, _)| *touch_id != id);
+                        if let Some(owner) = owner {
+                            self.touch_owners.push((id, owner));
+                            self.focused = Some(owner);
+                        }
+                        owner
+                    },
+                    _ => self.touch_owners.iter().find(|(touch_id, _)| *touch_id == id).map(|(_, owner)| *owner),
+                };
+                if let winit::event::TouchPhase::Ended | winit::event::TouchPhase::Cancelled = phase {
+                    self.touch_owners.retain(|(touch_id, _)| *touch_id != id);
+                }
+
+                if let Some(viewport) = owner.and_then(|owner| self.get_mut(owner)) {
+                    let local_point = viewport.get_local_point(point);
+                    viewport.get_camera_controller_mut().touch(id, phase, &local_point);
+                }
+            }
+            pub fn key_press(&mut self, key:winit::event::VirtualKeyCode) -> bool {
+                match self.focused.and_then(|id| self.get_mut(id)) {
+                    Some(viewport) => viewport.get_camera_controller_mut().key_press(key),
+                    None => false,
+                }
+            }
+
+            pub fn update(&mut self, tick:usize) {
+                for (_, viewport) in &mut self.viewports {
+                    viewport.get_camera_controller_mut().update(tick);
+                }
+            }
+        }
\ No newline at end of file
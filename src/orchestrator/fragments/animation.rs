@@ -0,0 +1,46 @@
+use super::super::Orchestrator;
+
+use crate::library::animation::Tween;
+use crate::library::data_type::Offset;
+
+
+
+
+impl Orchestrator {
+    //runs the tween against the node's offset, from the tick the tween starts on. Any tween already running on the
+    //node's offset is replaced
+    pub fn animate_offset(&mut self, node:usize, tween:Tween<Offset>) {
+        self.offset_tweens.retain(|(id, _)| *id != node);
+        self.offset_tweens.push((node, tween));
+    }
+    pub fn animate_opacity(&mut self, node:usize, tween:Tween<f32>) {
+        self.opacity_tweens.retain(|(id, _)| *id != node);
+        self.opacity_tweens.push((node, tween));
+    }
+    //leaves the node where its animations have got to
+    pub fn stop_animations(&mut self, node:usize) {
+        self.offset_tweens.retain(|(id, _)| *id != node);
+        self.opacity_tweens.retain(|(id, _)| *id != node);
+    }
+    pub fn is_animating(&self, node:usize) -> bool {
+        self.offset_tweens.iter().any(|(id, _)| *id == node) || self.opacity_tweens.iter().any(|(id, _)| *id == node)
+    }
+
+    //called once per revolution; finished tweens set their final values and are dropped, as are those of nodes that
+    //have since been removed
+    pub fn update_animations(&mut self) {
+        let tick = self.tick;
+        let scene_graph = &mut self.scene_graph;
+
+        self.offset_tweens.retain(|(node, tween)| {
+            if !scene_graph.contains(*node) { return false; }
+            scene_graph.set_offset(*node, tween.get_value(tick));
+            !tween.is_finished(tick)
+        });
+        self.opacity_tweens.retain(|(node, tween)| {
+            if !scene_graph.contains(*node) { return false; }
+            scene_graph.set_opacity(*node, tween.get_value(tick));
+            !tween.is_finished(tick)
+        });
+    }
+}
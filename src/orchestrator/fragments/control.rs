@@ -22,4 +22,9 @@ impl Orchestrator {
 
         println!(">> Orchestrator Halt Complete");
     }
+
+    //the clock that animations (see library::animation::Tween) are sampled against; it moves on once per revolution
+    pub fn get_tick(&self) -> usize {
+        self.tick
+    }
 }
\ No newline at end of file
@@ -4,7 +4,21 @@ use crate::library::{
     Communicator,
     Communique,
 };
-use crate::library::data_type::Point;
+use crate::library::data_type::{
+    BoundingBox,
+    Colour,
+    Point,
+    Viewbox,
+};
+use crate::library::scene_graph::{
+    EventDispatcher,
+    SceneGraph,
+};
+use crate::library::viewport::{
+    LayerFilter,
+    Viewport,
+    ViewportSet,
+};
 use crate::renderer;
 
 
@@ -27,6 +41,38 @@ impl Orchestrator {
                 ).ignition();
             });
 
+        //viewports
+            //the test scene (see renderer test5) side by side with a turned and zoomed copy of itself, and a minimap
+            //(showing only every third shape) in the corner
+            let size = window.inner_size().to_logical::<u32>(window.scale_factor());
+            let mut viewports = ViewportSet::new(size.width, size.height, window.scale_factor() as f32);
+            let scene_area = BoundingBox::new(0.0, 0.0, 500.0, 400.0);
+
+            //left half, fitted to the scene
+                let left = viewports.add( Viewport::new(
+                    BoundingBox::new(0.0, 0.0, 0.5, 1.0), Some(Colour::new(1.0, 1.0, 1.0, 1.0)), LayerFilter::All, true,
+                    Viewbox::new(0.0, 0.0, 1.0, 0.0, 0.5, 0.5, size.width, size.height),
+                ) );
+            //right half, turned and zoomed in on the middle
+                viewports.add( Viewport::new(
+                    BoundingBox::new(0.5, 0.0, 1.0, 1.0), Some(Colour::new(0.9, 0.9, 0.9, 1.0)), LayerFilter::All, true,
+                    Viewbox::new(250.0, 200.0, 2.0, 0.3, 0.5, 0.5, size.width, size.height),
+                ) );
+            //minimap, fitted to the scene
+                let minimap = viewports.add( Viewport::new(
+                    BoundingBox::new(0.72, 0.72, 0.98, 0.98), Some(Colour::new(0.2, 0.2, 0.2, 1.0)), LayerFilter::Only(vec![0]), false,
+                    Viewbox::new(0.0, 0.0, 1.0, 0.0, 0.5, 0.5, size.width, size.height),
+                ) );
+            //fitting needs the viewboxes at their regions' sizes, which they're only given once added. The fitted view
+            //is also what resetting returns to
+                for (id, padding) in [(left, 10.0), (minimap, 4.0)].iter() {
+                    let viewport = viewports.get_mut(*id).unwrap();
+                    let fitted = viewport.get_viewbox().get_fitted_to_bounding_box(&scene_area, *padding);
+                    let camera_controller = viewport.get_camera_controller_mut();
+                    camera_controller.set_initial_viewbox(fitted.clone());
+                    camera_controller.set_viewbox(fitted);
+                }
+
         Orchestrator {
             //loop
                 halt: false,
@@ -47,6 +93,18 @@ impl Orchestrator {
                 double_click_maximum_interval_duration: std::time::Duration::from_secs(1),
                 cursor_just_entered: false,
                 pressed_modifier_keys: winit::event::ModifiersState::empty(),
+
+            //viewports
+                viewports,
+                viewports_changed: true, //so that they're sent on the first revolution
+
+            //scene
+                scene_graph: SceneGraph::new(),
+                event_dispatcher: EventDispatcher::new(),
+
+            //animation
+                offset_tweens: vec![],
+                opacity_tweens: vec![],
         }
     }
 }
\ No newline at end of file
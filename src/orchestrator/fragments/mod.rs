@@ -1,4 +1,6 @@
 mod creation;
 mod window_event;
 mod control;
-mod revolution;
\ No newline at end of file
+mod revolution;
+mod scene_event;
+mod animation;
\ No newline at end of file
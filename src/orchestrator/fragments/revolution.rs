@@ -15,6 +15,20 @@ impl Orchestrator {
                 //     println!(" > Orchestrator renderer__communicator got: {:?}", item);
                 // }
 
+        //viewports
+            self.viewports.update(self.tick);
+            if self.viewports_changed || self.viewports.is_moving() {
+                self.renderer__communicator.send_message( renderer::library::MessageFromOrchestratorToRenderer::Viewports(self.viewports.get_snapshots()) ).ok();
+                self.viewports_changed = false;
+            }
+
+        //animation
+            self.update_animations();
+
+        //scene
+            //so that hit testing can skip whatever the pointer is nowhere near
+            self.scene_graph.update_world_bounding_boxes();
+
         //logic
             if self.tick == 1 {
                 self.renderer__communicator.send_message( renderer::library::MessageFromOrchestratorToRenderer::Test(3) ).ok();
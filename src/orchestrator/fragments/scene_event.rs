@@ -0,0 +1,75 @@
+use super::super::Orchestrator;
+
+use crate::library::data_type::{
+    CallbackType,
+    Point,
+};
+use crate::library::scene_graph::{
+    EventDispatcher,
+    SceneGraph,
+};
+use crate::library::viewport::LayerFilter;
+
+
+
+
+impl Orchestrator {
+    pub fn get_scene_graph(&self) -> &SceneGraph { &self.scene_graph }
+    pub fn get_scene_graph_mut(&mut self) -> &mut SceneGraph { &mut self.scene_graph }
+    pub fn get_event_dispatcher_mut(&mut self) -> &mut EventDispatcher { &mut self.event_dispatcher }
+
+    //removes the node (and everything in it) along with the callbacks added for them, which going through the scene
+    //graph directly would leave behind
+    pub fn remove_scene_node(&mut self, id:usize) -> bool {
+        if !self.scene_graph.remove(id) {
+            return false;
+        }
+        self.event_dispatcher.remove_callbacks_for_removed_nodes(&self.scene_graph);
+        true
+    }
+    pub fn clear_scene(&mut self) {
+        self.scene_graph.clear();
+        self.event_dispatcher.remove_callbacks_for_removed_nodes(&self.scene_graph);
+    }
+
+    //the mouse position in the world, as seen through the viewport it's over (along with what that viewport shows)
+    fn get_mouse_position_in_world(&self) -> Option<(Point, LayerFilter)> {
+        let viewport = self.viewports.get( self.viewports.get_viewport_id_at(&self.most_recent_mouse_position)? )?;
+        Some(( viewport.convert_point_to_world(&self.most_recent_mouse_position), viewport.get_layer_filter().clone() ))
+    }
+
+    pub fn scene_pointer_move(&mut self) {
+        match self.get_mouse_position_in_world() {
+            Some((point, layer_filter)) => self.event_dispatcher.pointer_move(&self.scene_graph, &point, &layer_filter),
+            None => self.scene_pointer_leave(),
+        }
+    }
+    pub fn scene_pointer_leave(&mut self) {
+        let point = self.most_recent_mouse_position;
+        self.event_dispatcher.pointer_leave(&self.scene_graph, &point);
+    }
+    pub fn scene_pointer_down(&mut self) {
+        if let Some((point, layer_filter)) = self.get_mouse_position_in_world() {
+            self.event_dispatcher.pointer_down(&self.scene_graph, &point, &layer_filter);
+        }
+    }
+    pub fn scene_pointer_up(&mut self) {
+        let (point, layer_filter) = match self.get_mouse_position_in_world() {
+            Some(position) => position,
+            None => return,
+        };
+
+        let now = std::time::Instant::now();
+        let within_double_click_interval = self.last_mouse_click_time.map_or(false, |time| now.duration_since(time) <= self.double_click_maximum_interval_duration);
+        match self.event_dispatcher.pointer_up(&self.scene_graph, &point, &layer_filter, within_double_click_interval) {
+            Some(CallbackType::Click) => self.last_mouse_click_time = Some(now),
+            Some(CallbackType::DoubleClick) => self.last_mouse_click_time = None, //so that a third click starts afresh
+            _ => {},
+        }
+    }
+    pub fn scene_wheel(&mut self, delta:&winit::event::MouseScrollDelta) {
+        if let Some((point, layer_filter)) = self.get_mouse_position_in_world() {
+            self.event_dispatcher.wheel(&self.scene_graph, delta, &point, &layer_filter);
+        }
+    }
+}
\ No newline at end of file
@@ -1,5 +1,8 @@
 use super::super::Orchestrator;
 
+use crate::library::data_type::Point;
+use crate::renderer;
+
 
 
 
@@ -29,16 +32,78 @@ impl Orchestrator {
                         //window event
                             winit::event::WindowEvent::CloseRequested => self.halt(),
 
-                        //escape key
+                            winit::event::WindowEvent::Resized(size) => {
+                                self.renderer__communicator.send_message( renderer::library::MessageFromOrchestratorToRenderer::WindowSize(*size) ).ok();
+                                let size = size.to_logical::<u32>(window.scale_factor());
+                                self.viewports.set_surface_size(size.width, size.height);
+                                self.viewports_changed = true;
+                            },
+                            winit::event::WindowEvent::ScaleFactorChanged { scale_factor, new_inner_size } => {
+                                let size = new_inner_size.to_logical::<u32>(*scale_factor);
+                                self.viewports.set_device_pixel_ratio(*scale_factor as f32);
+                                self.viewports.set_surface_size(size.width, size.height);
+                                self.viewports_changed = true;
+                                self.renderer__communicator.send_message( renderer::library::MessageFromOrchestratorToRenderer::DevicePixelDensityRatio(*scale_factor) ).ok();
+                                self.renderer__communicator.send_message( renderer::library::MessageFromOrchestratorToRenderer::WindowSize(**new_inner_size) ).ok();
+                            },
+
+                        //escape key (and the camera's keys)
                             winit::event::WindowEvent::KeyboardInput { input, .. } => { //{ device_id, input, is_synthetic }
                                 if let winit::event::KeyboardInput {
                                     state: winit::event::ElementState::Pressed, 
-                                    virtual_keycode: Some(winit::event::VirtualKeyCode::Escape), 
+                                    virtual_keycode: Some(key), 
                                     .. 
                                 } = input {
-                                    self.halt();
+                                    if *key == winit::event::VirtualKeyCode::Escape {
+                                        self.halt();
+                                    } else {
+                                        self.viewports.key_press(*key);
+                                        self.viewports_changed = true;
+                                    }
+                                }
+                            },
+                            winit::event::WindowEvent::ModifiersChanged(modifiers) => {
+                                self.pressed_modifier_keys = *modifiers;
+                            },
+
+                        //mouse
+                            winit::event::WindowEvent::CursorMoved { position, .. } => {
+                                self.most_recent_mouse_position = Point::new(position.x as f32, position.y as f32);
+                                self.viewports.pointer_move(&self.most_recent_mouse_position);
+                                self.viewports_changed |= self.viewports.is_dragging();
+                                self.scene_pointer_move();
+                            },
+                            winit::event::WindowEvent::CursorEntered { .. } => {
+                                self.cursor_just_entered = true;
+                            },
+                            winit::event::WindowEvent::CursorLeft { .. } => {
+                                self.viewports.release();
+                                self.scene_pointer_leave();
+                            },
+                            winit::event::WindowEvent::MouseInput { state, button: winit::event::MouseButton::Left, .. } => {
+                                match state {
+                                    winit::event::ElementState::Pressed => {
+                                        self.last_mouse_down_time = Some(std::time::Instant::now());
+                                        self.viewports.press(&self.most_recent_mouse_position, &self.pressed_modifier_keys);
+                                        self.scene_pointer_down();
+                                    },
+                                    winit::event::ElementState::Released => {
+                                        self.viewports.release();
+                                        self.scene_pointer_up();
+                                    },
                                 }
                             },
+                            winit::event::WindowEvent::MouseWheel { delta, .. } => {
+                                self.viewports.wheel(delta, &self.most_recent_mouse_position);
+                                self.viewports_changed = true;
+                                self.scene_wheel(delta);
+                            },
+
+                        //touch
+                            winit::event::WindowEvent::Touch(touch) => {
+                                self.viewports.touch(touch.id, touch.phase, &Point::new(touch.location.x as f32, touch.location.y as f32));
+                                self.viewports_changed = true;
+                            },
 
                         _ => {
                             // println!("Unhandled window event: {:?}", event);
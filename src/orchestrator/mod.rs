@@ -1,5 +1,14 @@
 use crate::library::Communicator;
-use crate::library::data_type::Point;
+use crate::library::animation::Tween;
+use crate::library::viewport::ViewportSet;
+use crate::library::scene_graph::{
+    EventDispatcher,
+    SceneGraph,
+};
+use crate::library::data_type::{
+    Offset,
+    Point,
+};
 use crate::renderer;
 
 
@@ -25,6 +34,18 @@ pub struct Orchestrator {
         double_click_maximum_interval_duration: std::time::Duration,
         cursor_just_entered: bool,
         pressed_modifier_keys: winit::event::ModifiersState,
+
+    //viewports
+        viewports: ViewportSet,
+        viewports_changed: bool, //since they were last sent to the renderer
+
+    //scene
+        scene_graph: SceneGraph,
+        event_dispatcher: EventDispatcher,
+
+    //animation
+        offset_tweens: Vec<(usize, Tween<Offset>)>, //node id and tween
+        opacity_tweens: Vec<(usize, Tween<f32>)>,
 }
 
 mod fragments;
\ No newline at end of file
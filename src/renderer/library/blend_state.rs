@@ -0,0 +1,84 @@
+use crate::library::data_type::{
+    BlendMode,
+    CompositeOperation,
+    DrawBlend,
+};
+
+
+
+
+
+
+
+
+//the colour state for a pipeline drawing with the given blend. Colours arrive linear and premultiplied (see
+//FragmentUniformData), which lets every Porter-Duff operator be done exactly by the fixed function blending
+//
+//blend modes are a closer fit for some than others:
+//  Screen is exact
+//  Multiply is exact over an opaque destination (which is the usual case)
+//  Darken and Lighten are only exact when both the source and destination are opaque; a translucent source is compared
+//  as it is rather than first being faded into the destination (so half transparent black over white comes out black,
+//  where Colour::blend gives grey)
+//  Overlay can't be done by fixed function blending at all, so falls back to Normal with a warning
+pub fn produce_color_state_descriptor(format:wgpu::TextureFormat, draw_blend:&DrawBlend) -> wgpu::ColorStateDescriptor {
+    let (color_blend, alpha_blend) = match draw_blend {
+        DrawBlend::Composite(operation) => {
+            let descriptor = composite_blend_descriptor(operation);
+            (descriptor.clone(), descriptor)
+        },
+        DrawBlend::Mode(mode) => mode_blend_descriptors(mode),
+    };
+
+    wgpu::ColorStateDescriptor {
+        format,
+        color_blend,
+        alpha_blend,
+        write_mask: wgpu::ColorWrite::ALL,
+    }
+}
+
+fn factors(src_factor:wgpu::BlendFactor, dst_factor:wgpu::BlendFactor) -> wgpu::BlendDescriptor {
+    wgpu::BlendDescriptor {
+        src_factor,
+        dst_factor,
+        operation: wgpu::BlendOperation::Add,
+    }
+}
+
+fn composite_blend_descriptor(operation:&CompositeOperation) -> wgpu::BlendDescriptor {
+    use wgpu::BlendFactor::*;
+
+    match operation {
+        CompositeOperation::Clear => factors(Zero, Zero),
+        CompositeOperation::Source => factors(One, Zero),
+        CompositeOperation::Destination => factors(Zero, One),
+        CompositeOperation::SourceOver => factors(One, OneMinusSrcAlpha),
+        CompositeOperation::DestinationOver => factors(OneMinusDstAlpha, One),
+        CompositeOperation::SourceIn => factors(DstAlpha, Zero),
+        CompositeOperation::DestinationIn => factors(Zero, SrcAlpha),
+        CompositeOperation::SourceOut => factors(OneMinusDstAlpha, Zero),
+        CompositeOperation::DestinationOut => factors(Zero, OneMinusSrcAlpha),
+        CompositeOperation::SourceAtop => factors(DstAlpha, OneMinusSrcAlpha),
+        CompositeOperation::DestinationAtop => factors(OneMinusDstAlpha, SrcAlpha),
+        CompositeOperation::Xor => factors(OneMinusDstAlpha, OneMinusSrcAlpha),
+        CompositeOperation::Plus => factors(One, One),
+    }
+}
+
+fn mode_blend_descriptors(mode:&BlendMode) -> (wgpu::BlendDescriptor, wgpu::BlendDescriptor) {
+    use wgpu::BlendFactor::*;
+
+    let source_over = factors(One, OneMinusSrcAlpha);
+    match mode {
+        BlendMode::Normal => (source_over.clone(), source_over),
+        BlendMode::Multiply => (factors(DstColor, OneMinusSrcAlpha), source_over),
+        BlendMode::Screen => (factors(One, OneMinusSrcColor), source_over),
+        BlendMode::Darken => (wgpu::BlendDescriptor { src_factor:One, dst_factor:One, operation:wgpu::BlendOperation::Min }, source_over),
+        BlendMode::Lighten => (wgpu::BlendDescriptor { src_factor:One, dst_factor:One, operation:wgpu::BlendOperation::Max }, source_over),
+        BlendMode::Overlay => {
+            println!("WARNING - renderer - the Overlay blend mode isn't supported by the pipeline; drawing as Normal instead");
+            (source_over.clone(), source_over)
+        },
+    }
+}
\ No newline at end of file
@@ -1,7 +1,13 @@
+use crate::library::viewport::ViewportSnapshot;
+
 #[derive(Debug)]
 pub enum MessageFromOrchestratorToRenderer {
     Halt,
 
+    DevicePixelDensityRatio(f64), //the window's scale factor changed
+    WindowSize(winit::dpi::PhysicalSize<u32>), //the window was resized (in physical pixels)
+    Viewports(Vec<ViewportSnapshot>), //the orchestrator's viewports changed (bottom to top)
+
     Test(usize),
 }
 
@@ -5,4 +5,21 @@ pub use communication::{
 };
 
 mod vertex;
-pub use vertex::Vertex;
\ No newline at end of file
+pub use vertex::Vertex;
+
+mod blend_state;
+pub use blend_state::produce_color_state_descriptor;
+
+mod texture;
+pub use texture::{
+    ImageTexture,
+    produce_sampler,
+    produce_texture_bind_group,
+    produce_texture_bind_group_layout,
+};
+
+mod uniform_data;
+pub use uniform_data::{
+    FragmentUniformData,
+    VertexUniformData,
+};
\ No newline at end of file
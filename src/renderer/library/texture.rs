@@ -0,0 +1,154 @@
+use crate::library::data_type::{
+    Image,
+    ImageFilter,
+    ImageSampling,
+};
+
+
+
+
+
+
+
+
+//the layout for a texture (binding 0) and the sampler to read it with (binding 1), as the glyph and image fragment
+//shaders have them
+pub fn produce_texture_bind_group_layout(device:&wgpu::Device) -> wgpu::BindGroupLayout {
+    device.create_bind_group_layout(
+        &wgpu::BindGroupLayoutDescriptor {
+            label: Some("Texture Bind Group Layout"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStage::FRAGMENT,
+                    ty: wgpu::BindingType::SampledTexture {
+                        dimension: wgpu::TextureViewDimension::D2,
+                        component_type: wgpu::TextureComponentType::Float,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStage::FRAGMENT,
+                    ty: wgpu::BindingType::Sampler {
+                        comparison: false,
+                    },
+                    count: None,
+                },
+            ],
+        }
+    )
+}
+
+pub fn produce_texture_bind_group(device:&wgpu::Device, layout:&wgpu::BindGroupLayout, view:&wgpu::TextureView, sampler:&wgpu::Sampler) -> wgpu::BindGroup {
+    device.create_bind_group(
+        &wgpu::BindGroupDescriptor {
+            label: Some("Texture Bind Group"),
+            layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(view),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::Sampler(sampler),
+                },
+            ],
+        }
+    )
+}
+
+pub fn produce_sampler(device:&wgpu::Device, sampling:&ImageSampling) -> wgpu::Sampler {
+    let filter = match sampling.get_filter() {
+        ImageFilter::Nearest => wgpu::FilterMode::Nearest,
+        ImageFilter::Linear => wgpu::FilterMode::Linear,
+    };
+    device.create_sampler(
+        &wgpu::SamplerDescriptor {
+            label: Some("Image Sampler"),
+            mag_filter: filter,
+            min_filter: filter,
+            mipmap_filter: filter,
+            ..Default::default()
+        }
+    )
+}
+
+
+
+
+
+
+
+
+//an Image uploaded to the GPU, ready to be bound for drawing. The pixels go up premultiplied, into an sRGB texture
+//(so that sampling, and the blending between mipmap levels, happens in linear space), and with every mipmap level if
+//the sampling asks for them
+pub struct ImageTexture {
+    image_id: usize,
+    sampling: ImageSampling,
+    mip_level_count: u32,
+    bind_group: wgpu::BindGroup,
+}
+//new
+    impl ImageTexture {
+        pub fn new(device:&wgpu::Device, queue:&wgpu::Queue, layout:&wgpu::BindGroupLayout, image:&Image, sampling:&ImageSampling) -> ImageTexture {
+            let levels = image.get_premultiplied_mip_levels(sampling.get_mipmaps());
+
+            let texture = device.create_texture(
+                &wgpu::TextureDescriptor {
+                    size: wgpu::Extent3d {
+                        width: image.get_width(),
+                        height: image.get_height(),
+                        depth: 1,
+                    },
+                    mip_level_count: levels.len() as u32,
+                    sample_count: 1,
+                    dimension: wgpu::TextureDimension::D2,
+                    format: wgpu::TextureFormat::Rgba8UnormSrgb,
+                    usage: wgpu::TextureUsage::SAMPLED | wgpu::TextureUsage::COPY_DST,
+                    label: Some("Image Texture"),
+                }
+            );
+            for (mip_level, (width, height, pixels)) in levels.iter().enumerate() {
+                queue.write_texture(
+                    wgpu::TextureCopyView {
+                        texture: &texture,
+                        mip_level: mip_level as u32,
+                        origin: wgpu::Origin3d::ZERO,
+                    },
+                    pixels,
+                    wgpu::TextureDataLayout {
+                        offset: 0,
+                        bytes_per_row: width * 4,
+                        rows_per_image: *height,
+                    },
+                    wgpu::Extent3d {
+                        width: *width,
+                        height: *height,
+                        depth: 1,
+                    },
+                );
+            }
+
+            let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+            let sampler = produce_sampler(device, sampling);
+
+            ImageTexture {
+                image_id: image.get_id(),
+                sampling: *sampling,
+                mip_level_count: levels.len() as u32,
+                bind_group: produce_texture_bind_group(device, layout, &view, &sampler),
+            }
+        }
+    }
+//getters
+    impl ImageTexture {
+        pub fn get_image_id(&self) -> usize { self.image_id }
+        pub fn get_sampling(&self) -> ImageSampling { self.sampling }
+        pub fn get_mip_level_count(&self) -> u32 { self.mip_level_count }
+        //for set 1 of the image pipeline
+        pub fn get_bind_group(&self) -> &wgpu::BindGroup { &self.bind_group }
+    }
\ No newline at end of file
@@ -0,0 +1,91 @@
+use crate::library::data_type::{
+    Colour,
+    Point,
+    Dimensions,
+};
+
+
+
+
+
+
+
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct VertexUniformData {
+    xy: [f32; 2],
+    scale: f32,
+    angle: f32,
+    dimensions: [f32; 2],
+    width: f32, height: f32,
+    anchor: [f32; 2],
+}
+impl VertexUniformData {
+    pub fn new(
+        x: f32,
+        y: f32, 
+        scale: f32, 
+        angle: f32,
+        dimensions: Dimensions<u32>,
+        width: f32, 
+        height: f32,
+        anchor: Point,
+    ) -> Self {
+        Self {
+            xy: [x, y],
+            scale: scale,
+            angle: angle,
+            dimensions: [*dimensions.get_width() as f32, *dimensions.get_height() as f32],
+            width: width,
+            height: height,
+            anchor: [anchor.get_x(), anchor.get_y()],
+        }
+    }
+}
+impl VertexUniformData {
+    pub fn update(
+        &mut self,
+        x: Option<f32>,
+        y: Option<f32>,
+        scale: Option<f32>,
+        angle: Option<f32>,
+        dimensions: Option<(u32, u32)>,
+        width: Option<f32>,
+        height: Option<f32>,
+        anchor: Option<Point>,
+    ) {
+        if let Some(x) = x { self.xy[0] = x; }
+        if let Some(y) = y { self.xy[1] = y; }
+        if let Some(angle) = angle { self.angle = angle; }
+        if let Some(scale) = scale { self.scale = scale; }
+        if let Some(width) = width { self.width = width; }
+        if let Some(height) = height { self.height = height; }
+        if let Some(anchor) = anchor { self.anchor = [anchor.get_x(), anchor.get_y()]; }
+    }
+    pub fn update_dimensions(&mut self, dimensions:&Dimensions<u32>) {
+        self.dimensions = [*dimensions.get_width() as f32, *dimensions.get_height() as f32];
+    }
+}
+
+//the colour is linear and premultiplied, to match the pipeline's blending (One, OneMinusSrcAlpha) and the sRGB swap chain
+#[repr(C)]
+#[derive(Debug, Copy, Clone, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct FragmentUniformData {
+    colour: [f32; 4],
+}
+impl FragmentUniformData {
+    pub fn new(colour: Colour) -> FragmentUniformData {
+        Self {
+            colour: colour.to_premultiplied_linear(),
+        }
+    }
+}
+impl FragmentUniformData {
+    pub fn update(
+        &mut self,
+        colour: Colour,
+    ) {
+        self.colour = colour.to_premultiplied_linear();
+    }
+}
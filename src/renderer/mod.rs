@@ -2,9 +2,13 @@ use crate::library::{
     Communicator,
     Communique,
 };
+use crate::library::viewport::ViewportSnapshot;
 
 pub mod library;
 
+mod viewport_drawing;
+use viewport_drawing::ViewportDrawing;
+
 
 
 
@@ -25,6 +29,11 @@ pub mod library;
             surface: wgpu::Surface, 
             size: winit::dpi::PhysicalSize<u32>,
             initial_device_pixel_density_ratio: f64,
+            device_pixel_density_ratio: f64, //kept up to date by the orchestrator, as the window moves between displays
+
+        //drawing
+            viewports: Vec<ViewportSnapshot>, //kept up to date by the orchestrator, whose viewports take the input
+            viewport_drawing: Option<ViewportDrawing>, //set up by test5; while there is one, it's drawn again whenever the viewports change
     }
 
 //pre-creation setup
@@ -78,6 +87,11 @@ pub mod library;
                     surface: wgpu_setup_data.surface,
                     size: wgpu_setup_data.size,
                     initial_device_pixel_density_ratio: wgpu_setup_data.initial_device_pixel_density_ratio,
+                    device_pixel_density_ratio: wgpu_setup_data.initial_device_pixel_density_ratio,
+
+                //drawing
+                    viewports: vec![],
+                    viewport_drawing: None,
             }
         }
     }
@@ -105,22 +119,54 @@ pub mod library;
         pub fn revolution(&mut self) {
             //message collection
                 let tmp = self.communicator_to_orchestrator.collect_messages();
+                let mut viewports_changed = false;
                 for item in tmp {
                     match item.open() {
                         //revolution control
                             library::MessageFromOrchestratorToRenderer::Halt => self.halt(),
 
+                        //window
+                            library::MessageFromOrchestratorToRenderer::DevicePixelDensityRatio(ratio) => {
+                                self.device_pixel_density_ratio = *ratio;
+                            },
+                            library::MessageFromOrchestratorToRenderer::WindowSize(size) => {
+                                self.size = *size;
+                                if let Some(viewport_drawing) = &mut self.viewport_drawing {
+                                    viewport_drawing.set_size(&self.surface, self.size);
+                                }
+                            },
+                            library::MessageFromOrchestratorToRenderer::Viewports(viewports) => {
+                                self.viewports = viewports.clone();
+                                viewports_changed = true;
+                            },
+
                         //test request
                             library::MessageFromOrchestratorToRenderer::Test(number) => {
+                                //other tests make their own swap chains on the surface
+                                self.viewport_drawing = None;
+                                viewports_changed = false; //drawn just now, if it's test5
                                 match number {
                                     1 => self.test1(),
                                     2 => self.test2(),
                                     3 => self.test3(),
+                                    4 => self.test4(),
+                                    5 => self.test5(),
+                                    6 => self.test6(),
+                                    7 => self.test7(),
+                                    8 => self.test8(),
                                     _ => {},
                                 }
                             },
                     }
                 }
+
+            //redraw
+                //however many changes arrived, it's drawn once
+                if viewports_changed {
+                    if let Some(viewport_drawing) = &mut self.viewport_drawing {
+                        viewport_drawing.draw(&self.viewports);
+                    }
+                }
         }
     }
 
@@ -0,0 +1,18 @@
+#version 450
+
+//rebuild with: naga --input-kind glsl --shader-stage frag --spirv-version 1.0 shader_glyph.frag shader_glyph.frag.spv
+//the atlas holds each glyph's coverage, which scales the (linear, premultiplied) colour
+
+layout(location=0) in vec2 uv;
+
+layout(location=0) out vec4 outputColour;
+
+layout(set=0, binding=1) uniform Uniform {
+    vec4 colour;
+};
+layout(set=1, binding=0) uniform texture2D glyph_atlas;
+layout(set=1, binding=1) uniform sampler glyph_sampler;
+
+void main() {
+    outputColour = colour * texture(sampler2D(glyph_atlas, glyph_sampler), uv).r;
+}
@@ -0,0 +1,77 @@
+#version 450
+
+//rebuild with: naga --input-kind glsl --shader-stage frag --spirv-version 1.0 shader_gradient.frag shader_gradient.frag.spv
+//the same sums as Gradient::get_colour_at_point; colours arrive linear and premultiplied
+
+layout(location=0) in vec2 world_position;
+
+layout(location=0) out vec4 outputColour;
+
+layout(set=0, binding=1) uniform Uniform {
+    vec4 geometry;  //linear: start x, start y, end x, end y    radial: centre x, centre y, radius    conic: centre x, centre y, angle
+    vec4 transform; //x, y, scale, angle (taking gradient space into the world)
+    vec4 settings;  //kind (0 linear, 1 radial, 2 conic), spread (0 pad, 1 repeat, 2 reflect), stop count
+    vec4 offsets[2];
+    vec4 colours[8];
+};
+
+const float PI = 3.14159265358979;
+
+void main() {
+    //world to gradient space
+        float scale = transform.z == 0.0 ? 1.0 : transform.z;
+        vec2 p = (world_position - transform.xy) / scale;
+        float c = cos(-transform.w);
+        float s = sin(-transform.w);
+        p = vec2(p.x * c - p.y * s, p.y * c + p.x * s);
+
+    //how far along
+        float t = 0.0;
+        int kind = int(settings.x);
+        if (kind == 0) {
+            vec2 direction = geometry.zw - geometry.xy;
+            float length_squared = dot(direction, direction);
+            t = length_squared == 0.0 ? 0.0 : dot(p - geometry.xy, direction) / length_squared;
+        } else if (kind == 1) {
+            t = geometry.z == 0.0 ? 1.0 : length(p - geometry.xy) / geometry.z;
+        } else {
+            vec2 d = p - geometry.xy;
+            float turns = (atan(d.y, d.x) - geometry.z) / (2.0 * PI);
+            t = turns - floor(turns);
+        }
+
+    //spread
+        int spread = int(settings.y);
+        if (spread == 0) {
+            t = clamp(t, 0.0, 1.0);
+        } else if (spread == 1) {
+            t = t - floor(t);
+        } else {
+            float m = t - 2.0 * floor(t / 2.0);
+            t = 1.0 - abs(m - 1.0);
+        }
+
+    //stops
+        int count = int(settings.z);
+        if (count == 0) {
+            outputColour = vec4(0.0);
+            return;
+        }
+        vec4 colour = colours[count - 1];
+        if (t <= offsets[0].x) {
+            colour = colours[0];
+        } else {
+            for (int index = 1; index < count; index++) {
+                float before = offsets[(index - 1) / 4][(index - 1) % 4];
+                float after = offsets[index / 4][index % 4];
+                if (t <= after) {
+                    float span = after - before;
+                    float fraction = span <= 0.0 ? 1.0 : (t - before) / span;
+                    colour = mix(colours[index - 1], colours[index], fraction);
+                    break;
+                }
+            }
+        }
+
+    outputColour = colour;
+}
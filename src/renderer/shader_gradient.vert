@@ -0,0 +1,31 @@
+#version 450
+
+//rebuild with: naga --input-kind glsl --shader-stage vert --spirv-version 1.0 shader_gradient.vert shader_gradient.vert.spv
+//as shader.vert, but also passes the world position of each point along, for the gradient to be worked out from
+
+layout(location=0) in vec2 point;
+
+layout(location=0) out vec2 world_position;
+
+struct location {
+    vec2 xy;
+    float scale;
+    float angle;
+};
+layout(set=0, binding=0) uniform Uniform {
+    location adjust;
+    vec2 resolution;
+    vec2 dimensions;
+    vec2 anchor;
+};
+
+void main() {
+    vec2 P = dimensions * adjust.scale * (point - anchor);
+    P = vec2(
+        P.x * cos(adjust.angle) + P.y * sin(adjust.angle),
+        P.y * cos(adjust.angle) - P.x * sin(adjust.angle)
+    ) + adjust.xy;
+
+    world_position = P;
+    gl_Position = vec4(((P / resolution) * 2.0 - 1.0) * vec2(1.0, -1.0), 0.0, 1.0);
+}
@@ -0,0 +1,19 @@
+#version 450
+
+//rebuild with: naga --input-kind glsl --shader-stage frag --spirv-version 1.0 shader_image.frag shader_image.frag.spv
+//the image arrives premultiplied and (through the sRGB texture) linear, so the colour can simply scale it; white at
+//the element's opacity draws it as it is
+
+layout(location=0) in vec2 uv;
+
+layout(location=0) out vec4 outputColour;
+
+layout(set=0, binding=1) uniform Uniform {
+    vec4 colour;
+};
+layout(set=1, binding=0) uniform texture2D image;
+layout(set=1, binding=1) uniform sampler image_sampler;
+
+void main() {
+    outputColour = colour * texture(sampler2D(image, image_sampler), uv);
+}
@@ -1,16 +1,53 @@
-use rand::prelude::*;
-
 use wgpu::util::DeviceExt;
 
+use std::collections::HashMap;
+
 use crate::library::data_type::{
+    BlendMode,
+    BoundingBox,
     Colour,
+    ColourStop,
+    DrawBlend,
+    Gradient,
+    GradientKind,
+    Image,
+    ImageFilter,
+    ImageSampling,
+    Offset,
     Point,
+    PrintingModePosition,
+    SpreadMode,
     Dimensions,
+    Viewbox,
+    distinguishable_colours,
+};
+use crate::library::scene_generation::SceneDescription;
+use crate::library::scene_graph::{
+    Element,
+    ElementKind,
+    SceneGraph,
+};
+use crate::library::text::{
+    Font,
+    GlyphAtlas,
+    TextAlignment,
+    TextLayout,
+    TextStyle,
 };
 
 use super::Renderer;
+use super::viewport_drawing::{
+    ViewportDrawing,
+    ViewportShape,
+};
 use super::library;
+use super::library::{
+    FragmentUniformData,
+    VertexUniformData,
+};
 
+//the same scene on every run, so that timings can be compared
+const TEST_SCENE_SEED:u64 = 0;
 
 
 
@@ -18,87 +55,85 @@ use super::library;
 
 
 
+
+//as FragmentUniformData, for shader_gradient.frag; lays out like the shader's uniform block (std140)
+pub const GRADIENT_MAXIMUM_GPU_STOPS:usize = 8;
 #[repr(C)]
 #[derive(Debug, Copy, Clone, bytemuck::Pod, bytemuck::Zeroable)]
-pub struct VertexUniformData {
-    xy: [f32; 2],
-    scale: f32,
-    angle: f32,
-    dimensions: [f32; 2],
-    width: f32, height: f32,
-    anchor: [f32; 2],
+pub struct GradientFragmentUniformData {
+    geometry: [f32; 4],
+    transform: [f32; 4],
+    settings: [f32; 4],
+    offsets: [f32; GRADIENT_MAXIMUM_GPU_STOPS],
+    colours: [[f32; 4]; GRADIENT_MAXIMUM_GPU_STOPS],
 }
-impl VertexUniformData {
-    pub fn new(
-        x: f32,
-        y: f32, 
-        scale: f32, 
-        angle: f32,
-        dimensions: Dimensions<u32>,
-        width: f32, 
-        height: f32,
-        anchor: Point,
-    ) -> Self {
-        Self {
-            xy: [x, y],
-            scale: scale,
-            angle: angle,
-            dimensions: [*dimensions.get_width() as f32, *dimensions.get_height() as f32],
-            width: width,
-            height: height,
-            anchor: [anchor.get_x(), anchor.get_y()],
-        }
+impl GradientFragmentUniformData {
+    pub fn new(gradient:&Gradient) -> GradientFragmentUniformData {
+        let mut output = GradientFragmentUniformData {
+            geometry: [0.0; 4],
+            transform: [0.0; 4],
+            settings: [0.0; 4],
+            offsets: [0.0; GRADIENT_MAXIMUM_GPU_STOPS],
+            colours: [[0.0; 4]; GRADIENT_MAXIMUM_GPU_STOPS],
+        };
+        output.update(gradient);
+        output
     }
 }
-impl VertexUniformData {
-    pub fn update(
-        &mut self,
-        x: Option<f32>,
-        y: Option<f32>,
-        scale: Option<f32>,
-        angle: Option<f32>,
-        dimensions: Option<(u32, u32)>,
-        width: Option<f32>,
-        height: Option<f32>,
-        anchor: Option<Point>,
-    ) {
-        if let Some(x) = x { self.xy[0] = x; }
-        if let Some(y) = y { self.xy[1] = y; }
-        if let Some(angle) = angle { self.angle = angle; }
-        if let Some(scale) = scale { self.scale = scale; }
-        if let Some(width) = width { self.width = width; }
-        if let Some(height) = height { self.height = height; }
-        if let Some(anchor) = anchor { self.anchor = [anchor.get_x(), anchor.get_y()]; }
-    }
-    pub fn update_dimensions(&mut self, dimensions:&Dimensions<u32>) {
-        self.dimensions = [*dimensions.get_width() as f32, *dimensions.get_height() as f32];
+impl GradientFragmentUniformData {
+    pub fn update(&mut self, gradient:&Gradient) {
+        let (kind, geometry) = match *gradient.get_kind() {
+            GradientKind::Linear { start, end } => (0.0, [start.get_x(), start.get_y(), end.get_x(), end.get_y()]),
+            GradientKind::Radial { centre, radius } => (1.0, [centre.get_x(), centre.get_y(), radius, 0.0]),
+            GradientKind::Conic { centre, angle } => (2.0, [centre.get_x(), centre.get_y(), angle, 0.0]),
+        };
+        let spread = match gradient.get_spread() {
+            SpreadMode::Pad => 0.0,
+            SpreadMode::Repeat => 1.0,
+            SpreadMode::Reflect => 2.0,
+        };
+        let transform = gradient.get_transform();
+
+        //with too many stops to fit, the gradient is sampled evenly instead
+        let stops:Vec<(f32, Colour)> = if gradient.get_stops().len() > GRADIENT_MAXIMUM_GPU_STOPS {
+            println!("WARNING - renderer - gradient has {} stops, but only {} fit; resampling", gradient.get_stops().len(), GRADIENT_MAXIMUM_GPU_STOPS);
+            (0..GRADIENT_MAXIMUM_GPU_STOPS).map(|index| {
+                let t = index as f32 / (GRADIENT_MAXIMUM_GPU_STOPS - 1) as f32;
+                (t, gradient.get_colour_at_parameter(t))
+            }).collect()
+        } else {
+            gradient.get_stops().iter().map(|stop| (stop.offset, stop.colour)).collect()
+        };
+
+        self.geometry = geometry;
+        self.transform = [transform.get_x(), transform.get_y(), transform.get_scale(), transform.get_angle()];
+        self.settings = [kind, spread, stops.len() as f32, 0.0];
+        for (index, (offset, colour)) in stops.iter().enumerate() {
+            self.offsets[index] = *offset;
+            self.colours[index] = colour.to_premultiplied_linear();
+        }
     }
 }
 
+
+//as VertexUniformData, for shader_texture.vert; the uv rect (left, top, right, bottom) of the part of the texture
+//drawn follows on, aligned to 16 bytes as the shader's uniform block has it (std140)
 #[repr(C)]
 #[derive(Debug, Copy, Clone, bytemuck::Pod, bytemuck::Zeroable)]
-pub struct FragmentUniformData {
-    colour: [f32; 4],
+pub struct TextureVertexUniformData {
+    base: VertexUniformData,
+    _padding: [f32; 2],
+    uv_rect: [f32; 4],
 }
-impl FragmentUniformData {
-    pub fn new(colour: Colour) -> FragmentUniformData {
-        Self {
-            colour: [colour.r(), colour.g(), colour.b(), colour.a()]
+impl TextureVertexUniformData {
+    pub fn new(base:VertexUniformData, uv:&BoundingBox) -> TextureVertexUniformData {
+        TextureVertexUniformData {
+            base,
+            _padding: [0.0; 2],
+            uv_rect: [uv.get_top_left().get_x(), uv.get_top_left().get_y(), uv.get_bottom_right().get_x(), uv.get_bottom_right().get_y()],
         }
     }
 }
-impl FragmentUniformData {
-    pub fn update(
-        &mut self,
-        colour: Colour,
-    ) {
-        self.colour[0] = colour.premultiplied_r();
-        self.colour[1] = colour.premultiplied_g();
-        self.colour[2] = colour.premultiplied_b();
-        self.colour[3] = colour.a();
-    }
-}
-
 
 
 
@@ -189,20 +224,20 @@ impl Renderer {
                 }
             );
 
-            let mut rng = rand::thread_rng();
+            let scene = SceneDescription::new_default(TEST_SCENE_SEED, rectangle_count);
 
             let mut uniform_bind_group_vector:Vec<wgpu::BindGroup> = vec![];
-            for index in 0..rectangle_count {
+            for (index, shape) in scene.iter().enumerate() {
                 //uniform buffers
                     //vertex
                         let vertex_data = VertexUniformData::new(
-                            500.0*rng.gen::<f32>(), //x
-                            400.0*rng.gen::<f32>(), //y
+                            shape.x, //x
+                            shape.y, //y
                             1.0, //scale
-                            2.0 * std::f32::consts::PI * rng.gen::<f32>(), //angle
+                            shape.angle, //angle
                             Dimensions::<u32>::new(500,400), //dimensions
-                            30.0, //width
-                            30.0, //height
+                            shape.width, //width
+                            shape.height, //height
                             Point::new(0.5,0.5), //anchor
                         );
                         let vertex_uniform_printing_buffer = device.create_buffer(
@@ -220,7 +255,7 @@ impl Renderer {
                         );
                     //fragment
                         let fragment_data = FragmentUniformData::new(
-                            Colour::new(rng.gen::<f32>(), rng.gen::<f32>(), rng.gen::<f32>(), 1.0),
+                            shape.colour,
                         );
                         let fragment_uniform_printing_buffer = device.create_buffer(
                             &wgpu::BufferDescriptor {
@@ -311,47 +346,55 @@ impl Renderer {
                     }
                 );  
             //pipelines
-                let render_pipeline = device.create_render_pipeline(
-                    &wgpu::RenderPipelineDescriptor {
-                        label: Some(&"Render Pipeline"),
-                        layout: Some(&render_pipeline_layout),
-                        vertex_stage: wgpu::ProgrammableStageDescriptor {
-                            module: &vertex_shader_module,
-                            entry_point: "main",
-                        },
-                        fragment_stage: Some(wgpu::ProgrammableStageDescriptor {
-                            module: &fragment_shader_module,
-                            entry_point: "main",
-                        }),
-                        rasterization_state: Some(wgpu::RasterizationStateDescriptor {
-                            front_face: wgpu::FrontFace::Cw,
-                            cull_mode: wgpu::CullMode::Back,
-                            depth_bias: 0,
-                            depth_bias_slope_scale: 0.0,
-                            depth_bias_clamp: 0.0,
-                            clamp_depth: false,
-                        }),
-                        primitive_topology: wgpu::PrimitiveTopology::TriangleList,
-                        color_states: &[wgpu::ColorStateDescriptor {
-                            format: swap_chain_descriptor.format,
-                            color_blend: wgpu::BlendDescriptor {
-                                src_factor: wgpu::BlendFactor::One,
-                                dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
-                                operation: wgpu::BlendOperation::Add,
-                            },
-                            alpha_blend: wgpu::BlendDescriptor::REPLACE,
-                            write_mask: wgpu::ColorWrite::ALL,
-                        }],
-                        depth_stencil_state: None,
-                        vertex_state: wgpu::VertexStateDescriptor {
-                            index_format: wgpu::IndexFormat::Uint16,
-                            vertex_buffers: &[library::Vertex::desc()],
-                        },
-                        sample_count: 4,
-                        sample_mask: !0,
-                        alpha_to_coverage_enabled: false,
+                //one for each blend used, chosen between draw by draw
+                //(every tenth multiplies, and the one after it screens, so that both modes get drawn)
+                let draw_blends:Vec<DrawBlend> = (0..rectangle_count).map(|index| match index % 10 {
+                    8 => DrawBlend::Mode(BlendMode::Multiply),
+                    9 => DrawBlend::Mode(BlendMode::Screen),
+                    _ => DrawBlend::new_default(),
+                }).collect();
+                let mut render_pipelines:HashMap<DrawBlend, wgpu::RenderPipeline> = HashMap::new();
+                for draw_blend in &draw_blends {
+                    if render_pipelines.contains_key(draw_blend) { continue; }
+                    //the default blend writes the source's alpha straight out, as this test always has
+                    let mut color_state = library::produce_color_state_descriptor(swap_chain_descriptor.format, draw_blend);
+                    if *draw_blend == DrawBlend::new_default() {
+                        color_state.alpha_blend = wgpu::BlendDescriptor::REPLACE;
                     }
-                );
+                    let render_pipeline = device.create_render_pipeline(
+                        &wgpu::RenderPipelineDescriptor {
+                            label: Some(&"Render Pipeline"),
+                            layout: Some(&render_pipeline_layout),
+                            vertex_stage: wgpu::ProgrammableStageDescriptor {
+                                module: &vertex_shader_module,
+                                entry_point: "main",
+                            },
+                            fragment_stage: Some(wgpu::ProgrammableStageDescriptor {
+                                module: &fragment_shader_module,
+                                entry_point: "main",
+                            }),
+                            rasterization_state: Some(wgpu::RasterizationStateDescriptor {
+                                front_face: wgpu::FrontFace::Cw,
+                                cull_mode: wgpu::CullMode::Back,
+                                depth_bias: 0,
+                                depth_bias_slope_scale: 0.0,
+                                depth_bias_clamp: 0.0,
+                                clamp_depth: false,
+                            }),
+                            primitive_topology: wgpu::PrimitiveTopology::TriangleList,
+                            color_states: &[color_state],
+                            depth_stencil_state: None,
+                            vertex_state: wgpu::VertexStateDescriptor {
+                                index_format: wgpu::IndexFormat::Uint16,
+                                vertex_buffers: &[library::Vertex::desc()],
+                            },
+                            sample_count: 4,
+                            sample_mask: !0,
+                            alpha_to_coverage_enabled: false,
+                        }
+                    );
+                    render_pipelines.insert(*draw_blend, render_pipeline);
+                }
 
         //setup frame
             let frame = match swap_chain.get_current_frame() {
@@ -416,13 +459,17 @@ impl Renderer {
                     }
                 );
 
-                //set pipeline
-                    render_pass.set_pipeline(&render_pipeline);
                 //set vertex buffer
                     render_pass.set_vertex_buffer(0, vertex_buffer.slice(..));
 
                 // for round in 0..70 {
+                    let mut current_draw_blend:Option<DrawBlend> = None;
                     for index in 0..uniform_bind_group_vector.len() {
+                        //set pipeline, if the blend has changed
+                            if current_draw_blend != Some(draw_blends[index]) {
+                                render_pass.set_pipeline(&render_pipelines[&draw_blends[index]]);
+                                current_draw_blend = Some(draw_blends[index]);
+                            }
                         //set uniform buffer group
                             render_pass.set_bind_group(0, &uniform_bind_group_vector[index], &[]);
                         //draw
@@ -499,7 +546,7 @@ impl Renderer {
 
         //uniforms
             //uniform buffers
-                let mut rng = rand::thread_rng();
+                let scene = SceneDescription::new_default(TEST_SCENE_SEED, rectangle_count);
 
                 //vertex
                     let vertex_uniform_buffer = device.create_buffer(
@@ -510,15 +557,15 @@ impl Renderer {
                             mapped_at_creation: false,
                         }
                     );
-                    for index in 0..rectangle_count {
+                    for (index, shape) in scene.iter().enumerate() {
                         let vertex_data = VertexUniformData::new(
-                            500.0*rng.gen::<f32>(), //x
-                            400.0*rng.gen::<f32>(), //y
+                            shape.x, //x
+                            shape.y, //y
                             1.0, //scale
-                            2.0 * std::f32::consts::PI * rng.gen::<f32>(), //angle
+                            shape.angle, //angle
                             Dimensions::<u32>::new(500,400), //dimensions
-                            30.0, //width
-                            30.0, //height
+                            shape.width, //width
+                            shape.height, //height
                             Point::new(0.5,0.5), //anchor
                         );
                         queue.write_buffer(
@@ -537,9 +584,9 @@ impl Renderer {
                             mapped_at_creation: false,
                         }
                     );
-                    for index in 0..rectangle_count {
+                    for (index, shape) in scene.iter().enumerate() {
                         let fragment_data = FragmentUniformData::new(
-                            Colour::new(rng.gen::<f32>(), rng.gen::<f32>(), rng.gen::<f32>(), 1.0),
+                            shape.colour,
                         );
                         queue.write_buffer(
                             &fragment_uniform_buffer, 
@@ -838,7 +885,8 @@ impl Renderer {
 
         //uniforms
             //uniform buffers
-                let mut rng = rand::thread_rng();
+                let mut scene = SceneDescription::new_default(TEST_SCENE_SEED, rectangle_count);
+                scene.set_palette( distinguishable_colours(16) );
 
                 // println!("{} {}", std::mem::size_of::<VertexUniformData>(), wgpu::BIND_BUFFER_ALIGNMENT);
 
@@ -853,15 +901,15 @@ impl Renderer {
                     );
                     println!("renderer >> vertex uniform buffer created: {:?}", std::time::Instant::now().checked_duration_since(start_time) );
                     start_time = std::time::Instant::now();
-                    for index in 0..rectangle_count {
+                    for (index, shape) in scene.iter().enumerate() {
                         let vertex_data = VertexUniformData::new(
-                            500.0*rng.gen::<f32>(), //x
-                            400.0*rng.gen::<f32>(), //y
+                            shape.x, //x
+                            shape.y, //y
                             1.0, //scale
-                            2.0 * std::f32::consts::PI * rng.gen::<f32>(), //angle
+                            shape.angle, //angle
                             Dimensions::<u32>::new(500,400), //dimensions
-                            30.0, //width
-                            30.0, //height
+                            shape.width, //width
+                            shape.height, //height
                             Point::new(0.5,0.5), //anchor
                         );
                         queue.write_buffer(
@@ -884,9 +932,9 @@ impl Renderer {
                     );
                     println!("renderer >> fragment uniform buffer created: {:?}", std::time::Instant::now().checked_duration_since(start_time) );
                     start_time = std::time::Instant::now();
-                    for index in 0..rectangle_count {
+                    for (index, shape) in scene.iter().enumerate() {
                         let fragment_data = FragmentUniformData::new(
-                            Colour::new(rng.gen::<f32>(), rng.gen::<f32>(), rng.gen::<f32>(), 1.0),
+                            shape.colour,
                         );
                         queue.write_buffer(
                             &fragment_uniform_buffer, 
@@ -1241,4 +1289,1401 @@ impl Renderer {
             println!("renderer >> submission complete: {:?}", std::time::Instant::now().checked_duration_since(start_time) );
             // start_time = std::time::Instant::now();
     }
+    pub fn test4(&mut self) {
+        println!("- test 4 -");
+        //gradients
+
+        let rectangle_count = 1_000;
+
+        //adapter
+            let adapter = futures::executor::block_on(
+                self.instance.request_adapter(
+                    &wgpu::RequestAdapterOptions {
+                        power_preference: wgpu::PowerPreference::Default,
+                        compatible_surface: Some(&self.surface),
+                    }
+                )
+            ).unwrap();
+
+        //device and queue
+            let (device, queue) = futures::executor::block_on(
+                adapter.request_device(
+                    &wgpu::DeviceDescriptor {
+                        features: wgpu::Features::empty(),
+                        limits: wgpu::Limits::default(),
+                        shader_validation: true,
+                    },
+                    None,
+                )
+            ).unwrap();
+
+        //swap chain
+            let swap_chain_descriptor = wgpu::SwapChainDescriptor {
+                usage: wgpu::TextureUsage::OUTPUT_ATTACHMENT,
+                format: wgpu::TextureFormat::Bgra8UnormSrgb,
+                width: self.size.width,
+                height: self.size.height,
+                present_mode: wgpu::PresentMode::Fifo,
+            };
+            let mut swap_chain = device.create_swap_chain( &self.surface, &swap_chain_descriptor );
+
+        //framebuffer
+            let multisampled_texture_extent = wgpu::Extent3d {
+                width: swap_chain_descriptor.width,
+                height: swap_chain_descriptor.height,
+                depth: 1,
+            };
+            let multisampled_frame_descriptor = &wgpu::TextureDescriptor {
+                size: multisampled_texture_extent,
+                mip_level_count: 1,
+                sample_count: 4,
+                dimension: wgpu::TextureDimension::D2,
+                format: swap_chain_descriptor.format,
+                usage: wgpu::TextureUsage::all(),
+                label: Some("multisampled framebuffer"),
+            };
+            let framebuffer = device.create_texture(multisampled_frame_descriptor).create_view(&wgpu::TextureViewDescriptor::default());
+
+        //create shader modules
+            let vertex_shader_module = device.create_shader_module( wgpu::include_spirv!("shader_gradient.vert.spv") );
+            let fragment_shader_module = device.create_shader_module( wgpu::include_spirv!("shader_gradient.frag.spv") );
+
+
+        //uniforms
+            let uniform_bind_group_layout = device.create_bind_group_layout(
+                &wgpu::BindGroupLayoutDescriptor {
+                    label: Some("Uniform Bind Group Layout"),
+                    entries: &[
+                        wgpu::BindGroupLayoutEntry {
+                            binding: 0,
+                            visibility: wgpu::ShaderStage::VERTEX,
+                            ty: wgpu::BindingType::UniformBuffer {
+                                dynamic: false,
+                                min_binding_size: None,
+                            },
+                            count: None,
+                        },
+                        wgpu::BindGroupLayoutEntry {
+                            binding: 1,
+                            visibility: wgpu::ShaderStage::FRAGMENT,
+                            ty: wgpu::BindingType::UniformBuffer {
+                                dynamic: false,
+                                min_binding_size: None,
+                            },
+                            count: None,
+                        },
+                    ],
+                }
+            );
+
+            let scene = SceneDescription::new_default(TEST_SCENE_SEED, rectangle_count);
+
+            let mut uniform_bind_group_vector:Vec<wgpu::BindGroup> = vec![];
+            for (index, shape) in scene.iter().enumerate() {
+                //uniform buffers
+                    //vertex
+                        let vertex_data = VertexUniformData::new(
+                            shape.x, //x
+                            shape.y, //y
+                            1.0, //scale
+                            shape.angle, //angle
+                            Dimensions::<u32>::new(500,400), //dimensions
+                            shape.width, //width
+                            shape.height, //height
+                            Point::new(0.5,0.5), //anchor
+                        );
+                        let vertex_uniform_printing_buffer = device.create_buffer(
+                            &wgpu::BufferDescriptor {
+                                label: Some("Vertex Uniform Printing Buffer"),
+                                size: std::mem::size_of::<VertexUniformData>() as wgpu::BufferAddress,
+                                usage: wgpu::BufferUsage::UNIFORM | wgpu::BufferUsage::COPY_DST,
+                                mapped_at_creation: false,
+                            }
+                        );
+                        queue.write_buffer(
+                            &vertex_uniform_printing_buffer, 
+                            0, 
+                            bytemuck::cast_slice(&[vertex_data])
+                        );
+                    //fragment
+                        //each rectangle's gradient is laid out around its centre, and turned with it (the vertex shader
+                        //turns shapes the opposite way to Offset)
+                        let stops = vec![
+                            ColourStop::new(0.0, shape.colour),
+                            ColourStop::new(1.0, Colour::new(1.0, 1.0, 1.0, 1.0)),
+                        ];
+                        let half_width = shape.width / 2.0;
+                        let mut gradient = match index % 3 {
+                            0 => Gradient::new_linear(Point::new(-half_width, 0.0), Point::new(half_width, 0.0), stops),
+                            1 => Gradient::new_radial(Point::new(0.0, 0.0), half_width, stops),
+                            _ => Gradient::new_conic(Point::new(0.0, 0.0), 0.0, stops),
+                        };
+                        gradient.set_transform(Offset::new(shape.x, shape.y, 1.0, -shape.angle));
+                        let fragment_data = GradientFragmentUniformData::new(&gradient);
+                        let fragment_uniform_printing_buffer = device.create_buffer(
+                            &wgpu::BufferDescriptor {
+                                label: Some("Fragment Uniform Printing Buffer"),
+                                size: std::mem::size_of::<GradientFragmentUniformData>() as wgpu::BufferAddress,
+                                usage: wgpu::BufferUsage::UNIFORM | wgpu::BufferUsage::COPY_DST,
+                                mapped_at_creation: false,
+                            }
+                        );
+                        queue.write_buffer(
+                            &fragment_uniform_printing_buffer, 
+                            0, 
+                            bytemuck::cast_slice(&[fragment_data])
+                        );
+
+                //uniform bind group
+                    let uniform_bind_group = device.create_bind_group(
+                        &wgpu::BindGroupDescriptor {
+                            label: Some("Uniform Bind Group"),
+                            layout: &uniform_bind_group_layout,
+                            entries: &[
+                                wgpu::BindGroupEntry {
+                                    binding: 0,
+                                    resource: wgpu::BindingResource::Buffer(vertex_uniform_printing_buffer.slice(..))
+                                },
+                                wgpu::BindGroupEntry {
+                                    binding: 1,
+                                    resource: wgpu::BindingResource::Buffer(fragment_uniform_printing_buffer.slice(..))
+                                }
+                            ],
+                        }
+                    );
+
+                //add to vector
+                    uniform_bind_group_vector.push(
+                        uniform_bind_group
+                    );
+            }
+            
+            println!("renderer >> uniforms created");
+
+        //vertex buffer
+            //two triangles covering the unit square
+            let vertex_point_data:Vec<library::Vertex> = [
+                [0.0,0.0], [1.0,0.0], [1.0,1.0],
+                [0.0,0.0], [1.0,1.0], [0.0,1.0],
+            ].iter().map(|point| library::Vertex::new(*point)).collect();
+
+            let vertex_buffer = device.create_buffer_init(
+                &wgpu::util::BufferInitDescriptor {
+                    label: Some("Vertex Buffer"),
+                    contents: bytemuck::cast_slice(&vertex_point_data),
+                    usage: wgpu::BufferUsage::VERTEX,
+                }
+            );
+
+        //render pipelines
+            //layout
+                let render_pipeline_layout = device.create_pipeline_layout(
+                    &wgpu::PipelineLayoutDescriptor {
+                        label: Some("Render Pipeline Layout"),
+                        bind_group_layouts: &[
+                            &uniform_bind_group_layout
+                        ],
+                        push_constant_ranges: &[],
+                    }
+                );  
+            //pipelines
+                //one for each blend used, chosen between draw by draw
+                let draw_blends:Vec<DrawBlend> = vec![DrawBlend::new_default(); rectangle_count];
+                let mut render_pipelines:HashMap<DrawBlend, wgpu::RenderPipeline> = HashMap::new();
+                for draw_blend in &draw_blends {
+                    if render_pipelines.contains_key(draw_blend) { continue; }
+                    let render_pipeline = device.create_render_pipeline(
+                        &wgpu::RenderPipelineDescriptor {
+                            label: Some("Render Pipeline"),
+                            layout: Some(&render_pipeline_layout),
+                            vertex_stage: wgpu::ProgrammableStageDescriptor {
+                                module: &vertex_shader_module,
+                                entry_point: "main",
+                            },
+                            fragment_stage: Some(wgpu::ProgrammableStageDescriptor {
+                                module: &fragment_shader_module,
+                                entry_point: "main",
+                            }),
+                            rasterization_state: Some(wgpu::RasterizationStateDescriptor {
+                                front_face: wgpu::FrontFace::Cw,
+                                cull_mode: wgpu::CullMode::Back,
+                                depth_bias: 0,
+                                depth_bias_slope_scale: 0.0,
+                                depth_bias_clamp: 0.0,
+                                clamp_depth: false,
+                            }),
+                            primitive_topology: wgpu::PrimitiveTopology::TriangleList,
+                            color_states: &[library::produce_color_state_descriptor(swap_chain_descriptor.format, draw_blend)],
+                            depth_stencil_state: None,
+                            vertex_state: wgpu::VertexStateDescriptor {
+                                index_format: wgpu::IndexFormat::Uint16,
+                                vertex_buffers: &[library::Vertex::desc()],
+                            },
+                            sample_count: 4,
+                            sample_mask: !0,
+                            alpha_to_coverage_enabled: false,
+                        }
+                    );
+                    render_pipelines.insert(*draw_blend, render_pipeline);
+                }
+
+        //setup frame
+            let frame = match swap_chain.get_current_frame() {
+                Err(e) => {
+                    println!("ERROR - Renderer : swap_chain.get_current_frame failed! {}", e);
+                    return;
+                },
+                Ok(frame) => frame.output,
+            };
+    
+        //create encoder
+            let mut encoder = device.create_command_encoder(
+                &wgpu::CommandEncoderDescriptor {
+                    label: Some("Renderer : Command Encoder"),
+                }
+            );
+
+        //clear frame
+            {
+                encoder.begin_render_pass(
+                    &wgpu::RenderPassDescriptor {
+                        color_attachments: &[
+                            wgpu::RenderPassColorAttachmentDescriptor {
+                                attachment: &framebuffer,
+                                resolve_target: Some(&frame.view),
+                                ops: wgpu::Operations {
+                                    load: wgpu::LoadOp::Clear(
+                                        wgpu::Color {
+                                            r: 1.0,
+                                            g: 1.0,
+                                            b: 1.0,
+                                            a: 1.0,
+                                        }
+                                    ),
+                                    store: true,
+                                },
+                            }
+                        ],
+                        depth_stencil_attachment: None,
+                    }
+                );
+            }
+
+        println!("renderer >> beginning main render pass");
+
+        //begin main render pass
+            let start_time = std::time::Instant::now();
+            {
+                let mut render_pass = encoder.begin_render_pass(
+                    &wgpu::RenderPassDescriptor {
+                        color_attachments: &[
+                            wgpu::RenderPassColorAttachmentDescriptor {
+                                attachment: &framebuffer,
+                                resolve_target: Some(&frame.view),
+                                ops: wgpu::Operations {
+                                    load: wgpu::LoadOp::Load,
+                                    store: true,
+                                },
+                            }
+                        ],
+                        depth_stencil_attachment: None,
+                    }
+                );
+
+                //set vertex buffer
+                    render_pass.set_vertex_buffer(0, vertex_buffer.slice(..));
+
+                // for round in 0..70 {
+                    let mut current_draw_blend:Option<DrawBlend> = None;
+                    for index in 0..uniform_bind_group_vector.len() {
+                        //set pipeline, if the blend has changed
+                            if current_draw_blend != Some(draw_blends[index]) {
+                                render_pass.set_pipeline(&render_pipelines[&draw_blends[index]]);
+                                current_draw_blend = Some(draw_blends[index]);
+                            }
+                        //set uniform buffer group
+                            render_pass.set_bind_group(0, &uniform_bind_group_vector[index], &[]);
+                        //draw
+                            render_pass.draw(0..vertex_point_data.len() as u32, 0..1);
+                    }
+                // }
+            }
+            let end_time = std::time::Instant::now();
+            println!("{:?}", end_time.checked_duration_since(start_time) );
+
+        //submit encoder to queue, to be rendered
+            queue.submit(std::iter::once(encoder.finish()));
+    }
+
+    pub fn test5(&mut self) {
+        println!("- test 5 -");
+        //viewports; the scene drawn through the orchestrator's viewports (as last sent), which are set up as the scene
+        //side by side with a turned and zoomed copy of itself, and a minimap (showing only every third shape) in the
+        //corner. The renderer keeps the viewport drawing this sets up, and draws it again whenever the viewports
+        //change, so panning, zooming and rotating show up
+
+        let rectangle_count = 1_000;
+        let layer_count = 3;
+
+        let scene = SceneDescription::new_default(TEST_SCENE_SEED, rectangle_count);
+        let shapes = scene.iter().enumerate().map(|(index, shape)| {
+            ViewportShape::new(index % layer_count, shape.x, shape.y, shape.width, shape.height, shape.angle, shape.colour)
+        }).collect();
+
+        let mut viewport_drawing = ViewportDrawing::new(&self.instance, &self.surface, self.size);
+        viewport_drawing.set_shapes(shapes);
+
+        let start_time = std::time::Instant::now();
+        viewport_drawing.draw(&self.viewports);
+        let end_time = std::time::Instant::now();
+        println!("{:?}", end_time.checked_duration_since(start_time) );
+
+        self.viewport_drawing = Some(viewport_drawing);
+    }
+
+    pub fn test6(&mut self) {
+        println!("- test 6 -");
+        //scene graph; the scene split into its four quarters, each a group turned, scaled and faded by its own
+        //amount, with the last quarter nested inside the third (so it picks up both groups' changes)
+
+        let rectangle_count = 1_000;
+
+        //adapter
+            let adapter = futures::executor::block_on(
+                self.instance.request_adapter(
+                    &wgpu::RequestAdapterOptions {
+                        power_preference: wgpu::PowerPreference::Default,
+                        compatible_surface: Some(&self.surface),
+                    }
+                )
+            ).unwrap();
+
+        //device and queue
+            let (device, queue) = futures::executor::block_on(
+                adapter.request_device(
+                    &wgpu::DeviceDescriptor {
+                        features: wgpu::Features::empty(),
+                        limits: wgpu::Limits::default(),
+                        shader_validation: true,
+                    },
+                    None,
+                )
+            ).unwrap();
+
+        //swap chain
+            let swap_chain_descriptor = wgpu::SwapChainDescriptor {
+                usage: wgpu::TextureUsage::OUTPUT_ATTACHMENT,
+                format: wgpu::TextureFormat::Bgra8UnormSrgb,
+                width: self.size.width,
+                height: self.size.height,
+                present_mode: wgpu::PresentMode::Fifo,
+            };
+            let mut swap_chain = device.create_swap_chain( &self.surface, &swap_chain_descriptor );
+
+        //framebuffer
+            let multisampled_texture_extent = wgpu::Extent3d {
+                width: swap_chain_descriptor.width,
+                height: swap_chain_descriptor.height,
+                depth: 1,
+            };
+            let multisampled_frame_descriptor = &wgpu::TextureDescriptor {
+                size: multisampled_texture_extent,
+                mip_level_count: 1,
+                sample_count: 4,
+                dimension: wgpu::TextureDimension::D2,
+                format: swap_chain_descriptor.format,
+                usage: wgpu::TextureUsage::all(),
+                label: Some("multisampled framebuffer"),
+            };
+            let framebuffer = device.create_texture(multisampled_frame_descriptor).create_view(&wgpu::TextureViewDescriptor::default());
+
+        //create shader modules
+            let vertex_shader_module = device.create_shader_module( wgpu::include_spirv!("shader.vert.spv") );
+            let fragment_shader_module = device.create_shader_module( wgpu::include_spirv!("shader.frag.spv") );
+
+        //viewbox
+            let scene = SceneDescription::new_default(TEST_SCENE_SEED, rectangle_count);
+            let scene_area = BoundingBox::new(0.0, 0.0, scene.get_area_width(), scene.get_area_height());
+            let logical_size = self.size.to_logical::<u32>(self.device_pixel_density_ratio);
+            let mut viewbox = Viewbox::new(0.0, 0.0, 1.0, 0.0, 0.5, 0.5, logical_size.width, logical_size.height);
+            viewbox.set_device_pixel_ratio(self.device_pixel_density_ratio as f32);
+            let mut viewbox = viewbox.get_fitted_to_bounding_box(&scene_area, 10.0);
+            //closer in than fitted, and turned a little, so that some of the scene is culled
+            viewbox.set_scale(viewbox.get_scale() * 1.6);
+            viewbox.set_angle(0.1);
+
+        //scene graph
+            let mut scene_graph = SceneGraph::new();
+            let half_width = scene.get_area_width() / 2.0;
+            let half_height = scene.get_area_height() / 2.0;
+
+            //each group sits on the middle of its quarter, so turning or scaling it happens about there
+                let quarter_centres = [
+                    (half_width / 2.0, half_height / 2.0),
+                    (half_width * 1.5, half_height / 2.0),
+                    (half_width / 2.0, half_height * 1.5),
+                    (half_width * 1.5, half_height * 1.5),
+                ];
+                let root = scene_graph.get_root();
+                let groups = [
+                    scene_graph.add_group(root, Offset::new(quarter_centres[0].0, quarter_centres[0].1, 1.0, 0.0)).unwrap(),
+                    scene_graph.add_group(root, Offset::new(quarter_centres[1].0, quarter_centres[1].1, 0.8, 0.2)).unwrap(),
+                    scene_graph.add_group(root, Offset::new(quarter_centres[2].0, quarter_centres[2].1, 1.0, -0.1)).unwrap(),
+                ];
+                let nested_group = scene_graph.add_group(
+                    groups[2],
+                    Offset::new(quarter_centres[3].0 - quarter_centres[2].0, quarter_centres[3].1 - quarter_centres[2].1, 0.9, 0.15),
+                ).unwrap();
+                scene_graph.set_opacity(groups[1], 0.75);
+                scene_graph.set_opacity(groups[2], 0.8);
+                scene_graph.set_opacity(nested_group, 0.6);
+
+            //shapes go into the group of the quarter they're in, placed relative to its middle
+                let quarter_groups = [groups[0], groups[1], groups[2], nested_group];
+                for shape in scene.iter() {
+                    let quarter = (shape.x >= half_width) as usize + 2 * (shape.y >= half_height) as usize;
+                    let (centre_x, centre_y) = quarter_centres[quarter];
+                    scene_graph.add_element(
+                        quarter_groups[quarter],
+                        Offset::new(shape.x - centre_x, shape.y - centre_y, 1.0, -shape.angle), //the vertex shader turns shapes the opposite way to Offset
+                        Element::new_rectangle(shape.width, shape.height, Point::new(0.5,0.5), shape.colour),
+                    );
+                }
+
+        //uniforms
+            let uniform_bind_group_layout = device.create_bind_group_layout(
+                &wgpu::BindGroupLayoutDescriptor {
+                    label: Some("Uniform Bind Group Layout"),
+                    entries: &[
+                        wgpu::BindGroupLayoutEntry {
+                            binding: 0,
+                            visibility: wgpu::ShaderStage::VERTEX,
+                            ty: wgpu::BindingType::UniformBuffer {
+                                dynamic: false,
+                                min_binding_size: None,
+                            },
+                            count: None,
+                        },
+                        wgpu::BindGroupLayoutEntry {
+                            binding: 1,
+                            visibility: wgpu::ShaderStage::FRAGMENT,
+                            ty: wgpu::BindingType::UniformBuffer {
+                                dynamic: false,
+                                min_binding_size: None,
+                            },
+                            count: None,
+                        },
+                    ],
+                }
+            );
+            let create_uniform_bind_group = |vertex_data:VertexUniformData, fragment_data:FragmentUniformData| -> wgpu::BindGroup {
+                let vertex_uniform_printing_buffer = device.create_buffer_init(
+                    &wgpu::util::BufferInitDescriptor {
+                        label: Some("Vertex Uniform Printing Buffer"),
+                        contents: bytemuck::cast_slice(&[vertex_data]),
+                        usage: wgpu::BufferUsage::UNIFORM,
+                    }
+                );
+                let fragment_uniform_printing_buffer = device.create_buffer_init(
+                    &wgpu::util::BufferInitDescriptor {
+                        label: Some("Fragment Uniform Printing Buffer"),
+                        contents: bytemuck::cast_slice(&[fragment_data]),
+                        usage: wgpu::BufferUsage::UNIFORM,
+                    }
+                );
+                device.create_bind_group(
+                    &wgpu::BindGroupDescriptor {
+                        label: Some("Uniform Bind Group"),
+                        layout: &uniform_bind_group_layout,
+                        entries: &[
+                            wgpu::BindGroupEntry {
+                                binding: 0,
+                                resource: wgpu::BindingResource::Buffer(vertex_uniform_printing_buffer.slice(..))
+                            },
+                            wgpu::BindGroupEntry {
+                                binding: 1,
+                                resource: wgpu::BindingResource::Buffer(fragment_uniform_printing_buffer.slice(..))
+                            }
+                        ],
+                    }
+                )
+            };
+
+            //every element the graph gives back (leaving out those outside the view), in order, with the group opacities
+            //multiplied into their colours
+            println!("renderer >> render decisions : {}", scene_graph.decide_rendering(&viewbox));
+            let flattened = scene_graph.flatten_rendered();
+            let mut bind_groups = vec![];
+            for flattened_element in &flattened {
+                let placement = Offset::combine( &flattened_element.offset, viewbox.get_offset() );
+                match flattened_element.element.get_kind() {
+                    ElementKind::Rectangle { width, height, anchor, colour } => {
+                        bind_groups.push(create_uniform_bind_group(
+                            VertexUniformData::new(
+                                placement.get_x(), //x
+                                placement.get_y(), //y
+                                placement.get_scale(), //scale
+                                -placement.get_angle(), //angle
+                                Dimensions::<u32>::new(viewbox.get_width(), viewbox.get_height()), //dimensions
+                                *width, //width
+                                *height, //height
+                                *anchor, //anchor
+                            ),
+                            FragmentUniformData::new(
+                                Colour::new(colour.r(), colour.g(), colour.b(), colour.a() * flattened_element.opacity)
+                            ),
+                        ));
+                    },
+                    ElementKind::Image { .. } => {}, //this scene has none; see test8
+                }
+            }
+            println!("renderer >> {} elements drawn from a graph of {} nodes", flattened.len(), scene_graph.len());
+
+        //vertex buffer
+            //two triangles covering the unit square
+            let vertex_point_data:Vec<library::Vertex> = [
+                [0.0,0.0], [1.0,0.0], [1.0,1.0],
+                [0.0,0.0], [1.0,1.0], [0.0,1.0],
+            ].iter().map(|point| library::Vertex::new(*point)).collect();
+
+            let vertex_buffer = device.create_buffer_init(
+                &wgpu::util::BufferInitDescriptor {
+                    label: Some("Vertex Buffer"),
+                    contents: bytemuck::cast_slice(&vertex_point_data),
+                    usage: wgpu::BufferUsage::VERTEX,
+                }
+            );
+
+        //render pipeline
+            let render_pipeline_layout = device.create_pipeline_layout(
+                &wgpu::PipelineLayoutDescriptor {
+                    label: Some("Render Pipeline Layout"),
+                    bind_group_layouts: &[
+                        &uniform_bind_group_layout
+                    ],
+                    push_constant_ranges: &[],
+                }
+            );
+            let render_pipeline = device.create_render_pipeline(
+                &wgpu::RenderPipelineDescriptor {
+                    label: Some("Render Pipeline"),
+                    layout: Some(&render_pipeline_layout),
+                    vertex_stage: wgpu::ProgrammableStageDescriptor {
+                        module: &vertex_shader_module,
+                        entry_point: "main",
+                    },
+                    fragment_stage: Some(wgpu::ProgrammableStageDescriptor {
+                        module: &fragment_shader_module,
+                        entry_point: "main",
+                    }),
+                    rasterization_state: Some(wgpu::RasterizationStateDescriptor {
+                        front_face: wgpu::FrontFace::Cw,
+                        cull_mode: wgpu::CullMode::Back,
+                        depth_bias: 0,
+                        depth_bias_slope_scale: 0.0,
+                        depth_bias_clamp: 0.0,
+                        clamp_depth: false,
+                    }),
+                    primitive_topology: wgpu::PrimitiveTopology::TriangleList,
+                    color_states: &[library::produce_color_state_descriptor(swap_chain_descriptor.format, &DrawBlend::new_default())],
+                    depth_stencil_state: None,
+                    vertex_state: wgpu::VertexStateDescriptor {
+                        index_format: wgpu::IndexFormat::Uint16,
+                        vertex_buffers: &[library::Vertex::desc()],
+                    },
+                    sample_count: 4,
+                    sample_mask: !0,
+                    alpha_to_coverage_enabled: false,
+                }
+            );
+
+        //setup frame
+            let frame = match swap_chain.get_current_frame() {
+                Err(e) => {
+                    println!("ERROR - Renderer : swap_chain.get_current_frame failed! {}", e);
+                    return;
+                },
+                Ok(frame) => frame.output,
+            };
+
+        //create encoder
+            let mut encoder = device.create_command_encoder(
+                &wgpu::CommandEncoderDescriptor {
+                    label: Some("Renderer : Command Encoder"),
+                }
+            );
+
+        println!("renderer >> beginning main render pass");
+
+        //begin main render pass
+            let start_time = std::time::Instant::now();
+            {
+                let mut render_pass = encoder.begin_render_pass(
+                    &wgpu::RenderPassDescriptor {
+                        color_attachments: &[
+                            wgpu::RenderPassColorAttachmentDescriptor {
+                                attachment: &framebuffer,
+                                resolve_target: Some(&frame.view),
+                                ops: wgpu::Operations {
+                                    load: wgpu::LoadOp::Clear(
+                                        wgpu::Color {
+                                            r: 1.0,
+                                            g: 1.0,
+                                            b: 1.0,
+                                            a: 1.0,
+                                        }
+                                    ),
+                                    store: true,
+                                },
+                            }
+                        ],
+                        depth_stencil_attachment: None,
+                    }
+                );
+
+                render_pass.set_pipeline(&render_pipeline);
+                render_pass.set_vertex_buffer(0, vertex_buffer.slice(..));
+
+                for bind_group in &bind_groups {
+                    render_pass.set_bind_group(0, bind_group, &[]);
+                    render_pass.draw(0..vertex_point_data.len() as u32, 0..1);
+                }
+            }
+            let end_time = std::time::Instant::now();
+            println!("{:?}", end_time.checked_duration_since(start_time) );
+
+        //submit encoder to queue, to be rendered
+            queue.submit(std::iter::once(encoder.finish()));
+    }
+
+    pub fn test7(&mut self) {
+        println!("- test 7 -");
+        //text; a few pieces laid out with different alignments and positions, each with its bounding box drawn
+        //behind it, and the glyphs drawn from an atlas
+
+        //font
+            //none is shipped, so use whatever the system has
+            let font_paths = [
+                "/usr/share/fonts/truetype/dejavu/DejaVuSans.ttf",
+                "/usr/share/fonts/TTF/DejaVuSans.ttf",
+                "/System/Library/Fonts/Supplemental/Arial.ttf",
+                "/Library/Fonts/Arial.ttf",
+                "C:\\Windows\\Fonts\\arial.ttf",
+            ];
+            let font = match font_paths.iter().find(|path| std::path::Path::new(path).exists()) {
+                Some(path) => match Font::new_from_file(path) {
+                    Some(font) => font,
+                    None => return,
+                },
+                None => {
+                    println!("WARNING - renderer - test7 : no font found (looked for {:?})", font_paths);
+                    return;
+                },
+            };
+
+        //adapter
+            let adapter = futures::executor::block_on(
+                self.instance.request_adapter(
+                    &wgpu::RequestAdapterOptions {
+                        power_preference: wgpu::PowerPreference::Default,
+                        compatible_surface: Some(&self.surface),
+                    }
+                )
+            ).unwrap();
+
+        //device and queue
+            let (device, queue) = futures::executor::block_on(
+                adapter.request_device(
+                    &wgpu::DeviceDescriptor {
+                        features: wgpu::Features::empty(),
+                        limits: wgpu::Limits::default(),
+                        shader_validation: true,
+                    },
+                    None,
+                )
+            ).unwrap();
+
+        //swap chain
+            let swap_chain_descriptor = wgpu::SwapChainDescriptor {
+                usage: wgpu::TextureUsage::OUTPUT_ATTACHMENT,
+                format: wgpu::TextureFormat::Bgra8UnormSrgb,
+                width: self.size.width,
+                height: self.size.height,
+                present_mode: wgpu::PresentMode::Fifo,
+            };
+            let mut swap_chain = device.create_swap_chain( &self.surface, &swap_chain_descriptor );
+
+        //framebuffer
+            let multisampled_texture_extent = wgpu::Extent3d {
+                width: swap_chain_descriptor.width,
+                height: swap_chain_descriptor.height,
+                depth: 1,
+            };
+            let multisampled_frame_descriptor = &wgpu::TextureDescriptor {
+                size: multisampled_texture_extent,
+                mip_level_count: 1,
+                sample_count: 4,
+                dimension: wgpu::TextureDimension::D2,
+                format: swap_chain_descriptor.format,
+                usage: wgpu::TextureUsage::all(),
+                label: Some("multisampled framebuffer"),
+            };
+            let framebuffer = device.create_texture(multisampled_frame_descriptor).create_view(&wgpu::TextureViewDescriptor::default());
+
+        //create shader modules
+            let vertex_shader_module = device.create_shader_module( wgpu::include_spirv!("shader.vert.spv") );
+            let fragment_shader_module = device.create_shader_module( wgpu::include_spirv!("shader.frag.spv") );
+            let glyph_vertex_shader_module = device.create_shader_module( wgpu::include_spirv!("shader_texture.vert.spv") );
+            let glyph_fragment_shader_module = device.create_shader_module( wgpu::include_spirv!("shader_glyph.frag.spv") );
+
+        //layout
+            //in logical pixels, with the glyphs rasterised at the device's own pixel size so that they stay sharp
+            let logical_size = self.size.to_logical::<u32>(self.device_pixel_density_ratio);
+            let dimensions = || Dimensions::<u32>::new(logical_size.width, logical_size.height);
+            let pixel_ratio = self.device_pixel_density_ratio as f32;
+            let width = logical_size.width as f32;
+            let height = logical_size.height as f32;
+
+            let paragraph = "The quick brown fox jumps over the lazy dog. Pack my box with five dozen liquor jugs.\nSphinx of black quartz, judge my vow.";
+            let texts = [
+                ("Top left, at 32", Point::new(20.0, 20.0), TextStyle::new(32.0, 1.0, TextAlignment::Left, None, PrintingModePosition::TopLeft)),
+                ("Centred on the middle of the window\nover two lines", Point::new(width / 2.0, height / 2.0), TextStyle::new(24.0, 1.0, TextAlignment::Centre, None, PrintingModePosition::Centre)),
+                ("Bottom right, on the baseline", Point::new(width - 20.0, height - 40.0), TextStyle::new(18.0, 1.0, TextAlignment::Right, None, PrintingModePosition::BaselineRight)),
+                (paragraph, Point::new(20.0, 80.0), TextStyle::new(16.0, 1.2, TextAlignment::Left, Some(260.0), PrintingModePosition::TopLeft)),
+                (paragraph, Point::new(width - 20.0, 80.0), TextStyle::new(16.0, 1.2, TextAlignment::Right, Some(260.0), PrintingModePosition::TopRight)),
+            ];
+
+            let mut glyph_atlas = GlyphAtlas::new(1024, 1024);
+            let mut boxes = vec![];
+            let mut glyph_quads = vec![];
+            for (text, position, style) in &texts {
+                let layout = TextLayout::new(&font, text, style);
+                println!("renderer >> text layout : {}", layout);
+
+                let mut bounding_box = *layout.get_bounding_box();
+                bounding_box.translate(position.get_x(), position.get_y());
+                boxes.push(bounding_box);
+
+                for glyph in layout.get_glyphs() {
+                    if let Some(atlas_glyph) = glyph_atlas.get_glyph(&font, glyph.glyph_id, style.get_size() * pixel_ratio) {
+                        //snapped to whole device pixels, so the glyph's pixels land on the screen's
+                        let x = ((position.get_x() + glyph.position.get_x()) * pixel_ratio).round() + atlas_glyph.offset.get_x();
+                        let y = ((position.get_y() + glyph.position.get_y()) * pixel_ratio).round() + atlas_glyph.offset.get_y();
+                        glyph_quads.push((Point::new(x / pixel_ratio, y / pixel_ratio), atlas_glyph));
+                    }
+                }
+            }
+            println!("renderer >> glyph atlas : {}", glyph_atlas);
+
+        //glyph atlas texture
+            let atlas_extent = wgpu::Extent3d {
+                width: glyph_atlas.get_width(),
+                height: glyph_atlas.get_height(),
+                depth: 1,
+            };
+            let atlas_texture = device.create_texture(
+                &wgpu::TextureDescriptor {
+                    size: atlas_extent,
+                    mip_level_count: 1,
+                    sample_count: 1,
+                    dimension: wgpu::TextureDimension::D2,
+                    format: wgpu::TextureFormat::R8Unorm,
+                    usage: wgpu::TextureUsage::SAMPLED | wgpu::TextureUsage::COPY_DST,
+                    label: Some("Glyph Atlas Texture"),
+                }
+            );
+            queue.write_texture(
+                wgpu::TextureCopyView {
+                    texture: &atlas_texture,
+                    mip_level: 0,
+                    origin: wgpu::Origin3d::ZERO,
+                },
+                glyph_atlas.get_pixels(),
+                wgpu::TextureDataLayout {
+                    offset: 0,
+                    bytes_per_row: glyph_atlas.get_width(),
+                    rows_per_image: glyph_atlas.get_height(),
+                },
+                atlas_extent,
+            );
+            let atlas_texture_view = atlas_texture.create_view(&wgpu::TextureViewDescriptor::default());
+            let atlas_sampler = library::produce_sampler(&device, &ImageSampling::new(ImageFilter::Linear, false));
+            let texture_bind_group_layout = library::produce_texture_bind_group_layout(&device);
+            let texture_bind_group = library::produce_texture_bind_group(&device, &texture_bind_group_layout, &atlas_texture_view, &atlas_sampler);
+
+        //uniforms
+            let uniform_bind_group_layout = device.create_bind_group_layout(
+                &wgpu::BindGroupLayoutDescriptor {
+                    label: Some("Uniform Bind Group Layout"),
+                    entries: &[
+                        wgpu::BindGroupLayoutEntry {
+                            binding: 0,
+                            visibility: wgpu::ShaderStage::VERTEX,
+                            ty: wgpu::BindingType::UniformBuffer {
+                                dynamic: false,
+                                min_binding_size: None,
+                            },
+                            count: None,
+                        },
+                        wgpu::BindGroupLayoutEntry {
+                            binding: 1,
+                            visibility: wgpu::ShaderStage::FRAGMENT,
+                            ty: wgpu::BindingType::UniformBuffer {
+                                dynamic: false,
+                                min_binding_size: None,
+                            },
+                            count: None,
+                        },
+                    ],
+                }
+            );
+            let create_uniform_bind_group = |vertex_data:&[u8], fragment_data:FragmentUniformData| -> wgpu::BindGroup {
+                let vertex_uniform_printing_buffer = device.create_buffer_init(
+                    &wgpu::util::BufferInitDescriptor {
+                        label: Some("Vertex Uniform Printing Buffer"),
+                        contents: vertex_data,
+                        usage: wgpu::BufferUsage::UNIFORM,
+                    }
+                );
+                let fragment_uniform_printing_buffer = device.create_buffer_init(
+                    &wgpu::util::BufferInitDescriptor {
+                        label: Some("Fragment Uniform Printing Buffer"),
+                        contents: bytemuck::cast_slice(&[fragment_data]),
+                        usage: wgpu::BufferUsage::UNIFORM,
+                    }
+                );
+                device.create_bind_group(
+                    &wgpu::BindGroupDescriptor {
+                        label: Some("Uniform Bind Group"),
+                        layout: &uniform_bind_group_layout,
+                        entries: &[
+                            wgpu::BindGroupEntry {
+                                binding: 0,
+                                resource: wgpu::BindingResource::Buffer(vertex_uniform_printing_buffer.slice(..))
+                            },
+                            wgpu::BindGroupEntry {
+                                binding: 1,
+                                resource: wgpu::BindingResource::Buffer(fragment_uniform_printing_buffer.slice(..))
+                            }
+                        ],
+                    }
+                )
+            };
+
+            let box_bind_groups:Vec<wgpu::BindGroup> = boxes.iter().map(|bounding_box| {
+                create_uniform_bind_group(
+                    bytemuck::cast_slice(&[VertexUniformData::new(
+                        bounding_box.get_top_left().get_x(), //x
+                        bounding_box.get_top_left().get_y(), //y
+                        1.0, //scale
+                        0.0, //angle
+                        dimensions(), //dimensions
+                        bounding_box.get_bottom_right().get_x() - bounding_box.get_top_left().get_x(), //width
+                        bounding_box.get_bottom_right().get_y() - bounding_box.get_top_left().get_y(), //height
+                        Point::new(0.0,0.0), //anchor
+                    )]),
+                    FragmentUniformData::new(Colour::new(0.85, 0.9, 1.0, 1.0)),
+                )
+            }).collect();
+            let glyph_bind_groups:Vec<wgpu::BindGroup> = glyph_quads.iter().map(|(top_left, atlas_glyph)| {
+                create_uniform_bind_group(
+                    bytemuck::cast_slice(&[TextureVertexUniformData::new(
+                        VertexUniformData::new(
+                            top_left.get_x(), //x
+                            top_left.get_y(), //y
+                            1.0, //scale
+                            0.0, //angle
+                            dimensions(), //dimensions
+                            atlas_glyph.width as f32 / pixel_ratio, //width
+                            atlas_glyph.height as f32 / pixel_ratio, //height
+                            Point::new(0.0,0.0), //anchor
+                        ),
+                        &atlas_glyph.uv,
+                    )]),
+                    FragmentUniformData::new(Colour::new(0.1, 0.1, 0.15, 1.0)),
+                )
+            }).collect();
+            println!("renderer >> {} glyphs drawn", glyph_bind_groups.len());
+
+        //vertex buffer
+            //two triangles covering the unit square
+            let vertex_point_data:Vec<library::Vertex> = [
+                [0.0,0.0], [1.0,0.0], [1.0,1.0],
+                [0.0,0.0], [1.0,1.0], [0.0,1.0],
+            ].iter().map(|point| library::Vertex::new(*point)).collect();
+
+            let vertex_buffer = device.create_buffer_init(
+                &wgpu::util::BufferInitDescriptor {
+                    label: Some("Vertex Buffer"),
+                    contents: bytemuck::cast_slice(&vertex_point_data),
+                    usage: wgpu::BufferUsage::VERTEX,
+                }
+            );
+
+        //render pipelines
+            let create_render_pipeline = |bind_group_layouts:&[&wgpu::BindGroupLayout], vertex_shader_module:&wgpu::ShaderModule, fragment_shader_module:&wgpu::ShaderModule| -> wgpu::RenderPipeline {
+                let render_pipeline_layout = device.create_pipeline_layout(
+                    &wgpu::PipelineLayoutDescriptor {
+                        label: Some("Render Pipeline Layout"),
+                        bind_group_layouts,
+                        push_constant_ranges: &[],
+                    }
+                );
+                device.create_render_pipeline(
+                    &wgpu::RenderPipelineDescriptor {
+                        label: Some("Render Pipeline"),
+                        layout: Some(&render_pipeline_layout),
+                        vertex_stage: wgpu::ProgrammableStageDescriptor {
+                            module: vertex_shader_module,
+                            entry_point: "main",
+                        },
+                        fragment_stage: Some(wgpu::ProgrammableStageDescriptor {
+                            module: fragment_shader_module,
+                            entry_point: "main",
+                        }),
+                        rasterization_state: Some(wgpu::RasterizationStateDescriptor {
+                            front_face: wgpu::FrontFace::Cw,
+                            cull_mode: wgpu::CullMode::Back,
+                            depth_bias: 0,
+                            depth_bias_slope_scale: 0.0,
+                            depth_bias_clamp: 0.0,
+                            clamp_depth: false,
+                        }),
+                        primitive_topology: wgpu::PrimitiveTopology::TriangleList,
+                        color_states: &[library::produce_color_state_descriptor(swap_chain_descriptor.format, &DrawBlend::new_default())],
+                        depth_stencil_state: None,
+                        vertex_state: wgpu::VertexStateDescriptor {
+                            index_format: wgpu::IndexFormat::Uint16,
+                            vertex_buffers: &[library::Vertex::desc()],
+                        },
+                        sample_count: 4,
+                        sample_mask: !0,
+                        alpha_to_coverage_enabled: false,
+                    }
+                )
+            };
+            let render_pipeline = create_render_pipeline(&[&uniform_bind_group_layout], &vertex_shader_module, &fragment_shader_module);
+            let glyph_render_pipeline = create_render_pipeline(&[&uniform_bind_group_layout, &texture_bind_group_layout], &glyph_vertex_shader_module, &glyph_fragment_shader_module);
+
+        //setup frame
+            let frame = match swap_chain.get_current_frame() {
+                Err(e) => {
+                    println!("ERROR - Renderer : swap_chain.get_current_frame failed! {}", e);
+                    return;
+                },
+                Ok(frame) => frame.output,
+            };
+
+        //create encoder
+            let mut encoder = device.create_command_encoder(
+                &wgpu::CommandEncoderDescriptor {
+                    label: Some("Renderer : Command Encoder"),
+                }
+            );
+
+        println!("renderer >> beginning main render pass");
+
+        //begin main render pass
+            let start_time = std::time::Instant::now();
+            {
+                let mut render_pass = encoder.begin_render_pass(
+                    &wgpu::RenderPassDescriptor {
+                        color_attachments: &[
+                            wgpu::RenderPassColorAttachmentDescriptor {
+                                attachment: &framebuffer,
+                                resolve_target: Some(&frame.view),
+                                ops: wgpu::Operations {
+                                    load: wgpu::LoadOp::Clear(
+                                        wgpu::Color {
+                                            r: 1.0,
+                                            g: 1.0,
+                                            b: 1.0,
+                                            a: 1.0,
+                                        }
+                                    ),
+                                    store: true,
+                                },
+                            }
+                        ],
+                        depth_stencil_attachment: None,
+                    }
+                );
+
+                render_pass.set_vertex_buffer(0, vertex_buffer.slice(..));
+
+                render_pass.set_pipeline(&render_pipeline);
+                for bind_group in &box_bind_groups {
+                    render_pass.set_bind_group(0, bind_group, &[]);
+                    render_pass.draw(0..vertex_point_data.len() as u32, 0..1);
+                }
+
+                render_pass.set_pipeline(&glyph_render_pipeline);
+                render_pass.set_bind_group(1, &texture_bind_group, &[]);
+                for bind_group in &glyph_bind_groups {
+                    render_pass.set_bind_group(0, bind_group, &[]);
+                    render_pass.draw(0..vertex_point_data.len() as u32, 0..1);
+                }
+            }
+            let end_time = std::time::Instant::now();
+            println!("{:?}", end_time.checked_duration_since(start_time) );
+
+        //submit encoder to queue, to be rendered
+            queue.submit(std::iter::once(encoder.finish()));
+    }
+
+    pub fn test8(&mut self) {
+        println!("- test 8 -");
+        //images; a made up sprite sheet drawn whole (with each filter, and shrunk with and without mipmaps) and in
+        //parts, as image elements in a scene graph alongside a rectangle
+
+        //sprite sheet
+            //four 32 pixel sprites; each a checkerboard of its own colour, within a circle that fades out at the edge
+            let sprite_size = 32;
+            let sheet_size = sprite_size * 2;
+            let sprite_colours = [
+                Colour::new(0.9, 0.3, 0.2, 1.0),
+                Colour::new(0.2, 0.7, 0.3, 1.0),
+                Colour::new(0.2, 0.4, 0.9, 1.0),
+                Colour::new(0.9, 0.8, 0.2, 1.0),
+            ];
+            let mut pixels = vec![];
+            for y in 0..sheet_size {
+                for x in 0..sheet_size {
+                    let sprite = (x / sprite_size) + 2 * (y / sprite_size);
+                    let (local_x, local_y) = ((x % sprite_size) as f32 + 0.5, (y % sprite_size) as f32 + 0.5);
+                    let distance = ((local_x - 16.0).powi(2) + (local_y - 16.0).powi(2)).sqrt();
+                    let alpha = (15.0 - distance).clamp(0.0, 1.0);
+                    let colour = if (x / 4 + y / 4) % 2 == 0 { sprite_colours[sprite as usize] } else { Colour::new(1.0, 1.0, 1.0, 1.0) };
+                    let (r, g, b, _) = colour.to_rgba8();
+                    pixels.extend_from_slice(&[r, g, b, (alpha * 255.0).round() as u8]);
+                }
+            }
+            let sheet = match Image::new_from_rgba8(sheet_size, sheet_size, pixels) {
+                Some(sheet) => sheet,
+                None => return,
+            };
+
+        //adapter
+            let adapter = futures::executor::block_on(
+                self.instance.request_adapter(
+                    &wgpu::RequestAdapterOptions {
+                        power_preference: wgpu::PowerPreference::Default,
+                        compatible_surface: Some(&self.surface),
+                    }
+                )
+            ).unwrap();
+
+        //device and queue
+            let (device, queue) = futures::executor::block_on(
+                adapter.request_device(
+                    &wgpu::DeviceDescriptor {
+                        features: wgpu::Features::empty(),
+                        limits: wgpu::Limits::default(),
+                        shader_validation: true,
+                    },
+                    None,
+                )
+            ).unwrap();
+
+        //swap chain
+            let swap_chain_descriptor = wgpu::SwapChainDescriptor {
+                usage: wgpu::TextureUsage::OUTPUT_ATTACHMENT,
+                format: wgpu::TextureFormat::Bgra8UnormSrgb,
+                width: self.size.width,
+                height: self.size.height,
+                present_mode: wgpu::PresentMode::Fifo,
+            };
+            let mut swap_chain = device.create_swap_chain( &self.surface, &swap_chain_descriptor );
+
+        //framebuffer
+            let multisampled_texture_extent = wgpu::Extent3d {
+                width: swap_chain_descriptor.width,
+                height: swap_chain_descriptor.height,
+                depth: 1,
+            };
+            let multisampled_frame_descriptor = &wgpu::TextureDescriptor {
+                size: multisampled_texture_extent,
+                mip_level_count: 1,
+                sample_count: 4,
+                dimension: wgpu::TextureDimension::D2,
+                format: swap_chain_descriptor.format,
+                usage: wgpu::TextureUsage::all(),
+                label: Some("multisampled framebuffer"),
+            };
+            let framebuffer = device.create_texture(multisampled_frame_descriptor).create_view(&wgpu::TextureViewDescriptor::default());
+
+        //create shader modules
+            let vertex_shader_module = device.create_shader_module( wgpu::include_spirv!("shader.vert.spv") );
+            let fragment_shader_module = device.create_shader_module( wgpu::include_spirv!("shader.frag.spv") );
+            let image_vertex_shader_module = device.create_shader_module( wgpu::include_spirv!("shader_texture.vert.spv") );
+            let image_fragment_shader_module = device.create_shader_module( wgpu::include_spirv!("shader_image.frag.spv") );
+
+        //scene graph
+            //in logical pixels
+            let logical_size = self.size.to_logical::<u32>(self.device_pixel_density_ratio);
+            let dimensions = || Dimensions::<u32>::new(logical_size.width, logical_size.height);
+
+            let mut scene_graph = SceneGraph::new();
+            let root = scene_graph.get_root();
+            let nearest = ImageSampling::new(ImageFilter::Nearest, false);
+            let linear = ImageSampling::new(ImageFilter::Linear, false);
+            let mipmapped = ImageSampling::new(ImageFilter::Linear, true);
+
+            scene_graph.add_element(root, Offset::new(20.0, 20.0, 1.0, 0.0), Element::new_rectangle(530.0, 270.0, Point::new(0.0,0.0), Colour::new(0.85, 0.9, 1.0, 1.0)));
+
+            //the whole sheet, blown up
+                scene_graph.add_element(root, Offset::new(30.0, 30.0, 1.0, 0.0), Element::new_image(sheet.clone(), None, 256.0, 256.0, Point::new(0.0,0.0), nearest));
+                scene_graph.add_element(root, Offset::new(290.0, 30.0, 1.0, 0.0), Element::new_image(sheet.clone(), None, 256.0, 256.0, Point::new(0.0,0.0), linear));
+
+            //shrunk, where mipmaps make the difference
+                scene_graph.add_element(root, Offset::new(560.0, 30.0, 1.0, 0.0), Element::new_image(sheet.clone(), None, 12.0, 12.0, Point::new(0.0,0.0), linear));
+                scene_graph.add_element(root, Offset::new(580.0, 30.0, 1.0, 0.0), Element::new_image(sheet.clone(), None, 12.0, 12.0, Point::new(0.0,0.0), mipmapped));
+
+            //each sprite on its own, turned about its middle, in a faded group
+                let sprites = scene_graph.add_group(root, Offset::new(30.0, 340.0, 1.0, 0.0)).unwrap();
+                scene_graph.set_opacity(sprites, 0.8);
+                for index in 0..4 {
+                    let source = BoundingBox::new(
+                        ((index % 2) * sprite_size) as f32,
+                        ((index / 2) * sprite_size) as f32,
+                        ((index % 2 + 1) * sprite_size) as f32,
+                        ((index / 2 + 1) * sprite_size) as f32,
+                    );
+                    scene_graph.add_element(
+                        sprites,
+                        Offset::new(50.0 + index as f32 * 110.0, 50.0, 1.0, index as f32 * 0.3),
+                        Element::new_image(sheet.clone(), Some(source), 96.0, 96.0, Point::new(0.5,0.5), mipmapped),
+                    );
+                }
+
+        //uniforms
+            let uniform_bind_group_layout = device.create_bind_group_layout(
+                &wgpu::BindGroupLayoutDescriptor {
+                    label: Some("Uniform Bind Group Layout"),
+                    entries: &[
+                        wgpu::BindGroupLayoutEntry {
+                            binding: 0,
+                            visibility: wgpu::ShaderStage::VERTEX,
+                            ty: wgpu::BindingType::UniformBuffer {
+                                dynamic: false,
+                                min_binding_size: None,
+                            },
+                            count: None,
+                        },
+                        wgpu::BindGroupLayoutEntry {
+                            binding: 1,
+                            visibility: wgpu::ShaderStage::FRAGMENT,
+                            ty: wgpu::BindingType::UniformBuffer {
+                                dynamic: false,
+                                min_binding_size: None,
+                            },
+                            count: None,
+                        },
+                    ],
+                }
+            );
+            let create_uniform_bind_group = |vertex_data:&[u8], fragment_data:FragmentUniformData| -> wgpu::BindGroup {
+                let vertex_uniform_printing_buffer = device.create_buffer_init(
+                    &wgpu::util::BufferInitDescriptor {
+                        label: Some("Vertex Uniform Printing Buffer"),
+                        contents: vertex_data,
+                        usage: wgpu::BufferUsage::UNIFORM,
+                    }
+                );
+                let fragment_uniform_printing_buffer = device.create_buffer_init(
+                    &wgpu::util::BufferInitDescriptor {
+                        label: Some("Fragment Uniform Printing Buffer"),
+                        contents: bytemuck::cast_slice(&[fragment_data]),
+                        usage: wgpu::BufferUsage::UNIFORM,
+                    }
+                );
+                device.create_bind_group(
+                    &wgpu::BindGroupDescriptor {
+                        label: Some("Uniform Bind Group"),
+                        layout: &uniform_bind_group_layout,
+                        entries: &[
+                            wgpu::BindGroupEntry {
+                                binding: 0,
+                                resource: wgpu::BindingResource::Buffer(vertex_uniform_printing_buffer.slice(..))
+                            },
+                            wgpu::BindGroupEntry {
+                                binding: 1,
+                                resource: wgpu::BindingResource::Buffer(fragment_uniform_printing_buffer.slice(..))
+                            }
+                        ],
+                    }
+                )
+            };
+
+            //each image is uploaded once for every way it's sampled
+            let texture_bind_group_layout = library::produce_texture_bind_group_layout(&device);
+            let mut image_textures:HashMap<(usize, ImageSampling), library::ImageTexture> = HashMap::new();
+
+            //the textures for images drawn are noted alongside their uniforms, and drawn with the image pipeline
+            let mut draws:Vec<(wgpu::BindGroup, Option<(usize, ImageSampling)>)> = vec![];
+            for flattened_element in scene_graph.flatten() {
+                let placement = flattened_element.offset;
+                let vertex_data = |width:f32, height:f32, anchor:Point| VertexUniformData::new(
+                    placement.get_x(), //x
+                    placement.get_y(), //y
+                    placement.get_scale(), //scale
+                    -placement.get_angle(), //angle
+                    dimensions(), //dimensions
+                    width, //width
+                    height, //height
+                    anchor, //anchor
+                );
+                match flattened_element.element.get_kind() {
+                    ElementKind::Rectangle { width, height, anchor, colour } => {
+                        draws.push((
+                            create_uniform_bind_group(
+                                bytemuck::cast_slice(&[vertex_data(*width, *height, *anchor)]),
+                                FragmentUniformData::new(Colour::new(colour.r(), colour.g(), colour.b(), colour.a() * flattened_element.opacity)),
+                            ),
+                            None,
+                        ));
+                    },
+                    ElementKind::Image { image, source, width, height, anchor, sampling } => {
+                        let key = (image.get_id(), *sampling);
+                        image_textures.entry(key).or_insert_with(|| {
+                            let image_texture = library::ImageTexture::new(&device, &queue, &texture_bind_group_layout, image, sampling);
+                            println!("renderer >> uploaded image {} with {} ({} mip levels)", image, sampling, image_texture.get_mip_level_count());
+                            image_texture
+                        });
+                        draws.push((
+                            create_uniform_bind_group(
+                                bytemuck::cast_slice(&[TextureVertexUniformData::new(vertex_data(*width, *height, *anchor), &image.get_uv(source))]),
+                                FragmentUniformData::new(Colour::new(1.0, 1.0, 1.0, flattened_element.opacity)),
+                            ),
+                            Some(key),
+                        ));
+                    },
+                }
+            }
+            println!("renderer >> {} elements drawn, with {} textures", draws.len(), image_textures.len());
+
+        //vertex buffer
+            //two triangles covering the unit square
+            let vertex_point_data:Vec<library::Vertex> = [
+                [0.0,0.0], [1.0,0.0], [1.0,1.0],
+                [0.0,0.0], [1.0,1.0], [0.0,1.0],
+            ].iter().map(|point| library::Vertex::new(*point)).collect();
+
+            let vertex_buffer = device.create_buffer_init(
+                &wgpu::util::BufferInitDescriptor {
+                    label: Some("Vertex Buffer"),
+                    contents: bytemuck::cast_slice(&vertex_point_data),
+                    usage: wgpu::BufferUsage::VERTEX,
+                }
+            );
+
+        //render pipelines
+            let create_render_pipeline = |bind_group_layouts:&[&wgpu::BindGroupLayout], vertex_shader_module:&wgpu::ShaderModule, fragment_shader_module:&wgpu::ShaderModule| -> wgpu::RenderPipeline {
+                let render_pipeline_layout = device.create_pipeline_layout(
+                    &wgpu::PipelineLayoutDescriptor {
+                        label: Some("Render Pipeline Layout"),
+                        bind_group_layouts,
+                        push_constant_ranges: &[],
+                    }
+                );
+                device.create_render_pipeline(
+                    &wgpu::RenderPipelineDescriptor {
+                        label: Some("Render Pipeline"),
+                        layout: Some(&render_pipeline_layout),
+                        vertex_stage: wgpu::ProgrammableStageDescriptor {
+                            module: vertex_shader_module,
+                            entry_point: "main",
+                        },
+                        fragment_stage: Some(wgpu::ProgrammableStageDescriptor {
+                            module: fragment_shader_module,
+                            entry_point: "main",
+                        }),
+                        rasterization_state: Some(wgpu::RasterizationStateDescriptor {
+                            front_face: wgpu::FrontFace::Cw,
+                            cull_mode: wgpu::CullMode::Back,
+                            depth_bias: 0,
+                            depth_bias_slope_scale: 0.0,
+                            depth_bias_clamp: 0.0,
+                            clamp_depth: false,
+                        }),
+                        primitive_topology: wgpu::PrimitiveTopology::TriangleList,
+                        color_states: &[library::produce_color_state_descriptor(swap_chain_descriptor.format, &DrawBlend::new_default())],
+                        depth_stencil_state: None,
+                        vertex_state: wgpu::VertexStateDescriptor {
+                            index_format: wgpu::IndexFormat::Uint16,
+                            vertex_buffers: &[library::Vertex::desc()],
+                        },
+                        sample_count: 4,
+                        sample_mask: !0,
+                        alpha_to_coverage_enabled: false,
+                    }
+                )
+            };
+            let render_pipeline = create_render_pipeline(&[&uniform_bind_group_layout], &vertex_shader_module, &fragment_shader_module);
+            let image_render_pipeline = create_render_pipeline(&[&uniform_bind_group_layout, &texture_bind_group_layout], &image_vertex_shader_module, &image_fragment_shader_module);
+
+        //setup frame
+            let frame = match swap_chain.get_current_frame() {
+                Err(e) => {
+                    println!("ERROR - Renderer : swap_chain.get_current_frame failed! {}", e);
+                    return;
+                },
+                Ok(frame) => frame.output,
+            };
+
+        //create encoder
+            let mut encoder = device.create_command_encoder(
+                &wgpu::CommandEncoderDescriptor {
+                    label: Some("Renderer : Command Encoder"),
+                }
+            );
+
+        println!("renderer >> beginning main render pass");
+
+        //begin main render pass
+            let start_time = std::time::Instant::now();
+            {
+                let mut render_pass = encoder.begin_render_pass(
+                    &wgpu::RenderPassDescriptor {
+                        color_attachments: &[
+                            wgpu::RenderPassColorAttachmentDescriptor {
+                                attachment: &framebuffer,
+                                resolve_target: Some(&frame.view),
+                                ops: wgpu::Operations {
+                                    load: wgpu::LoadOp::Clear(
+                                        wgpu::Color {
+                                            r: 1.0,
+                                            g: 1.0,
+                                            b: 1.0,
+                                            a: 1.0,
+                                        }
+                                    ),
+                                    store: true,
+                                },
+                            }
+                        ],
+                        depth_stencil_attachment: None,
+                    }
+                );
+
+                render_pass.set_vertex_buffer(0, vertex_buffer.slice(..));
+
+                for (bind_group, texture) in &draws {
+                    match texture {
+                        Some(key) => {
+                            render_pass.set_pipeline(&image_render_pipeline);
+                            render_pass.set_bind_group(1, image_textures[key].get_bind_group(), &[]);
+                        },
+                        None => render_pass.set_pipeline(&render_pipeline),
+                    }
+                    render_pass.set_bind_group(0, bind_group, &[]);
+                    render_pass.draw(0..vertex_point_data.len() as u32, 0..1);
+                }
+            }
+            let end_time = std::time::Instant::now();
+            println!("{:?}", end_time.checked_duration_since(start_time) );
+
+        //submit encoder to queue, to be rendered
+            queue.submit(std::iter::once(encoder.finish()));
+    }
 }
\ No newline at end of file
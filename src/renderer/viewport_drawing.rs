@@ -0,0 +1,448 @@
+use wgpu::util::DeviceExt;
+
+use crate::library::data_type::{
+    BoundingBox,
+    Colour,
+    Dimensions,
+    DrawBlend,
+    Offset,
+    Point,
+};
+use crate::library::viewport::ViewportSnapshot;
+
+use super::library;
+use super::library::{
+    FragmentUniformData,
+    VertexUniformData,
+};
+
+
+
+
+
+
+
+
+//viewport shape
+    //a rectangle drawn through the viewports; centred on x and y in the world, on one of the layers that viewports
+    //filter by
+    pub struct ViewportShape {
+        layer: usize,
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+        angle: f32,
+        colour: Colour,
+        bounding_box: BoundingBox, //of the turned rectangle, for culling
+    }
+    //new
+        impl ViewportShape {
+            pub fn new(layer:usize, x:f32, y:f32, width:f32, height:f32, angle:f32, colour:Colour) -> ViewportShape {
+                let half_width = (width * angle.cos().abs() + height * angle.sin().abs()) / 2.0;
+                let half_height = (width * angle.sin().abs() + height * angle.cos().abs()) / 2.0;
+
+                ViewportShape {
+                    layer,
+                    x,
+                    y,
+                    width,
+                    height,
+                    angle,
+                    colour,
+                    bounding_box: BoundingBox::new(x - half_width, y - half_height, x + half_width, y + half_height),
+                }
+            }
+        }
+
+
+
+
+
+
+
+
+//uniform slot
+    //a draw's uniforms, and the bind group for them; kept from one frame to the next and written over
+    struct UniformSlot {
+        vertex_buffer: wgpu::Buffer,
+        fragment_buffer: wgpu::Buffer,
+        bind_group: wgpu::BindGroup,
+    }
+
+
+
+
+
+
+
+
+//viewport drawing
+    //draws its shapes through each of the orchestrator's viewports in turn, bottom to top; each clears its own region
+    //to its clear colour (if it has one) and draws only the shapes on its layers that it can see. Everything the GPU
+    //needs is made once, here; after that, a frame is just uniforms written into the slots already made (with more
+    //made only when a frame needs more than ever before), and the swap chain is only made again when the size changes
+    pub struct ViewportDrawing {
+        device: wgpu::Device,
+        queue: wgpu::Queue,
+        swap_chain_descriptor: wgpu::SwapChainDescriptor,
+        swap_chain: wgpu::SwapChain,
+        framebuffer: wgpu::TextureView, //multisampled
+        uniform_bind_group_layout: wgpu::BindGroupLayout,
+        render_pipeline: wgpu::RenderPipeline,
+        vertex_buffer: wgpu::Buffer,
+        vertex_count: u32,
+        uniform_slots: Vec<UniformSlot>,
+        shapes: Vec<ViewportShape>,
+    }
+    //new
+        impl ViewportDrawing {
+            pub fn new(instance:&wgpu::Instance, surface:&wgpu::Surface, size:winit::dpi::PhysicalSize<u32>) -> ViewportDrawing {
+                //adapter
+                    let adapter = futures::executor::block_on(
+                        instance.request_adapter(
+                            &wgpu::RequestAdapterOptions {
+                                power_preference: wgpu::PowerPreference::Default,
+                                compatible_surface: Some(surface),
+                            }
+                        )
+                    ).unwrap();
+
+                //device and queue
+                    let (device, queue) = futures::executor::block_on(
+                        adapter.request_device(
+                            &wgpu::DeviceDescriptor {
+                                features: wgpu::Features::empty(),
+                                limits: wgpu::Limits::default(),
+                                shader_validation: true,
+                            },
+                            None,
+                        )
+                    ).unwrap();
+
+                //swap chain and framebuffer
+                    let swap_chain_descriptor = wgpu::SwapChainDescriptor {
+                        usage: wgpu::TextureUsage::OUTPUT_ATTACHMENT,
+                        format: wgpu::TextureFormat::Bgra8UnormSrgb,
+                        width: size.width.max(1),
+                        height: size.height.max(1),
+                        present_mode: wgpu::PresentMode::Fifo,
+                    };
+                    let swap_chain = device.create_swap_chain(surface, &swap_chain_descriptor);
+                    let framebuffer = produce_framebuffer(&device, &swap_chain_descriptor);
+
+                //create shader modules
+                    let vertex_shader_module = device.create_shader_module( wgpu::include_spirv!("shader.vert.spv") );
+                    let fragment_shader_module = device.create_shader_module( wgpu::include_spirv!("shader.frag.spv") );
+
+                //uniforms
+                    let uniform_bind_group_layout = device.create_bind_group_layout(
+                        &wgpu::BindGroupLayoutDescriptor {
+                            label: Some("Uniform Bind Group Layout"),
+                            entries: &[
+                                wgpu::BindGroupLayoutEntry {
+                                    binding: 0,
+                                    visibility: wgpu::ShaderStage::VERTEX,
+                                    ty: wgpu::BindingType::UniformBuffer {
+                                        dynamic: false,
+                                        min_binding_size: None,
+                                    },
+                                    count: None,
+                                },
+                                wgpu::BindGroupLayoutEntry {
+                                    binding: 1,
+                                    visibility: wgpu::ShaderStage::FRAGMENT,
+                                    ty: wgpu::BindingType::UniformBuffer {
+                                        dynamic: false,
+                                        min_binding_size: None,
+                                    },
+                                    count: None,
+                                },
+                            ],
+                        }
+                    );
+
+                //vertex buffer
+                    //two triangles covering the unit square
+                    let vertex_point_data:Vec<library::Vertex> = [
+                        [0.0,0.0], [1.0,0.0], [1.0,1.0],
+                        [0.0,0.0], [1.0,1.0], [0.0,1.0],
+                    ].iter().map(|point| library::Vertex::new(*point)).collect();
+
+                    let vertex_buffer = device.create_buffer_init(
+                        &wgpu::util::BufferInitDescriptor {
+                            label: Some("Vertex Buffer"),
+                            contents: bytemuck::cast_slice(&vertex_point_data),
+                            usage: wgpu::BufferUsage::VERTEX,
+                        }
+                    );
+
+                //render pipeline
+                    let render_pipeline_layout = device.create_pipeline_layout(
+                        &wgpu::PipelineLayoutDescriptor {
+                            label: Some("Render Pipeline Layout"),
+                            bind_group_layouts: &[
+                                &uniform_bind_group_layout
+                            ],
+                            push_constant_ranges: &[],
+                        }
+                    );
+                    let render_pipeline = device.create_render_pipeline(
+                        &wgpu::RenderPipelineDescriptor {
+                            label: Some("Render Pipeline"),
+                            layout: Some(&render_pipeline_layout),
+                            vertex_stage: wgpu::ProgrammableStageDescriptor {
+                                module: &vertex_shader_module,
+                                entry_point: "main",
+                            },
+                            fragment_stage: Some(wgpu::ProgrammableStageDescriptor {
+                                module: &fragment_shader_module,
+                                entry_point: "main",
+                            }),
+                            rasterization_state: Some(wgpu::RasterizationStateDescriptor {
+                                front_face: wgpu::FrontFace::Cw,
+                                cull_mode: wgpu::CullMode::Back,
+                                depth_bias: 0,
+                                depth_bias_slope_scale: 0.0,
+                                depth_bias_clamp: 0.0,
+                                clamp_depth: false,
+                            }),
+                            primitive_topology: wgpu::PrimitiveTopology::TriangleList,
+                            color_states: &[library::produce_color_state_descriptor(swap_chain_descriptor.format, &DrawBlend::new_default())],
+                            depth_stencil_state: None,
+                            vertex_state: wgpu::VertexStateDescriptor {
+                                index_format: wgpu::IndexFormat::Uint16,
+                                vertex_buffers: &[library::Vertex::desc()],
+                            },
+                            sample_count: 4,
+                            sample_mask: !0,
+                            alpha_to_coverage_enabled: false,
+                        }
+                    );
+
+                ViewportDrawing {
+                    device,
+                    queue,
+                    swap_chain_descriptor,
+                    swap_chain,
+                    framebuffer,
+                    uniform_bind_group_layout,
+                    render_pipeline,
+                    vertex_buffer,
+                    vertex_count: vertex_point_data.len() as u32,
+                    uniform_slots: vec![],
+                    shapes: vec![],
+                }
+            }
+        }
+    //setters
+        impl ViewportDrawing {
+            pub fn set_shapes(&mut self, new:Vec<ViewportShape>) { self.shapes = new; }
+            //makes the swap chain (and framebuffer) again, if the size is actually different. A minimised window has
+            //no size to draw at, so the old one's kept until it's back
+            pub fn set_size(&mut self, surface:&wgpu::Surface, size:winit::dpi::PhysicalSize<u32>) {
+                if size.width == 0 || size.height == 0 {
+                    return;
+                }
+                if size.width == self.swap_chain_descriptor.width && size.height == self.swap_chain_descriptor.height {
+                    return;
+                }
+
+                self.swap_chain_descriptor.width = size.width;
+                self.swap_chain_descriptor.height = size.height;
+                self.swap_chain = self.device.create_swap_chain(surface, &self.swap_chain_descriptor);
+                self.framebuffer = produce_framebuffer(&self.device, &self.swap_chain_descriptor);
+            }
+        }
+    //drawing
+        impl ViewportDrawing {
+            pub fn draw(&mut self, viewports:&[ViewportSnapshot]) {
+                //uniforms; a slot per draw, with a rectangle of the clear colour filling the region first
+                    let mut slot_count = 0;
+                    let mut viewport_slots = vec![]; //(device pixel region, slots drawn)
+                    for viewport in viewports {
+                        let viewbox = viewport.get_viewbox();
+                        let first_slot = slot_count;
+
+                        if let Some(colour) = viewport.get_clear_colour() {
+                            self.write_uniforms(
+                                slot_count,
+                                VertexUniformData::new( 0.0, 0.0, 1.0, 0.0, Dimensions::<u32>::new(viewbox.get_width(), viewbox.get_height()), viewbox.get_width() as f32, viewbox.get_height() as f32, Point::new(0.0,0.0) ),
+                                FragmentUniformData::new(colour),
+                            );
+                            slot_count += 1;
+                        }
+
+                        for index in 0..self.shapes.len() {
+                            let shape = &self.shapes[index];
+                            if !viewport.is_visible(shape.layer, &shape.bounding_box) {
+                                continue;
+                            }
+
+                            //the vertex shader turns shapes the opposite way to Offset
+                            let placement = Offset::combine( &Offset::new(shape.x, shape.y, 1.0, -shape.angle), viewbox.get_offset() );
+                            let vertex_data = VertexUniformData::new(
+                                placement.get_x(), //x
+                                placement.get_y(), //y
+                                placement.get_scale(), //scale
+                                -placement.get_angle(), //angle
+                                Dimensions::<u32>::new(viewbox.get_width(), viewbox.get_height()), //dimensions
+                                shape.width, //width
+                                shape.height, //height
+                                Point::new(0.5,0.5), //anchor
+                            );
+                            let fragment_data = FragmentUniformData::new(shape.colour);
+                            self.write_uniforms(slot_count, vertex_data, fragment_data);
+                            slot_count += 1;
+                        }
+
+                        viewport_slots.push( (viewport.get_device_pixel_region(), first_slot..slot_count) );
+                    }
+
+                //setup frame
+                    let frame = match self.swap_chain.get_current_frame() {
+                        Err(e) => {
+                            println!("ERROR - ViewportDrawing::draw : swap_chain.get_current_frame failed! {}", e);
+                            return;
+                        },
+                        Ok(frame) => frame.output,
+                    };
+
+                //create encoder
+                    let mut encoder = self.device.create_command_encoder(
+                        &wgpu::CommandEncoderDescriptor {
+                            label: Some("ViewportDrawing : Command Encoder"),
+                        }
+                    );
+
+                //render pass
+                    //the clear is for anywhere no viewport covers
+                    {
+                        let mut render_pass = encoder.begin_render_pass(
+                            &wgpu::RenderPassDescriptor {
+                                color_attachments: &[
+                                    wgpu::RenderPassColorAttachmentDescriptor {
+                                        attachment: &self.framebuffer,
+                                        resolve_target: Some(&frame.view),
+                                        ops: wgpu::Operations {
+                                            load: wgpu::LoadOp::Clear(
+                                                wgpu::Color {
+                                                    r: 0.0,
+                                                    g: 0.0,
+                                                    b: 0.0,
+                                                    a: 1.0,
+                                                }
+                                            ),
+                                            store: true,
+                                        },
+                                    }
+                                ],
+                                depth_stencil_attachment: None,
+                            }
+                        );
+
+                        render_pass.set_pipeline(&self.render_pipeline);
+                        render_pass.set_vertex_buffer(0, self.vertex_buffer.slice(..));
+
+                        let surface_width = self.swap_chain_descriptor.width;
+                        let surface_height = self.swap_chain_descriptor.height;
+                        for ((x, y, width, height), slots) in viewport_slots {
+                            //keep within the surface, as the scissor rectangle must be
+                                let x = x.min(surface_width);
+                                let y = y.min(surface_height);
+                                let width = width.min(surface_width - x);
+                                let height = height.min(surface_height - y);
+                                if width == 0 || height == 0 { continue; }
+
+                            render_pass.set_viewport(x as f32, y as f32, width as f32, height as f32, 0.0, 1.0);
+                            render_pass.set_scissor_rect(x, y, width, height);
+
+                            for slot in &self.uniform_slots[slots] {
+                                render_pass.set_bind_group(0, &slot.bind_group, &[]);
+                                render_pass.draw(0..self.vertex_count, 0..1);
+                            }
+                        }
+                    }
+
+                //submit encoder to queue, to be rendered
+                    self.queue.submit(std::iter::once(encoder.finish()));
+            }
+
+            //writes over the slot's uniforms, making the slot first if this is further than any frame has gone before
+            fn write_uniforms(&mut self, index:usize, vertex_data:VertexUniformData, fragment_data:FragmentUniformData) {
+                if index == self.uniform_slots.len() {
+                    let slot = self.produce_uniform_slot();
+                    self.uniform_slots.push(slot);
+                }
+
+                let slot = &self.uniform_slots[index];
+                self.queue.write_buffer(&slot.vertex_buffer, 0, bytemuck::cast_slice(&[vertex_data]));
+                self.queue.write_buffer(&slot.fragment_buffer, 0, bytemuck::cast_slice(&[fragment_data]));
+            }
+            fn produce_uniform_slot(&self) -> UniformSlot {
+                let vertex_buffer = self.device.create_buffer(
+                    &wgpu::BufferDescriptor {
+                        label: Some("Vertex Uniform Buffer"),
+                        size: std::mem::size_of::<VertexUniformData>() as wgpu::BufferAddress,
+                        usage: wgpu::BufferUsage::UNIFORM | wgpu::BufferUsage::COPY_DST,
+                        mapped_at_creation: false,
+                    }
+                );
+                let fragment_buffer = self.device.create_buffer(
+                    &wgpu::BufferDescriptor {
+                        label: Some("Fragment Uniform Buffer"),
+                        size: std::mem::size_of::<FragmentUniformData>() as wgpu::BufferAddress,
+                        usage: wgpu::BufferUsage::UNIFORM | wgpu::BufferUsage::COPY_DST,
+                        mapped_at_creation: false,
+                    }
+                );
+                let bind_group = self.device.create_bind_group(
+                    &wgpu::BindGroupDescriptor {
+                        label: Some("Uniform Bind Group"),
+                        layout: &self.uniform_bind_group_layout,
+                        entries: &[
+                            wgpu::BindGroupEntry {
+                                binding: 0,
+                                resource: wgpu::BindingResource::Buffer(vertex_buffer.slice(..))
+                            },
+                            wgpu::BindGroupEntry {
+                                binding: 1,
+                                resource: wgpu::BindingResource::Buffer(fragment_buffer.slice(..))
+                            }
+                        ],
+                    }
+                );
+
+                UniformSlot {
+                    vertex_buffer,
+                    fragment_buffer,
+                    bind_group,
+                }
+            }
+        }
+
+
+
+
+
+
+
+
+//utilities
+    fn produce_framebuffer(device:&wgpu::Device, swap_chain_descriptor:&wgpu::SwapChainDescriptor) -> wgpu::TextureView {
+        device.create_texture(
+            &wgpu::TextureDescriptor {
+                size: wgpu::Extent3d {
+                    width: swap_chain_descriptor.width,
+                    height: swap_chain_descriptor.height,
+                    depth: 1,
+                },
+                mip_level_count: 1,
+                sample_count: 4,
+                dimension: wgpu::TextureDimension::D2,
+                format: swap_chain_descriptor.format,
+                usage: wgpu::TextureUsage::all(),
+                label: Some("multisampled framebuffer"),
+            }
+        ).create_view(&wgpu::TextureViewDescriptor::default())
+    }